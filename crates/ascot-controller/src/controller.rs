@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use tracing::warn;
 
 use crate::device::{Device, Devices};
-use crate::discovery::Discovery;
+use crate::discovery::{Discovery, RejectedDevice};
 use crate::error::{Error, ErrorKind};
 use crate::parameters::Parameters;
 use crate::policy::Policy;
@@ -18,14 +18,28 @@ fn sender_error(error: impl Into<Cow<'static, str>>) -> Error {
 }
 
 /// A request sender.
+///
+/// The privacy policy is evaluated when a request is sent, since the
+/// hazards of a request might depend on its parameters.
 #[derive(Debug, PartialEq)]
 pub struct RequestSender<'controller> {
-    controller: &'controller Controller,
+    device_sender: &'controller DeviceSender<'controller>,
     request: &'controller Request,
-    skip: bool,
+    confirmed: bool,
 }
 
 impl RequestSender<'_> {
+    /// Explicitly confirms the sending of a request.
+    ///
+    /// Requests performing safety-critical operations, such as unlocking a
+    /// door, are not sent until they are confirmed.
+    #[must_use]
+    #[inline]
+    pub const fn confirm(mut self) -> Self {
+        self.confirmed = true;
+        self
+    }
+
     /// Sends a request to a device, getting in return a [`Response`].
     ///
     /// # Errors
@@ -33,9 +47,17 @@ impl RequestSender<'_> {
     /// While sending a request to a device, some network failures or timeouts
     /// can prevent the effective sending. Moreover, the same issues can also
     /// affect the returned response.
+    /// An error is also returned when a request requiring an explicit
+    /// confirmation has not been confirmed.
     pub async fn send(&self) -> Result<Response, Error> {
+        self.check_confirmation()?;
+
+        let skip = self
+            .device_sender
+            .evaluate_privacy_policy(self.request, &Parameters::new())?;
+
         self.request
-            .retrieve_response(self.skip, || async { self.request.plain_send().await })
+            .retrieve_response(skip, || async { self.request.plain_send().await })
             .await
     }
 
@@ -47,21 +69,39 @@ impl RequestSender<'_> {
     /// While sending a request to a device, some network failures or timeouts
     /// can prevent the effective sending. Moreover, the same issues can also
     /// affect the returned response.
+    /// An error is also returned when a request requiring an explicit
+    /// confirmation has not been confirmed.
     pub async fn send_with_parameters(
         &self,
         parameters: &Parameters<'_>,
     ) -> Result<Response, Error> {
+        self.check_confirmation()?;
+
         if self.request.parameters_data.is_empty() {
             warn!("The request does not have input parameters.");
             return self.send().await;
         }
 
+        let skip = self
+            .device_sender
+            .evaluate_privacy_policy(self.request, parameters)?;
+
         self.request
-            .retrieve_response(self.skip, || async {
+            .retrieve_response(skip, || async {
                 self.request.create_response(parameters).await
             })
             .await
     }
+
+    fn check_confirmation(&self) -> Result<(), Error> {
+        if self.request.confirmation && !self.confirmed {
+            return Err(sender_error(format!(
+                "The request with route `{}` requires an explicit confirmation.",
+                self.request.route
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// A sender for the requests of a determined device.
@@ -85,35 +125,42 @@ impl DeviceSender<'_> {
             "Error in retrieving the request with route `{route}`."
         )))?;
 
-        let skip = if request.hazards.is_empty() {
-            false
-        } else {
-            self.evaluate_privacy_policy(request, route)
-        };
-
         Ok(RequestSender {
-            controller: self.controller,
+            device_sender: self,
             request,
-            skip,
+            confirmed: false,
         })
     }
 
-    fn evaluate_privacy_policy(&self, request: &Request, route: &str) -> bool {
+    // Evaluates the privacy policy on the hazards of a request sent with the
+    // given parameters, returning whether the request must be skipped.
+    fn evaluate_privacy_policy(
+        &self,
+        request: &Request,
+        parameters: &Parameters<'_>,
+    ) -> Result<bool, Error> {
+        let hazards = request.effective_hazards(parameters)?;
+        if hazards.is_empty() {
+            return Ok(false);
+        }
+
+        let route = &request.route;
         let mut skip = false;
 
         let global_blocked_hazards = self
             .controller
             .privacy_policy
-            .global_blocked_hazards(&request.hazards);
+            .global_blocked_hazards(&hazards, &request.hazard_levels);
 
-        let local_blocked_hazards = self
-            .controller
-            .privacy_policy
-            .local_blocked_hazards(self.id, &request.hazards);
+        let local_blocked_hazards = self.controller.privacy_policy.local_blocked_hazards(
+            self.id,
+            &hazards,
+            &request.hazard_levels,
+        );
 
         if !global_blocked_hazards.is_empty() {
             warn!(
-                "The {route} is skipped because it contains the global blocked hazards: {:?}",
+                "The request with route `{route}` is skipped because it contains the global blocked hazards: {:?}",
                 global_blocked_hazards
             );
             skip = true;
@@ -121,13 +168,13 @@ impl DeviceSender<'_> {
 
         if !local_blocked_hazards.is_empty() {
             warn!(
-                "The {route} is skipped because the device contains the local blocked hazards: {:?}",
+                "The request with route `{route}` is skipped because the device contains the local blocked hazards: {:?}",
                 local_blocked_hazards
             );
             skip = true;
         }
 
-        skip
+        Ok(skip)
     }
 }
 
@@ -143,6 +190,7 @@ impl DeviceSender<'_> {
 pub struct Controller {
     discovery: Discovery,
     devices: Devices,
+    rejected_devices: Vec<RejectedDevice>,
     privacy_policy: Policy,
 }
 
@@ -154,6 +202,7 @@ impl Controller {
         Self {
             discovery,
             devices: Devices::new(),
+            rejected_devices: Vec::new(),
             privacy_policy: Policy::init(),
         }
     }
@@ -169,6 +218,7 @@ impl Controller {
         Self {
             discovery,
             devices,
+            rejected_devices: Vec::new(),
             privacy_policy: Policy::init(),
         }
     }
@@ -203,9 +253,13 @@ impl Controller {
     /// structure and all of its routes, some network failures or
     /// timeouts can prevent the effective sending.
     /// Moreover, the same issues can also affect the return response.
+    ///
+    /// Devices which cannot be contacted are ignored, while devices which
+    /// are not compatible with the controller are reported through
+    /// [`Self::rejected_devices`].
     #[inline]
     pub async fn discover(&mut self) -> Result<(), Error> {
-        self.devices = self.discovery.discover().await?;
+        (self.devices, self.rejected_devices) = self.discovery.discover().await?;
         Ok(())
     }
 
@@ -215,6 +269,13 @@ impl Controller {
         &self.devices
     }
 
+    /// Returns the devices rejected during the last discovery process,
+    /// together with the reasons of their rejection.
+    #[must_use]
+    pub fn rejected_devices(&self) -> &[RejectedDevice] {
+        &self.rejected_devices
+    }
+
     /// Builds a [`DeviceSender`] for the [`Device`] with the given identifier.
     ///
     /// # Errors
@@ -257,7 +318,9 @@ mod tests {
     use crate::policy::Policy;
     use crate::response::Response;
 
-    use crate::device::tests::{create_light, create_unknown};
+    use crate::device::tests::{
+        create_door_lock, create_halogen_lamp, create_light, create_unknown,
+    };
     use crate::discovery::tests::configure_discovery;
     use crate::tests::{Brightness, check_function_with_device};
 
@@ -272,6 +335,7 @@ mod tests {
             Controller {
                 discovery: configure_discovery(),
                 devices: Devices::new(),
+                rejected_devices: Vec::new(),
                 privacy_policy: Policy::init(),
             }
         );
@@ -291,11 +355,53 @@ mod tests {
             Controller {
                 discovery: configure_discovery(),
                 devices: Devices::from_devices(vec![create_light(), create_unknown()]),
+                rejected_devices: Vec::new(),
                 privacy_policy: Policy::init(),
             }
         );
     }
 
+    #[tokio::test]
+    async fn unconfirmed_request() {
+        let devices = Devices::from_devices(vec![create_door_lock()]);
+        let controller = Controller::from_devices(configure_discovery(), devices);
+        let device_sender = controller.device(0).unwrap();
+
+        let lock = device_sender.request("/lock").unwrap();
+        assert!(!lock.request.requires_confirmation());
+
+        let unlock = device_sender.request("/unlock").unwrap();
+        assert!(unlock.request.requires_confirmation());
+        assert_eq!(
+            unlock.send().await.err(),
+            Some(sender_error(
+                "The request with route `http://192.168.1.178:6000/door-lock/unlock` requires an explicit confirmation."
+            ))
+        );
+    }
+
+    #[test]
+    fn conditional_hazards_policy() {
+        let devices = Devices::from_devices(vec![create_halogen_lamp()]);
+        let controller = Controller::from_devices(configure_discovery(), devices)
+            .policy(Policy::new(Hazards::new().insert(Hazard::FireHazard)));
+        let device_sender = controller.device(0).unwrap();
+        let request = device_sender.device.request("/brightness").unwrap();
+
+        let evaluate = |parameters: &Parameters| {
+            device_sender
+                .evaluate_privacy_policy(request, parameters)
+                .unwrap()
+        };
+
+        // The default brightness does not cause a fire hazard.
+        assert!(!evaluate(&Parameters::new()));
+        assert!(!evaluate(Parameters::new().u8("brightness", 80)));
+
+        // The fire hazard occurs only above the brightness threshold.
+        assert!(evaluate(Parameters::new().u8("brightness", 90)));
+    }
+
     async fn check_ok_response_plain(device_sender: &DeviceSender<'_>, route: &str) {
         check_ok_response(device_sender, route, async move |request_sender| {
             request_sender.send().await
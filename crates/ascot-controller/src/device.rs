@@ -3,7 +3,7 @@ use std::net::IpAddr;
 
 use serde::Serialize;
 
-use ascot::device::{DeviceEnvironment, DeviceKind};
+use ascot::device::{DeviceEnvironment, DeviceIdentity, DeviceKind};
 use ascot::route::RouteConfigs;
 
 use crate::request::{Request, RequestInfo, create_requests};
@@ -60,6 +60,8 @@ pub struct Description {
     pub environment: DeviceEnvironment,
     /// Device main route.
     pub main_route: String,
+    /// Device identity.
+    pub identity: DeviceIdentity,
 }
 
 impl Description {
@@ -70,8 +72,16 @@ impl Description {
             kind,
             environment,
             main_route,
+            identity: DeviceIdentity::empty(),
         }
     }
+
+    /// Sets the [`DeviceIdentity`].
+    #[must_use]
+    pub fn identity(mut self, identity: DeviceIdentity) -> Self {
+        self.identity = identity;
+        self
+    }
 }
 
 /// A compliant device.
@@ -101,6 +111,7 @@ impl Device {
             route_configs,
             &network_info.last_reachable_address,
             &description.main_route,
+            description.kind,
             description.environment,
         );
 
@@ -247,7 +258,7 @@ pub(crate) mod tests {
 
     use ascot::device::{DeviceEnvironment, DeviceKind};
     use ascot::hazards::{Hazard, Hazards};
-    use ascot::parameters::Parameters;
+    use ascot::parameters::{Comparison, Condition, Parameters};
     use ascot::route::{Route, RouteConfigs};
 
     use super::{Description, Device, Devices, NetworkInformation, build_device_address};
@@ -335,6 +346,41 @@ pub(crate) mod tests {
         Device::new(network_info, description, route_configs)
     }
 
+    pub(crate) fn create_halogen_lamp() -> Device {
+        let network_info = create_network_info("192.168.1.180", 6500);
+        let description = create_description(DeviceKind::Light, "halogen-lamp/");
+
+        let brightness_route = Route::put("Brightness", "/brightness")
+            .description("Change the lamp brightness.")
+            .with_hazard(Hazard::ElectricEnergyConsumption)
+            .with_conditional_hazard(
+                Hazard::FireHazard,
+                Condition::new("brightness", Comparison::Greater, 80u8),
+            )
+            .with_parameters(Parameters::new().u8_with_limits("brightness", 50, 0, 100));
+
+        let route_configs = RouteConfigs::new().insert(brightness_route.serialize_data());
+
+        Device::new(network_info, description, route_configs)
+    }
+
+    pub(crate) fn create_door_lock() -> Device {
+        let network_info = create_network_info("192.168.1.178", 6000);
+        let description = create_description(DeviceKind::DoorLock, "door-lock/");
+
+        let lock_route = Route::put("Lock", "/lock").description("Lock the door.");
+
+        let unlock_route = Route::put("Unlock", "/unlock")
+            .description("Unlock the door.")
+            .with_hazard(Hazard::UnauthorisedPhysicalAccess);
+
+        let route_configs = RouteConfigs::new()
+            .insert(lock_route.serialize_data())
+            .insert(unlock_route.serialize_data());
+
+        Device::new(network_info, description, route_configs)
+    }
+
     #[test]
     fn check_devices() {
         let devices_vector = vec![create_light(), create_unknown()];
@@ -1,12 +1,15 @@
 use std::net::IpAddr;
 use std::time::Duration;
 
-use ascot::device::DeviceData;
+use ascot::device::{DeviceData, ProfileError};
+use ascot::protocol::{PROTOCOL_VERSION, PROTOCOL_VERSION_PROPERTY, ProtocolVersion};
 
-use mdns_sd::{IfKind, ResolvedService, ServiceDaemon, ServiceEvent, ServiceInfo};
+use mdns_sd::{IfKind, ResolvedService, ServiceDaemon, ServiceEvent, ServiceInfo, TxtProperties};
 
 use tracing::{info, warn};
 
+use serde::Deserialize;
+
 use crate::device::{Description, Device, Devices, NetworkInformation, build_device_address};
 use crate::error::Error;
 use crate::request::create_requests;
@@ -42,6 +45,65 @@ impl TransportProtocol {
     }
 }
 
+/// The reason why a discovered device has been rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectionReason {
+    /// The device does not advertise any protocol version.
+    MissingProtocolVersion,
+    /// The device advertises a malformed protocol version.
+    InvalidProtocolVersion(String),
+    /// The device protocol version is not supported.
+    UnsupportedProtocolVersion(ProtocolVersion),
+    /// The device data cannot be decoded.
+    InvalidData(String),
+    /// The device has neither a valid MAC address nor a unique identifier.
+    MissingIdentifier,
+    /// The device does not comply with the profile of its kind.
+    ProfileMismatch(ProfileError),
+}
+
+impl std::fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingProtocolVersion => "no protocol version has been advertised".fmt(f),
+            Self::InvalidProtocolVersion(version) => {
+                write!(f, "the `{version}` protocol version is malformed")
+            }
+            Self::UnsupportedProtocolVersion(version) => {
+                write!(f, "the {version} protocol version is not supported")
+            }
+            Self::InvalidData(error) => write!(f, "invalid device data: {error}"),
+            Self::MissingIdentifier => {
+                "neither valid MAC addresses nor a unique identifier have been found".fmt(f)
+            }
+            Self::ProfileMismatch(error) => {
+                write!(f, "the device does not comply with its profile: {error}")
+            }
+        }
+    }
+}
+
+/// A discovered device which has been rejected during a discovery process.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedDevice {
+    /// Device complete name.
+    pub name: String,
+    /// Device address.
+    pub address: String,
+    /// Rejection reason.
+    pub reason: RejectionReason,
+}
+
+impl RejectedDevice {
+    const fn new(name: String, address: String, reason: RejectionReason) -> Self {
+        Self {
+            name,
+            address,
+            reason,
+        }
+    }
+}
+
 /// Devices discovery.
 ///
 /// It detects all `ascot`-compliant [`Device`]s in a network.
@@ -54,6 +116,8 @@ pub struct Discovery {
     disable_ipv6: bool,
     disable_ip: Option<IpAddr>,
     disable_network_interface: Option<&'static str>,
+    min_protocol_version: ProtocolVersion,
+    max_protocol_version: ProtocolVersion,
 }
 
 impl Discovery {
@@ -68,6 +132,11 @@ impl Discovery {
             disable_ipv6: false,
             disable_ip: None,
             disable_network_interface: None,
+            // By default, only versions up to the one implemented by this
+            // crate are supported, since data added by newer minor versions
+            // would be silently ignored.
+            min_protocol_version: ProtocolVersion::new(PROTOCOL_VERSION.major, 0, 0),
+            max_protocol_version: PROTOCOL_VERSION,
         }
     }
 
@@ -121,11 +190,27 @@ impl Discovery {
         self
     }
 
-    pub(crate) async fn discover(&self) -> Result<Devices, Error> {
+    /// Sets the inclusive range of supported protocol versions.
+    ///
+    /// By default, all protocol versions with the same major number of
+    /// [`PROTOCOL_VERSION`], up to its minor number, are supported. Devices
+    /// advertising a protocol version outside of this range are rejected.
+    /// Patch releases of the upper bound are always supported.
+    ///
+    /// Raising the upper bound beyond [`PROTOCOL_VERSION`] accepts devices
+    /// whose newer data is ignored by this crate.
+    #[must_use]
+    pub const fn protocol_versions(mut self, min: ProtocolVersion, max: ProtocolVersion) -> Self {
+        self.min_protocol_version = min;
+        self.max_protocol_version = max;
+        self
+    }
+
+    pub(crate) async fn discover(&self) -> Result<(Devices, Vec<RejectedDevice>), Error> {
         // Discover devices.
         let discovery_info = self.discover_devices()?;
 
-        Self::obtain_devices_data(discovery_info).await
+        Ok(self.obtain_devices_data(discovery_info).await)
     }
 
     fn discover_devices(&self) -> Result<Vec<ResolvedService>, Error> {
@@ -197,42 +282,71 @@ impl Discovery {
     }
 
     async fn obtain_devices_data(
+        &self,
         discovery_service: Vec<ResolvedService>,
-    ) -> Result<Devices, Error> {
+    ) -> (Devices, Vec<RejectedDevice>) {
         // Devices collection.
         let mut devices = Devices::new();
+        // Rejected devices.
+        let mut rejected_devices = Vec::new();
 
         // Iterate over discovered metadata
         for service in discovery_service {
+            let scheme = service
+                .txt_properties
+                .get_property_val_str("scheme")
+                .unwrap_or("http");
+
+            // Avoid contacting a device whose advertised protocol version
+            // is not supported.
+            if let Err(reason) = self.check_protocol_property(&service.txt_properties) {
+                let address = service
+                    .addresses
+                    .iter()
+                    .next()
+                    .map_or_else(String::new, |address| {
+                        build_device_address(scheme, &address.to_ip_addr(), service.port)
+                    });
+                warn!("Ignoring device {address}: {reason}");
+                rejected_devices.push(RejectedDevice::new(service.fullname, address, reason));
+                continue;
+            }
+
             // Try to contact each available address for a device
             // to retrieve data.
             for address in &service.addresses {
-                let complete_address = build_device_address(
-                    service
-                        .txt_properties
-                        .get_property_val_str("scheme")
-                        .unwrap_or("http"),
-                    &address.to_ip_addr(),
-                    service.port,
-                );
+                let complete_address =
+                    build_device_address(scheme, &address.to_ip_addr(), service.port);
                 info!("Complete address: {complete_address}");
 
                 // Contact devices to retrieve their data
                 match reqwest::get(&complete_address).await {
                     Ok(response) => {
-                        let device_data: DeviceData = response.json().await?;
-
-                        if device_data.wifi_mac.is_none() && device_data.ethernet_mac.is_none() {
-                            warn!(
-                                "Ignoring device {complete_address} because no valid MAC addresses have been found"
-                            );
-                            continue;
-                        }
+                        let device_data = match response.json::<serde_json::Value>().await {
+                            Ok(value) => self.check_device_data(value),
+                            Err(e) => Err(RejectionReason::InvalidData(e.to_string())),
+                        };
+
+                        let device_data = match device_data {
+                            Ok(device_data) => device_data,
+                            Err(reason) => {
+                                warn!("Ignoring device {complete_address}: {reason}");
+                                rejected_devices.push(RejectedDevice::new(
+                                    service.fullname,
+                                    complete_address,
+                                    reason,
+                                ));
+                                // The device has been reached, so there is
+                                // no need to try with its other addresses.
+                                break;
+                            }
+                        };
 
                         let requests = create_requests(
                             device_data.route_configs,
                             &complete_address,
                             &device_data.main_route,
+                            device_data.kind,
                             device_data.environment,
                         );
 
@@ -240,7 +354,8 @@ impl Discovery {
                             device_data.kind,
                             device_data.environment,
                             device_data.main_route.into_owned(),
-                        );
+                        )
+                        .identity(device_data.identity);
 
                         let network_info = NetworkInformation::new(
                             service.fullname,
@@ -266,7 +381,67 @@ impl Discovery {
             }
         }
 
-        Ok(devices)
+        (devices, rejected_devices)
+    }
+
+    // Checks the protocol version advertised by the discovery service.
+    //
+    // Devices which do not advertise a protocol version in their properties
+    // are not rejected here, since their version is checked again on their
+    // data.
+    fn check_protocol_property(&self, properties: &TxtProperties) -> Result<(), RejectionReason> {
+        properties
+            .get_property_val_str(PROTOCOL_VERSION_PROPERTY)
+            .map_or(Ok(()), |version| {
+                let version = version
+                    .parse()
+                    .map_err(|_| RejectionReason::InvalidProtocolVersion(version.into()))?;
+                self.check_protocol_version(version)
+            })
+    }
+
+    fn check_protocol_version(&self, version: ProtocolVersion) -> Result<(), RejectionReason> {
+        if version.is_within(&self.min_protocol_version, &self.max_protocol_version) {
+            Ok(())
+        } else {
+            Err(RejectionReason::UnsupportedProtocolVersion(version))
+        }
+    }
+
+    // Checks device data.
+    //
+    // The protocol version is checked before decoding the whole data, so
+    // that an incompatible device is reported as such instead of as a
+    // decoding failure.
+    fn check_device_data(&self, value: serde_json::Value) -> Result<DeviceData, RejectionReason> {
+        let version = value
+            .get("protocol version")
+            .ok_or(RejectionReason::MissingProtocolVersion)?;
+
+        let version = ProtocolVersion::deserialize(version)
+            .map_err(|_| RejectionReason::InvalidProtocolVersion(version.to_string()))?;
+
+        self.check_protocol_version(version)?;
+
+        let device_data = DeviceData::deserialize(value)
+            .map_err(|e| RejectionReason::InvalidData(e.to_string()))?;
+
+        // A device is identified either by its MAC addresses or, when they
+        // cannot be retrieved, by its unique identifier.
+        if device_data.wifi_mac.is_none()
+            && device_data.ethernet_mac.is_none()
+            && device_data.identity.uuid.is_none()
+        {
+            return Err(RejectionReason::MissingIdentifier);
+        }
+
+        device_data
+            .kind
+            .profile()
+            .check_route_configs(&device_data.route_configs)
+            .map_err(RejectionReason::ProfileMismatch)?;
+
+        Ok(device_data)
     }
 
     // A discovered device is equal to another device when:
@@ -307,6 +482,14 @@ impl Discovery {
 pub(crate) mod tests {
     use std::time::Duration;
 
+    use ascot::device::{DeviceData, DeviceEnvironment, DeviceKind, ProfileError, Uuid};
+    use ascot::protocol::{PROTOCOL_VERSION, PROTOCOL_VERSION_PROPERTY, ProtocolVersion};
+    use ascot::route::{LightOffRoute, LightOnRoute, RouteConfigs};
+
+    use mdns_sd::ServiceInfo;
+
+    use serde_json::json;
+
     use tracing::warn;
 
     use serial_test::serial;
@@ -315,7 +498,7 @@ pub(crate) mod tests {
         DOMAIN, check_function_with_device, check_function_with_two_devices, compare_device_data,
     };
 
-    use super::Discovery;
+    use super::{Discovery, RejectionReason};
 
     pub(crate) fn configure_discovery() -> Discovery {
         Discovery::new(DOMAIN)
@@ -325,17 +508,181 @@ pub(crate) mod tests {
     }
 
     async fn discovery_comparison(devices_len: usize) {
-        let devices = configure_discovery().discover().await.unwrap();
+        let (devices, rejected_devices) = configure_discovery().discover().await.unwrap();
 
         // Count devices.
         assert_eq!(devices.len(), devices_len);
 
+        // All devices must be compatible.
+        assert!(rejected_devices.is_empty());
+
         // Iterate over devices and compare data.
         for device in devices {
             compare_device_data(&device);
         }
     }
 
+    fn light_data() -> DeviceData {
+        let route_configs = RouteConfigs::new()
+            .insert(LightOnRoute::put("On").into_route().serialize_data())
+            .insert(LightOffRoute::put("Off").into_route().serialize_data());
+
+        DeviceData::new(
+            DeviceKind::Light,
+            DeviceEnvironment::Os,
+            "/light",
+            route_configs,
+            Some([0, 1, 2, 3, 4, 5]),
+            None,
+            2,
+        )
+    }
+
+    #[test]
+    fn check_protocol_property() {
+        let discovery = configure_discovery()
+            .protocol_versions(ProtocolVersion::new(1, 0, 0), ProtocolVersion::new(1, 1, 0));
+
+        let check = |properties: &[(&str, &str)]| {
+            let info = ServiceInfo::new(
+                "_ascot._tcp.local.",
+                "device",
+                "device.local.",
+                "192.168.1.10",
+                3000,
+                properties,
+            )
+            .unwrap();
+            discovery.check_protocol_property(info.get_properties())
+        };
+
+        assert_eq!(check(&[]), Ok(()));
+        assert_eq!(check(&[(PROTOCOL_VERSION_PROPERTY, "1.1.3")]), Ok(()));
+        assert_eq!(
+            check(&[(PROTOCOL_VERSION_PROPERTY, "2.0.0")]),
+            Err(RejectionReason::UnsupportedProtocolVersion(
+                ProtocolVersion::new(2, 0, 0)
+            ))
+        );
+        assert_eq!(
+            check(&[(PROTOCOL_VERSION_PROPERTY, "latest")]),
+            Err(RejectionReason::InvalidProtocolVersion("latest".into()))
+        );
+
+        // A restricted range rejects newer minor versions.
+        assert_eq!(
+            check(&[(PROTOCOL_VERSION_PROPERTY, "1.2.0")]),
+            Err(RejectionReason::UnsupportedProtocolVersion(
+                ProtocolVersion::new(1, 2, 0)
+            ))
+        );
+    }
+
+    #[test]
+    fn check_newer_minor_protocol_version() {
+        let discovery = configure_discovery();
+
+        // Newer patch releases are supported by default.
+        let patch = ProtocolVersion::new(
+            PROTOCOL_VERSION.major,
+            PROTOCOL_VERSION.minor,
+            PROTOCOL_VERSION.patch + 1,
+        );
+        assert_eq!(discovery.check_protocol_version(patch), Ok(()));
+
+        // Newer minor versions are rejected by default.
+        let newer = ProtocolVersion::new(PROTOCOL_VERSION.major, PROTOCOL_VERSION.minor + 1, 0);
+        assert_eq!(
+            discovery.check_protocol_version(newer),
+            Err(RejectionReason::UnsupportedProtocolVersion(newer))
+        );
+
+        // Newer minor versions are supported only when explicitly allowed.
+        let discovery = configure_discovery()
+            .protocol_versions(ProtocolVersion::new(PROTOCOL_VERSION.major, 0, 0), newer);
+        assert_eq!(discovery.check_protocol_version(newer), Ok(()));
+
+        let next_major = ProtocolVersion::new(PROTOCOL_VERSION.major + 1, 0, 0);
+        assert_eq!(
+            discovery.check_protocol_version(next_major),
+            Err(RejectionReason::UnsupportedProtocolVersion(next_major))
+        );
+
+        // Data affecting policies is added through a major version, so
+        // previous major versions are rejected.
+        let previous_major = ProtocolVersion::new(PROTOCOL_VERSION.major - 1, 9, 0);
+        assert_eq!(
+            configure_discovery().check_protocol_version(previous_major),
+            Err(RejectionReason::UnsupportedProtocolVersion(previous_major))
+        );
+    }
+
+    #[test]
+    fn check_device_data() {
+        let discovery = configure_discovery();
+
+        // Compatible device.
+        let device_data = light_data();
+        assert_eq!(
+            discovery.check_device_data(serde_json::to_value(&device_data).unwrap()),
+            Ok(device_data)
+        );
+
+        // Device without a protocol version.
+        let mut value = serde_json::to_value(light_data()).unwrap();
+        value.as_object_mut().unwrap().remove("protocol version");
+        assert_eq!(
+            discovery.check_device_data(value),
+            Err(RejectionReason::MissingProtocolVersion)
+        );
+
+        // Incompatible device whose data cannot even be decoded.
+        let next_major = ProtocolVersion::new(PROTOCOL_VERSION.major + 1, 0, 0);
+        let value = json!({
+            "protocol version": next_major,
+            "kind": "A new device kind",
+        });
+        assert_eq!(
+            discovery.check_device_data(value),
+            Err(RejectionReason::UnsupportedProtocolVersion(next_major))
+        );
+
+        // Compatible device with malformed data.
+        let mut value = serde_json::to_value(light_data()).unwrap();
+        value.as_object_mut().unwrap().remove("kind");
+        assert!(matches!(
+            discovery.check_device_data(value),
+            Err(RejectionReason::InvalidData(_))
+        ));
+
+        // Device without MAC addresses, but with a unique identifier.
+        let mut device_data = light_data();
+        device_data.wifi_mac = None;
+        device_data.identity.uuid = Some(Uuid::from_u128(1));
+        assert_eq!(
+            discovery.check_device_data(serde_json::to_value(&device_data).unwrap()),
+            Ok(device_data)
+        );
+
+        // Device without any identifier.
+        let mut device_data = light_data();
+        device_data.wifi_mac = None;
+        assert_eq!(
+            discovery.check_device_data(serde_json::to_value(&device_data).unwrap()),
+            Err(RejectionReason::MissingIdentifier)
+        );
+
+        // Device which does not comply with its profile.
+        let mut device_data = light_data();
+        device_data.route_configs = RouteConfigs::new();
+        assert_eq!(
+            discovery.check_device_data(serde_json::to_value(&device_data).unwrap()),
+            Err(RejectionReason::ProfileMismatch(
+                ProfileError::MissingRoute { path: "/on" }
+            ))
+        );
+    }
+
     #[inline]
     async fn run_discovery_function<F, Fut>(name: &str, function: F)
     where
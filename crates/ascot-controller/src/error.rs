@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use ascot::parameters::ValidationError;
+
 /// All possible error kinds.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ErrorKind {
@@ -15,6 +17,8 @@ pub enum ErrorKind {
     StreamResponse,
     /// Errors in building the mechanism to send a request to a device.
     Sender,
+    /// Errors in scheduling jobs.
+    Scheduler,
 }
 
 impl ErrorKind {
@@ -26,6 +30,7 @@ impl ErrorKind {
             Self::JsonResponse => "Json Response",
             Self::StreamResponse => "Stream Response",
             Self::Sender => "Response Sender",
+            Self::Scheduler => "Scheduler",
         }
     }
 }
@@ -41,6 +46,7 @@ impl std::fmt::Display for ErrorKind {
 pub struct Error {
     kind: ErrorKind,
     description: Cow<'static, str>,
+    validation: Option<Box<ValidationError>>,
 }
 
 impl std::fmt::Display for Error {
@@ -62,6 +68,31 @@ impl Error {
         Self {
             kind,
             description: description.into(),
+            validation: None,
+        }
+    }
+
+    /// Returns the [`ErrorKind`].
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the [`ValidationError`] which caused the error.
+    ///
+    /// If [`None`], the error has not been caused by a wrong input parameter.
+    #[must_use]
+    #[inline]
+    pub fn validation(&self) -> Option<&ValidationError> {
+        self.validation.as_deref()
+    }
+
+    pub(crate) fn from_validation(validation: ValidationError) -> Self {
+        Self {
+            kind: ErrorKind::WrongParameter,
+            description: validation.to_string().into(),
+            validation: Some(Box::new(validation)),
         }
     }
 
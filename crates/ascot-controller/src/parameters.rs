@@ -1,4 +1,7 @@
-use ascot::parameters::{ParameterId, ParameterKind, ParametersData};
+use std::borrow::Cow;
+
+use ascot::parameters::{Color, ParameterValue, ParametersData, ValidationError};
+use ascot::units::Unit;
 
 use hashbrown::DefaultHashBuilder;
 
@@ -6,80 +9,19 @@ use indexmap::map::IndexMap;
 
 use tracing::error;
 
-use crate::error::{Error, ErrorKind};
-
-pub(crate) fn parameter_error(message: String) -> Error {
-    error!(message);
-    Error::new(ErrorKind::WrongParameter, message)
-}
-
-pub(crate) fn convert_to_parameter_value(parameter_kind: &ParameterKind) -> ParameterValue {
-    match parameter_kind {
-        ParameterKind::Bool { default } => ParameterValue::Bool(*default),
-        ParameterKind::U8 { default, .. } => ParameterValue::U8(*default),
-        ParameterKind::U16 { default, .. } => ParameterValue::U16(*default),
-        ParameterKind::U32 { default, .. } => ParameterValue::U32(*default),
-        ParameterKind::U64 { default, .. } | ParameterKind::RangeU64 { default, .. } => {
-            ParameterValue::U64(*default)
-        }
-        ParameterKind::F32 { default, .. } => ParameterValue::F32(*default),
-        ParameterKind::F64 { default, .. } | ParameterKind::RangeF64 { default, .. } => {
-            ParameterValue::F64(*default)
-        }
-        ParameterKind::CharsSequence { default, .. } => ParameterValue::String(default.to_string()),
-    }
-}
+use crate::error::Error;
 
-#[derive(Debug, Clone)]
-pub(crate) enum ParameterValue {
-    Bool(bool),
-    U8(u8),
-    U16(u16),
-    U32(u32),
-    U64(u64),
-    F32(f32),
-    F64(f64),
-    String(String),
-}
-
-impl ParameterValue {
-    pub(crate) fn as_string(&self) -> String {
-        match self {
-            Self::Bool(v) => v.to_string(),
-            Self::U8(v) => v.to_string(),
-            Self::U16(v) => v.to_string(),
-            Self::U32(v) => v.to_string(),
-            Self::U64(v) => v.to_string(),
-            Self::F32(v) => v.to_string(),
-            Self::F64(v) => v.to_string(),
-            Self::String(v) => v.to_string(),
-        }
-    }
-
-    const fn compare_with_kind(&self, parameter_kind: &ParameterKind) -> bool {
-        matches!(
-            (self, parameter_kind),
-            (Self::Bool(_), ParameterKind::Bool { .. })
-                | (Self::U8(_), ParameterKind::U8 { .. })
-                | (Self::U16(_), ParameterKind::U16 { .. })
-                | (Self::U32(_), ParameterKind::U32 { .. })
-                | (
-                    Self::U64(_),
-                    ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. }
-                )
-                | (Self::F32(_), ParameterKind::F32 { .. })
-                | (
-                    Self::F64(_),
-                    ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. }
-                )
-                | (Self::String(_), ParameterKind::CharsSequence { .. })
-        )
-    }
+pub(crate) fn parameter_error(validation_error: ValidationError) -> Error {
+    error!("{validation_error}");
+    Error::from_validation(validation_error)
 }
 
 /// Route input parameters.
 #[derive(Debug, Clone)]
-pub struct Parameters<'a>(IndexMap<&'a str, ParameterValue, DefaultHashBuilder>);
+pub struct Parameters<'a> {
+    values: IndexMap<&'a str, ParameterValue, DefaultHashBuilder>,
+    units: IndexMap<&'a str, Unit, DefaultHashBuilder>,
+}
 
 impl Default for Parameters<'_> {
     fn default() -> Self {
@@ -92,7 +34,10 @@ impl<'a> Parameters<'a> {
     #[must_use]
     #[inline]
     pub fn new() -> Self {
-        Self(IndexMap::with_hasher(DefaultHashBuilder::default()))
+        Self {
+            values: IndexMap::with_hasher(DefaultHashBuilder::default()),
+            units: IndexMap::with_hasher(DefaultHashBuilder::default()),
+        }
     }
 
     /// Adds a [`bool`] value.
@@ -125,6 +70,30 @@ impl<'a> Parameters<'a> {
         self.add_value_parameter(name, ParameterValue::U64(value))
     }
 
+    /// Adds an [`i8`] parameter.
+    #[inline]
+    pub fn i8(&mut self, name: &'a str, value: i8) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::I8(value))
+    }
+
+    /// Adds an [`i16`] parameter.
+    #[inline]
+    pub fn i16(&mut self, name: &'a str, value: i16) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::I16(value))
+    }
+
+    /// Adds an [`i32`] parameter.
+    #[inline]
+    pub fn i32(&mut self, name: &'a str, value: i32) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::I32(value))
+    }
+
+    /// Adds an [`i64`] parameter.
+    #[inline]
+    pub fn i64(&mut self, name: &'a str, value: i64) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::I64(value))
+    }
+
     /// Adds a [`f32`] parameter.
     #[inline]
     pub fn f32(&mut self, name: &'a str, value: f32) -> &mut Self {
@@ -140,31 +109,321 @@ impl<'a> Parameters<'a> {
     /// Adds a characters sequence.
     #[inline]
     pub fn characters_sequence(&mut self, name: &'a str, value: String) -> &mut Self {
-        self.add_value_parameter(name, ParameterValue::String(value))
+        self.add_value_parameter(name, ParameterValue::CharsSequence(value))
+    }
+
+    /// Adds a label chosen among a set of labels.
+    #[inline]
+    pub fn choice(&mut self, name: &'a str, label: impl Into<String>) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::CharsSequence(label.into()))
+    }
+
+    /// Adds a [`Color`] parameter.
+    #[inline]
+    pub fn color(&mut self, name: &'a str, value: Color) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::Color(value))
+    }
+
+    /// Adds a value expressed in the given [`Unit`].
+    ///
+    /// Before validating the parameters, the value is converted into the
+    /// unit of the parameter.
+    #[inline]
+    pub fn measure(&mut self, name: &'a str, value: f64, unit: Unit) -> &mut Self {
+        self.add_value_parameter(name, ParameterValue::F64(value));
+        self.units.insert(name, unit);
+        self
+    }
+
+    /// Adds a list of values.
+    #[inline]
+    pub fn list<V: Into<ParameterValue>>(
+        &mut self,
+        name: &'a str,
+        values: impl IntoIterator<Item = V>,
+    ) -> &mut Self {
+        self.add_value_parameter(
+            name,
+            ParameterValue::List(values.into_iter().map(Into::into).collect()),
+        )
+    }
+
+    /// Adds an object composed of the given fields.
+    #[inline]
+    pub fn object(&mut self, name: &'a str, fields: &Parameters<'_>) -> &mut Self {
+        self.add_value_parameter(
+            name,
+            ParameterValue::Object(
+                fields
+                    .values
+                    .iter()
+                    .map(|(name, value)| ((*name).into(), value.clone()))
+                    .collect(),
+            ),
+        )
     }
 
     pub(crate) fn get<'b>(&'b self, name: &'b str) -> Option<&'b ParameterValue> {
-        self.0.get(name)
+        self.values.get(name)
     }
 
     pub(crate) fn check_parameters(&self, parameters_data: &ParametersData) -> Result<(), Error> {
-        for (name, parameter_value) in &self.0 {
-            let Some(parameter_kind) = parameters_data.get(*name) else {
-                return Err(parameter_error(format!("`{name}` does not exist")));
+        parameters_data
+            .validate(&self.values)
+            .map_err(parameter_error)?;
+
+        parameters_data
+            .validate_constraints(&self.supplied())
+            .map_err(parameter_error)
+    }
+
+    // Returns the supplied values together with their names.
+    pub(crate) fn supplied(&self) -> Vec<(&str, &ParameterValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (*name, value))
+            .collect()
+    }
+
+    // Converts the values expressed in a unit into the units of their
+    // parameters.
+    pub(crate) fn convert_units(
+        &self,
+        parameters_data: &ParametersData,
+    ) -> Result<Cow<'_, Self>, Error> {
+        if self.units.is_empty() {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        let mut parameters = self.clone();
+        for (name, unit) in &self.units {
+            let (Some(parameter), Some(ParameterValue::F64(value))) =
+                (parameters_data.get(*name), self.values.get(name))
+            else {
+                continue;
             };
 
-            if !parameter_value.compare_with_kind(parameter_kind) {
-                return Err(parameter_error(format!(
-                    "`{name}` must be of type `{}`",
-                    ParameterId::from_parameter_kind(parameter_kind).as_type(),
-                )));
-            }
+            let converted = parameter.convert(*value, *unit).map_err(|violation| {
+                parameter_error(ValidationError {
+                    name: (*name).into(),
+                    value: ParameterValue::F64(*value),
+                    violation,
+                })
+            })?;
+            parameters.values.insert(name, converted);
         }
-        Ok(())
+        parameters.units.clear();
+
+        Ok(Cow::Owned(parameters))
     }
 
     fn add_value_parameter(&mut self, name: &'a str, parameter_value: ParameterValue) -> &mut Self {
-        self.0.insert(name, parameter_value);
+        self.values.insert(name, parameter_value);
+        self.units.shift_remove(name);
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ascot::parameters::{
+        Color, DecimalPrecision, ParameterValue, Parameters as AscotParameters, ParametersData,
+        ValidationError, Violation,
+    };
+
+    use ascot::units::Unit;
+
+    use crate::error::ErrorKind;
+
+    use super::Parameters;
+
+    fn parameters_data() -> ParametersData {
+        AscotParameters::new()
+            .u8("u8", 0)
+            .u8_with_limits("brightness", 5, 0, 20)
+            .f32_with_limits("f32", 0., -1., 1., DecimalPrecision::OneDigit)
+            .rangeu64_with_default("rangeu64", (2, 20, 3), 5)
+            .rangef64("rangef64", (0.5, 2., 0.25))
+            .i16_with_limits("offset", 0, -10, 10)
+            .characters_sequence_with_length("greeting", "hello", 5)
+            .choice("format", &["random", "closest", "exact"])
+            .color_temperature("temperature", 4000, 2700, 6500)
+            .color("tint", Color::rgb(255, 255, 255))
+            .serialize_data()
+    }
+
+    fn check(parameters: &Parameters) -> Result<(), ValidationError> {
+        parameters
+            .check_parameters(&parameters_data())
+            .map_err(|e| e.validation().cloned().unwrap())
+    }
+
+    #[test]
+    fn valid_parameters() {
+        assert!(
+            check(
+                Parameters::new()
+                    .u8("u8", u8::MAX)
+                    .u8("brightness", 20)
+                    .f32("f32", -0.3)
+                    .u64("rangeu64", 20)
+                    .f64("rangef64", 1.75)
+                    .i16("offset", -10)
+                    .characters_sequence("greeting", "hi".into())
+                    .choice("format", "exact")
+                    .color("temperature", Color::kelvin(2700))
+                    .color("tint", Color::rgb(0, 128, 255))
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_parameters() {
+        let error = check(Parameters::new().u8("brightness", 21)).unwrap_err();
+        assert_eq!(
+            error,
+            ValidationError {
+                name: "brightness".into(),
+                value: ParameterValue::U8(21),
+                violation: Violation::OutOfRange {
+                    min: ParameterValue::U8(0),
+                    max: ParameterValue::U8(20),
+                },
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "`brightness` value `21` is outside of [0, 20]"
+        );
+
+        assert_eq!(
+            check(Parameters::new().f32("f32", 1.5))
+                .unwrap_err()
+                .violation,
+            Violation::OutOfRange {
+                min: ParameterValue::F32(-1.),
+                max: ParameterValue::F32(1.),
+            }
+        );
+
+        assert_eq!(
+            check(Parameters::new().i16("offset", -11))
+                .unwrap_err()
+                .to_string(),
+            "`offset` value `-11` is outside of [-10, 10]"
+        );
+
+        // Wrong signedness.
+        assert!(check(Parameters::new().u16("offset", 1)).is_err());
+    }
+
+    #[test]
+    fn misaligned_parameters() {
+        let error = check(Parameters::new().u64("rangeu64", 6)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`rangeu64` value `6` is not reachable from `2` with step `3`"
+        );
+
+        assert!(check(Parameters::new().f64("rangef64", 1.6)).is_err());
+        assert!(check(Parameters::new().f64("rangef64", 0.25)).is_err());
+        assert!(check(Parameters::new().f32("f32", 0.25)).is_err());
+    }
+
+    #[test]
+    fn too_long_parameters() {
+        let error = Parameters::new()
+            .characters_sequence("greeting", "hello world".into())
+            .check_parameters(&parameters_data())
+            .unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::WrongParameter);
+        assert_eq!(
+            error.validation().unwrap().violation,
+            Violation::TooLong { length: 5 }
+        );
+    }
+
+    #[test]
+    fn invalid_choice_parameters() {
+        let error = check(Parameters::new().choice("format", "highest")).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "`format` value `highest` is not one of [random, closest, exact]"
+        );
+    }
+
+    #[test]
+    fn invalid_color_parameters() {
+        assert_eq!(
+            check(Parameters::new().color("temperature", Color::kelvin(7000)))
+                .unwrap_err()
+                .to_string(),
+            "`temperature` value `kelvin:7000` is outside of [kelvin:2700, kelvin:6500]"
+        );
+
+        assert_eq!(
+            check(Parameters::new().color("tint", Color::kelvin(3000)))
+                .unwrap_err()
+                .to_string(),
+            "`tint` value `kelvin:3000` must adopt the `rgb` color model"
+        );
+    }
+
+    #[test]
+    fn measured_parameters() {
+        let parameters_data = AscotParameters::new()
+            .f64_with_limits("temperature", 20., 5., 30., DecimalPrecision::OneDigit)
+            .unit("temperature", Unit::Celsius)
+            .u8("level", 0)
+            .serialize_data();
+        let check = |parameters: &Parameters| {
+            parameters
+                .convert_units(&parameters_data)
+                .and_then(|parameters| {
+                    parameters.check_parameters(&parameters_data)?;
+                    Ok(parameters.get("temperature").cloned())
+                })
+                .map_err(|e| e.validation().unwrap().to_string())
+        };
+
+        assert_eq!(
+            check(Parameters::new().measure("temperature", 77., Unit::Fahrenheit)),
+            Ok(Some(ParameterValue::F64(25.)))
+        );
+        // Values which are not exactly converted are rounded to the decimal
+        // precision of the parameter.
+        assert_eq!(
+            check(Parameters::new().measure("temperature", 70., Unit::Fahrenheit)),
+            Ok(Some(ParameterValue::F64(21.1)))
+        );
+        assert_eq!(
+            check(Parameters::new().measure("temperature", 72., Unit::Fahrenheit)),
+            Ok(Some(ParameterValue::F64(22.2)))
+        );
+        assert_eq!(
+            check(Parameters::new().measure("temperature", 212., Unit::Fahrenheit)),
+            Err("`temperature` value `100` is outside of [5, 30]".into())
+        );
+        assert_eq!(
+            check(Parameters::new().measure("temperature", 1., Unit::Watt)),
+            Err("`temperature` value `1` cannot be converted from `W` into `°C`".into())
+        );
+        assert_eq!(
+            check(Parameters::new().measure("level", 1., Unit::Percent)),
+            Err("`level` value `1` cannot be converted from `%`, since `level` has no unit".into())
+        );
+
+        // A plain value replaces a measured one.
+        assert_eq!(
+            check(
+                Parameters::new()
+                    .measure("temperature", 77., Unit::Fahrenheit)
+                    .f64("temperature", 22.)
+            ),
+            Ok(Some(ParameterValue::F64(22.)))
+        );
+    }
+}
@@ -1,17 +1,84 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use ascot::hazards::Hazards;
+use ascot::hazards::{Category, HazardLevels, Hazards, Severity};
 
 // TODO: Eventually rewrite policy IDs as &'static str.
 
+// Minimum severities, for each hazard category, which block a request.
+type SeverityThresholds = HashMap<Category, Severity>;
+
+// Retrieves the hazards blocked because of their identity, their category
+// or their severity.
+fn blocked_hazards(
+    blocked: Option<&Hazards>,
+    categories: Option<&HashSet<Category>>,
+    thresholds: Option<&SeverityThresholds>,
+    hazards: &Hazards,
+    hazard_levels: &HazardLevels,
+) -> Hazards {
+    // Blocking an hazard also blocks the hazards it implies.
+    let blocked = blocked.map(Hazards::closure);
+
+    let mut blocked_hazards = Hazards::new();
+    for hazard in hazards {
+        // An hazard is blocked also when one of the hazards it implies is
+        // blocked, since it causes them too.
+        let implied = Hazards::new().insert(*hazard).closure();
+
+        let blocked_by_identity = blocked
+            .as_ref()
+            .is_some_and(|blocked| implied.iter().any(|implied| blocked.contains(implied)));
+        let blocked_by_category = categories.is_some_and(|categories| {
+            implied
+                .iter()
+                .any(|implied| categories.contains(&implied.category()))
+        });
+        // An hazard declared without a severity is assumed to have the worst
+        // one, so that a threshold cannot be bypassed by omitting it.
+        let blocked_by_severity = thresholds
+            .and_then(|thresholds| thresholds.get(&hazard.category()))
+            .is_some_and(|threshold| {
+                hazard_levels
+                    .get(hazard)
+                    .map_or(Severity::Catastrophic, |level| level.severity)
+                    >= *threshold
+            });
+
+        if blocked_by_identity || blocked_by_category || blocked_by_severity {
+            blocked_hazards.add(*hazard);
+        }
+    }
+    blocked_hazards
+}
+
 /// A privacy policy manager.
 ///
 /// A privacy policy can be applied to all requests sent to devices or just to
 /// specific ones.
+///
+/// A request can be blocked because of the identity of its hazards, because
+/// of their [`Category`], or because the severity of its hazards reaches a
+/// threshold defined for their [`Category`]. Hazards declared without a
+/// [`Severity`] are treated as [`Severity::Catastrophic`], hence they are
+/// always blocked by a threshold.
+///
+/// Blocking an hazard also blocks all the hazards it implies, for example,
+/// blocking [`Hazard::AudioVideoRecordAndStore`](ascot::hazards::Hazard::AudioVideoRecordAndStore)
+/// also blocks [`Hazard::VideoDisplay`](ascot::hazards::Hazard::VideoDisplay).
+/// Likewise, an hazard is blocked when one of the hazards it implies is
+/// blocked, so blocking [`Hazard::VideoDisplay`](ascot::hazards::Hazard::VideoDisplay)
+/// also blocks [`Hazard::VideoRecordAndStore`](ascot::hazards::Hazard::VideoRecordAndStore).
+///
+/// Custom hazards declared by device vendors are unknown in advance, so
+/// policies can be applied to them only through their [`Category`].
 #[derive(Debug, PartialEq)]
 pub struct Policy {
     block_on_hazards: Hazards,
     block_device_on_hazards: HashMap<usize, Hazards>,
+    blocked_categories: HashSet<Category>,
+    device_blocked_categories: HashMap<usize, HashSet<Category>>,
+    severity_thresholds: SeverityThresholds,
+    device_severity_thresholds: HashMap<usize, SeverityThresholds>,
 }
 
 impl Policy {
@@ -22,7 +89,7 @@ impl Policy {
     pub fn new(block_on_hazards: Hazards) -> Self {
         Self {
             block_on_hazards,
-            block_device_on_hazards: HashMap::new(),
+            ..Self::init()
         }
     }
 
@@ -44,43 +111,105 @@ impl Policy {
         self
     }
 
+    /// Adds a [`Policy`] rule to block the sending of all requests with
+    /// hazards of the given [`Category`].
+    #[must_use]
+    #[inline]
+    pub fn block_on_category(mut self, category: Category) -> Self {
+        self.blocked_categories.insert(category);
+        self
+    }
+
+    /// Adds a [`Policy`] rule to block the sending of requests for the
+    /// [`crate::device::Device`] with the given identifier, whether they
+    /// have hazards of the given [`Category`].
+    #[must_use]
+    #[inline]
+    pub fn block_device_on_category(mut self, id: usize, category: Category) -> Self {
+        self.device_blocked_categories
+            .entry(id)
+            .or_default()
+            .insert(category);
+        self
+    }
+
+    /// Adds a [`Policy`] rule to block the sending of all requests with
+    /// hazards of the given [`Category`] whose [`Severity`] is equal to or
+    /// greater than the given one.
+    #[must_use]
+    #[inline]
+    pub fn block_on_severity(mut self, category: Category, severity: Severity) -> Self {
+        self.severity_thresholds.insert(category, severity);
+        self
+    }
+
+    /// Adds a [`Policy`] rule to block the sending of requests for the
+    /// [`crate::device::Device`] with the given identifier, whether their
+    /// hazards of the given [`Category`] have a [`Severity`] equal to or
+    /// greater than the given one.
+    #[must_use]
+    #[inline]
+    pub fn block_device_on_severity(
+        mut self,
+        id: usize,
+        category: Category,
+        severity: Severity,
+    ) -> Self {
+        self.device_severity_thresholds
+            .entry(id)
+            .or_default()
+            .insert(category, severity);
+        self
+    }
+
     pub(crate) fn init() -> Self {
         Self {
             block_on_hazards: Hazards::new(),
             block_device_on_hazards: HashMap::new(),
+            blocked_categories: HashSet::new(),
+            device_blocked_categories: HashMap::new(),
+            severity_thresholds: HashMap::new(),
+            device_severity_thresholds: HashMap::new(),
         }
     }
 
-    pub(crate) fn global_blocked_hazards(&self, hazards: &Hazards) -> Hazards {
-        let mut blocked_hazards = Hazards::new();
-        for hazard in hazards {
-            if self.block_on_hazards.contains(hazard) {
-                blocked_hazards.add(*hazard);
-            }
-        }
-        blocked_hazards
+    pub(crate) fn global_blocked_hazards(
+        &self,
+        hazards: &Hazards,
+        hazard_levels: &HazardLevels,
+    ) -> Hazards {
+        blocked_hazards(
+            Some(&self.block_on_hazards),
+            Some(&self.blocked_categories),
+            Some(&self.severity_thresholds),
+            hazards,
+            hazard_levels,
+        )
     }
 
-    pub(crate) fn local_blocked_hazards(&self, id: usize, hazards: &Hazards) -> Hazards {
-        if let Some(local_hazards) = self.block_device_on_hazards.get(&id) {
-            let mut blocked_hazards = Hazards::new();
-            for hazard in hazards {
-                if local_hazards.contains(hazard) {
-                    blocked_hazards.add(*hazard);
-                }
-            }
-            blocked_hazards
-        } else {
-            Hazards::new()
-        }
+    pub(crate) fn local_blocked_hazards(
+        &self,
+        id: usize,
+        hazards: &Hazards,
+        hazard_levels: &HazardLevels,
+    ) -> Hazards {
+        blocked_hazards(
+            self.block_device_on_hazards.get(&id),
+            self.device_blocked_categories.get(&id),
+            self.device_severity_thresholds.get(&id),
+            hazards,
+            hazard_levels,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
-    use ascot::hazards::{Hazard, Hazards};
+    use ascot::hazards::{
+        Category, CustomHazard, Hazard, HazardLevel, HazardLevels, Hazards, Severity,
+    };
 
     use super::Policy;
 
@@ -102,6 +231,10 @@ mod tests {
             &Policy {
                 block_on_hazards,
                 block_device_on_hazards: devices_hazards,
+                blocked_categories: HashSet::new(),
+                device_blocked_categories: HashMap::new(),
+                severity_thresholds: HashMap::new(),
+                device_severity_thresholds: HashMap::new(),
             }
         );
     }
@@ -114,7 +247,11 @@ mod tests {
             policy,
             Policy {
                 block_on_hazards: hazards,
-                block_device_on_hazards: HashMap::new()
+                block_device_on_hazards: HashMap::new(),
+                blocked_categories: HashSet::new(),
+                device_blocked_categories: HashMap::new(),
+                severity_thresholds: HashMap::new(),
+                device_severity_thresholds: HashMap::new(),
             }
         );
     }
@@ -141,4 +278,107 @@ mod tests {
 
         check_device_policies(&policy, global_hazards, &local_hazards);
     }
+
+    #[test]
+    fn severity_policy() {
+        let policy = Policy::new(Hazards::new().insert(Hazard::TakePictures))
+            .block_on_severity(Category::Safety, Severity::High)
+            .block_device_on_severity(1, Category::Financial, Severity::Moderate);
+
+        let hazards = Hazards::new()
+            .insert(Hazard::FireHazard)
+            .insert(Hazard::SpoiledFood)
+            .insert(Hazard::TakePictures)
+            .insert(Hazard::SpendMoney)
+            .insert(Hazard::WaterFlooding);
+
+        let hazard_levels = HazardLevels::new()
+            .insert(Hazard::FireHazard, HazardLevel::new(Severity::Catastrophic))
+            .insert(Hazard::SpoiledFood, HazardLevel::new(Severity::Minor))
+            .insert(Hazard::SpendMoney, HazardLevel::new(Severity::High));
+
+        // Hazards are blocked by identity or by severity, while hazards
+        // without a level are always blocked by a threshold.
+        assert_eq!(
+            policy.global_blocked_hazards(&hazards, &hazard_levels),
+            Hazards::new()
+                .insert(Hazard::FireHazard)
+                .insert(Hazard::TakePictures)
+                .insert(Hazard::WaterFlooding)
+        );
+
+        // Device thresholds apply only to their device.
+        assert_eq!(
+            policy.local_blocked_hazards(1, &hazards, &hazard_levels),
+            Hazards::new().insert(Hazard::SpendMoney)
+        );
+        assert!(
+            policy
+                .local_blocked_hazards(2, &hazards, &hazard_levels)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn category_policy() {
+        // A custom hazard unknown to the controller.
+        let dosing = Hazard::Custom(CustomHazard::new(1000, Category::Safety).unwrap());
+
+        let policy = Policy::init()
+            .block_on_category(Category::Privacy)
+            .block_device_on_category(1, Category::Safety);
+
+        let hazards = Hazards::new()
+            .insert(Hazard::TakePictures)
+            .insert(Hazard::SpendMoney)
+            .insert(dosing);
+
+        assert_eq!(
+            policy.global_blocked_hazards(&hazards, &HazardLevels::new()),
+            Hazards::new().insert(Hazard::TakePictures)
+        );
+
+        // Custom hazards are blocked through their category.
+        assert_eq!(
+            policy.local_blocked_hazards(1, &hazards, &HazardLevels::new()),
+            Hazards::new().insert(dosing)
+        );
+        assert!(
+            policy
+                .local_blocked_hazards(2, &hazards, &HazardLevels::new())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn implied_hazards_policy() {
+        let policy = Policy::new(Hazards::new().insert(Hazard::AudioVideoRecordAndStore))
+            .block_device_on_hazards(1, Hazards::new().insert(Hazard::PaySubscriptionFee));
+
+        let hazards = Hazards::new()
+            .insert(Hazard::VideoDisplay)
+            .insert(Hazard::TakePictures)
+            .insert(Hazard::SpendMoney);
+
+        // Hazards implied by a blocked hazard are blocked too.
+        assert_eq!(
+            policy.global_blocked_hazards(&hazards, &HazardLevels::new()),
+            Hazards::new().insert(Hazard::VideoDisplay)
+        );
+        assert_eq!(
+            policy.local_blocked_hazards(1, &hazards, &HazardLevels::new()),
+            Hazards::new().insert(Hazard::SpendMoney)
+        );
+
+        // Hazards implying a blocked hazard are blocked too.
+        assert_eq!(
+            Policy::new(Hazards::new().insert(Hazard::VideoDisplay)).global_blocked_hazards(
+                &Hazards::new()
+                    .insert(Hazard::VideoRecordAndStore)
+                    .insert(Hazard::TakePictures),
+                &HazardLevels::new()
+            ),
+            Hazards::new().insert(Hazard::VideoRecordAndStore)
+        );
+    }
 }
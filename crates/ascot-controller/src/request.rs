@@ -4,16 +4,22 @@ use std::future::Future;
 
 use serde::Serialize;
 
+use serde_json::{Map, Value};
+
 use tracing::error;
 
-use ascot::device::DeviceEnvironment;
-use ascot::hazards::Hazards;
-use ascot::parameters::ParametersData;
+use ascot::device::{DeviceEnvironment, DeviceKind};
+use ascot::hazards::{HazardLevels, Hazards};
+use ascot::parameters::{
+    ParameterKind, ParameterValue, ParametersData, ValidationError, Violation,
+};
 use ascot::response::{ResponseKind, SERIALIZATION_ERROR};
-use ascot::route::{RestKind, RouteConfig, RouteConfigs};
+use ascot::route::{
+    ConditionalHazard, RestKind, RouteConfig, RouteConfigs, RouteTranslations, effective_hazards,
+};
 
 use crate::error::{Error, ErrorKind};
-use crate::parameters::{Parameters, convert_to_parameter_value};
+use crate::parameters::{Parameters, parameter_error};
 use crate::response::{InfoResponseParser, OkResponseParser, Response, SerialResponseParser};
 
 fn slash_end(s: &str) -> &str {
@@ -36,14 +42,47 @@ fn slash_start_end(s: &str) -> &str {
     slash_start(slash_end(s))
 }
 
+// Values are sent in their textual form, while lists and objects are sent
+// as JSON arrays and objects.
+fn json_value(value: &ParameterValue) -> Value {
+    match value {
+        ParameterValue::List(values) => values.iter().map(json_value).collect(),
+        ParameterValue::Object(fields) => fields
+            .iter()
+            .map(|(name, value)| (name.clone(), json_value(value)))
+            .collect::<Map<_, _>>()
+            .into(),
+        value => Value::String(value.to_string()),
+    }
+}
+
+// Returns the value of a parameter, or its default value when missing.
+//
+// When the presence of a missing parameter is checked by its constraints,
+// no value is returned, since its default value cannot be used in its place.
+fn parameter_value(
+    name: &str,
+    parameters_data: &ParametersData,
+    parameter_kind: &ParameterKind,
+    parameters: &Parameters,
+) -> Option<Value> {
+    match parameters.get(name) {
+        Some(value) => Some(json_value(value)),
+        None if parameters_data.is_constrained(name) => None,
+        None => Some(json_value(&ParameterValue::from_parameter_kind(
+            parameter_kind,
+        ))),
+    }
+}
+
 #[derive(Debug, PartialEq)]
 struct RequestData {
     request: String,
-    parameters: HashMap<String, String>,
+    parameters: HashMap<String, Value>,
 }
 
 impl RequestData {
-    const fn new(request: String, parameters: HashMap<String, String>) -> Self {
+    const fn new(request: String, parameters: HashMap<String, Value>) -> Self {
         Self {
             request,
             parameters,
@@ -55,15 +94,16 @@ pub(crate) fn create_requests(
     route_configs: RouteConfigs,
     complete_address: &str,
     main_route: &str,
+    kind: DeviceKind,
     environment: DeviceEnvironment,
 ) -> HashMap<String, Request> {
     route_configs
         .into_iter()
         .map(|route| {
-            (
-                route.data.path.to_string(),
-                Request::new(complete_address, main_route, environment, route),
-            )
+            let path = route.data.path.to_string();
+            let mut request = Request::new(complete_address, main_route, environment, route);
+            request.confirmation = kind.requires_confirmation(&path);
+            (path, request)
         })
         .collect()
 }
@@ -78,10 +118,19 @@ pub struct RequestInfo<'device> {
     pub rest_kind: RestKind,
     /// Route hazards.
     pub hazards: &'device Hazards,
+    /// Levels of the route hazards.
+    pub hazard_levels: &'device HazardLevels,
+    /// Route hazards which occur only when a condition on the parameters
+    /// is met.
+    pub conditional_hazards: &'device [ConditionalHazard],
+    /// Translations of the route name and description.
+    pub translations: &'device RouteTranslations,
     /// Parameters data.
     pub parameters_data: &'device ParametersData,
     /// Response kind.
     pub response_kind: ResponseKind,
+    /// Whether the request must be explicitly confirmed before being sent.
+    pub requires_confirmation: bool,
 }
 
 impl<'device> RequestInfo<'device> {
@@ -91,8 +140,12 @@ impl<'device> RequestInfo<'device> {
             description: request.description.as_deref(),
             rest_kind: request.kind,
             hazards: &request.hazards,
+            hazard_levels: &request.hazard_levels,
+            conditional_hazards: &request.conditional_hazards,
+            translations: &request.translations,
             parameters_data: &request.parameters_data,
             response_kind: request.response_kind,
+            requires_confirmation: request.confirmation,
         }
     }
 }
@@ -107,11 +160,15 @@ impl<'device> RequestInfo<'device> {
 pub struct Request {
     pub(crate) kind: RestKind,
     pub(crate) hazards: Hazards,
+    pub(crate) hazard_levels: HazardLevels,
+    pub(crate) conditional_hazards: Vec<ConditionalHazard>,
     pub(crate) route: String,
     pub(crate) description: Option<String>,
+    pub(crate) translations: RouteTranslations,
     pub(crate) parameters_data: ParametersData,
     pub(crate) response_kind: ResponseKind,
     pub(crate) device_environment: DeviceEnvironment,
+    pub(crate) confirmation: bool,
 }
 
 impl Request {
@@ -121,6 +178,33 @@ impl Request {
         &self.hazards
     }
 
+    /// Returns an immutable reference to request [`HazardLevels`].
+    #[must_use]
+    pub fn hazard_levels(&self) -> &HazardLevels {
+        &self.hazard_levels
+    }
+
+    /// Returns the request [`ConditionalHazard`]s, which occur only when a
+    /// condition on the request parameters is met.
+    #[must_use]
+    pub fn conditional_hazards(&self) -> &[ConditionalHazard] {
+        &self.conditional_hazards
+    }
+
+    /// Returns an immutable reference to the [`RouteTranslations`] of the
+    /// request route name and description.
+    #[must_use]
+    pub fn translations(&self) -> &RouteTranslations {
+        &self.translations
+    }
+
+    /// Checks whether a request must be explicitly confirmed before being
+    /// sent, since it performs a safety-critical operation on a device.
+    #[must_use]
+    pub fn requires_confirmation(&self) -> bool {
+        self.confirmation
+    }
+
     /// Returns a request [`RestKind`].
     #[must_use]
     pub fn kind(&self) -> RestKind {
@@ -152,20 +236,45 @@ impl Request {
             slash_start_end(&route_config.data.path)
         );
         let hazards = route_config.data.hazards;
+        let hazard_levels = route_config.data.hazard_levels;
+        let conditional_hazards = route_config.data.conditional_hazards;
         let parameters_data = route_config.data.parameters;
         let response_kind = route_config.response_kind;
 
         Self {
             kind,
             hazards,
+            hazard_levels,
+            conditional_hazards,
             route,
             description: route_config.data.description.map(|s| s.to_string()),
+            translations: route_config.data.translations,
             parameters_data,
             response_kind,
             device_environment,
+            confirmation: false,
         }
     }
 
+    // Computes the hazards of a request sent with the given parameters.
+    //
+    // Values expressed in a unit are converted before evaluating the
+    // conditions of the conditional hazards.
+    pub(crate) fn effective_hazards(&self, parameters: &Parameters) -> Result<Hazards, Error> {
+        if self.conditional_hazards.is_empty() {
+            return Ok(self.hazards.clone());
+        }
+
+        let parameters = parameters.convert_units(&self.parameters_data)?;
+
+        Ok(effective_hazards(
+            &self.hazards,
+            &self.conditional_hazards,
+            &self.parameters_data,
+            &parameters.supplied(),
+        ))
+    }
+
     pub(crate) async fn retrieve_response<F, Fut>(
         &self,
         skip: bool,
@@ -193,10 +302,7 @@ impl Request {
     }
 
     pub(crate) async fn plain_send(&self) -> Result<reqwest::Response, Error> {
-        let request_data =
-            self.request_data(|| self.axum_get_plain(), || self.create_params_plain());
-
-        self.parameters_send(request_data).await
+        self.create_response(&Parameters::new()).await
     }
 
     pub(crate) async fn create_response(
@@ -256,69 +362,65 @@ impl Request {
         Ok(response)
     }
 
-    fn request_data<A, F>(&self, axum_get: A, params: F) -> RequestData
+    fn request_data<A, F>(&self, axum_get: A, params: F) -> Result<RequestData, Error>
     where
-        A: FnOnce() -> String,
-        F: FnOnce() -> HashMap<String, String>,
+        A: FnOnce() -> Result<String, Error>,
+        F: FnOnce() -> HashMap<String, Value>,
     {
         let request =
             if self.kind == RestKind::Get && self.device_environment == DeviceEnvironment::Os {
-                axum_get()
+                axum_get()?
             } else {
-                self.route.to_string()
+                self.route.clone()
             };
 
         let parameters = params();
 
-        RequestData::new(request, parameters)
+        Ok(RequestData::new(request, parameters))
     }
 
     fn create_request(&self, parameters: &Parameters) -> Result<RequestData, Error> {
+        // Convert values into the units of their parameters.
+        let parameters = parameters.convert_units(&self.parameters_data)?;
+
         // Check parameters.
         parameters.check_parameters(&self.parameters_data)?;
 
-        Ok(self.request_data(
-            || self.axum_get(parameters),
-            || self.create_params(parameters),
-        ))
-    }
-
-    fn axum_get_plain(&self) -> String {
-        let mut route = self.route.to_string();
-        for (_, parameter_kind) in &self.parameters_data {
-            // TODO: Consider returning `Option<String>`
-            if let Err(e) = write!(
-                route,
-                "/{}",
-                convert_to_parameter_value(parameter_kind).as_string()
-            ) {
-                error!("Error in adding a path to a route : {e}");
-                break;
-            }
-        }
-        route
-    }
-
-    fn create_params_plain(&self) -> HashMap<String, String> {
-        let mut params = HashMap::new();
-        for (name, parameter_kind) in &self.parameters_data {
-            params.insert(
-                name.to_string(),
-                convert_to_parameter_value(parameter_kind).as_string(),
-            );
-        }
-        params
+        self.request_data(
+            || self.axum_get(&parameters),
+            || self.create_params(&parameters),
+        )
     }
 
     // Axum parameters: hello/{{1}}/{{2}}
     //                  hello/0.5/1
-    fn axum_get(&self, parameters: &Parameters) -> String {
+    //
+    // Lists and objects are passed through the query string as JSON text:
+    //                  hello/0.5/1?list=%5B1%2C2%5D
+    //
+    // A path segment cannot be omitted, so constrained parameters must be
+    // supplied, since their defaults cannot be sent in their place.
+    fn axum_get(&self, parameters: &Parameters) -> Result<String, Error> {
         let mut route = String::from(&self.route);
-        for (name, parameter_kind) in &self.parameters_data {
-            let value = if let Some(value) = parameters.get(name) {
-                value.as_string()
-            } else {
-                convert_to_parameter_value(parameter_kind).as_string()
+        let mut query = Vec::new();
+        for (name, parameter) in &self.parameters_data {
+            let value = parameter_value(name, &self.parameters_data, &parameter.kind, parameters);
+            if parameter.kind.is_structured() {
+                if let Some(value) = value {
+                    query.push((name, value.to_string()));
+                }
+                continue;
+            }
+
+            let value = match value {
+                Some(value) => value.as_str().unwrap_or_default().to_string(),
+                None => {
+                    return Err(parameter_error(ValidationError {
+                        name: name.clone(),
+                        value: ParameterValue::from_parameter_kind(&parameter.kind),
+                        violation: Violation::Required,
+                    }));
+                }
             };
             // TODO: Consider returning `Option<String>`
             if let Err(e) = write!(route, "/{value}") {
@@ -327,20 +429,27 @@ impl Request {
             }
         }
 
-        route
+        if !query.is_empty() {
+            match reqwest::Url::parse(&route) {
+                Ok(mut url) => {
+                    url.query_pairs_mut().extend_pairs(query);
+                    route = url.into();
+                }
+                Err(e) => error!("Error in adding a query to a route : {e}"),
+            }
+        }
+
+        Ok(route)
     }
 
-    fn create_params(&self, parameters: &Parameters<'_>) -> HashMap<String, String> {
-        let mut params = HashMap::new();
-        for (name, parameter_kind) in &self.parameters_data {
-            let (name, value) = if let Some(value) = parameters.get(name) {
-                (name, value.as_string())
-            } else {
-                (name, convert_to_parameter_value(parameter_kind).as_string())
-            };
-            params.insert(name.to_string(), value);
-        }
-        params
+    fn create_params(&self, parameters: &Parameters<'_>) -> HashMap<String, Value> {
+        self.parameters_data
+            .iter()
+            .filter_map(|(name, parameter)| {
+                parameter_value(name, &self.parameters_data, &parameter.kind, parameters)
+                    .map(|value| (name.clone(), value))
+            })
+            .collect()
     }
 }
 
@@ -348,14 +457,19 @@ impl Request {
 mod tests {
     use std::collections::HashMap;
 
-    use ascot::device::DeviceEnvironment;
-    use ascot::hazards::{Hazard, Hazards};
-    use ascot::parameters::{ParameterKind, Parameters as AscotParameters, ParametersData};
-    use ascot::route::{RestKind, Route, RouteConfig};
+    use ascot::device::{DeviceEnvironment, DeviceKind};
+    use ascot::hazards::{Hazard, HazardLevels, Hazards};
+    use ascot::parameters::{
+        ParameterId, ParameterKind, ParameterValue, Parameters as AscotParameters, ParametersData,
+        ValidationError, Violation,
+    };
+    use ascot::route::{RestKind, Route, RouteConfig, RouteConfigs, RouteTranslations};
+
+    use serde_json::{Value, json};
 
     use crate::parameters::{Parameters, parameter_error};
 
-    use super::{Request, RequestData, ResponseKind};
+    use super::{Request, RequestData, ResponseKind, create_requests};
 
     const ADDRESS_ROUTE: &str = "http://ascot.local/";
     const ADDRESS_ROUTE_WITHOUT_SLASH: &str = "http://ascot.local/";
@@ -376,11 +490,15 @@ mod tests {
             Request {
                 kind,
                 hazards,
+                hazard_levels: HazardLevels::new(),
+                conditional_hazards: Vec::new(),
                 route: COMPLETE_ROUTE.into(),
                 description,
+                translations: RouteTranslations::new(),
                 parameters_data: ParametersData::new(),
                 response_kind: ResponseKind::Ok,
                 device_environment: DeviceEnvironment::Os,
+                confirmation: false,
             }
         );
     }
@@ -426,29 +544,56 @@ mod tests {
             Request {
                 kind,
                 hazards: hazards.clone(),
+                hazard_levels: HazardLevels::new(),
+                conditional_hazards: Vec::new(),
                 route: COMPLETE_ROUTE.into(),
                 description,
+                translations: RouteTranslations::new(),
                 parameters_data,
                 response_kind: ResponseKind::Ok,
                 device_environment: DeviceEnvironment::Os,
+                confirmation: false,
             }
         );
 
         // Non-existent parameter.
         assert_eq!(
             request.create_request(Parameters::new().u64("wrong", 0)),
-            Err(parameter_error("`wrong` does not exist".into()))
+            Err(parameter_error(ValidationError {
+                name: "wrong".into(),
+                value: ParameterValue::U64(0),
+                violation: Violation::Undeclared,
+            }))
         );
 
         // Wrong parameter type.
         assert_eq!(
             request.create_request(Parameters::new().f64("rangeu64", 0.)),
-            Err(parameter_error("`rangeu64` must be of type `u64`".into()))
+            Err(parameter_error(ValidationError {
+                name: "rangeu64".into(),
+                value: ParameterValue::F64(0.),
+                violation: Violation::WrongType {
+                    expected: ParameterId::RangeU64,
+                },
+            }))
+        );
+
+        // Out of range parameter.
+        assert_eq!(
+            request.create_request(Parameters::new().u64("rangeu64", 999)),
+            Err(parameter_error(ValidationError {
+                name: "rangeu64".into(),
+                value: ParameterValue::U64(999),
+                violation: Violation::OutOfRange {
+                    min: ParameterValue::U64(0),
+                    max: ParameterValue::U64(20),
+                },
+            }))
         );
 
         let mut parameters = HashMap::with_capacity(2);
-        parameters.insert("rangeu64".into(), "3".into());
-        parameters.insert("rangef64".into(), "0".into());
+        parameters.insert("rangeu64".into(), Value::from("3"));
+        parameters.insert("rangef64".into(), Value::from("0"));
 
         assert_eq!(
             request.create_request(Parameters::new().u64("rangeu64", 3)),
@@ -474,11 +619,15 @@ mod tests {
             Request {
                 kind: RestKind::Put,
                 hazards: Hazards::new(),
+                hazard_levels: HazardLevels::new(),
+                conditional_hazards: Vec::new(),
                 route: COMPLETE_ROUTE.into(),
                 description: None,
+                translations: RouteTranslations::new(),
                 parameters_data: ParametersData::new(),
                 response_kind: ResponseKind::Ok,
                 device_environment: DeviceEnvironment::Os,
+                confirmation: false,
             }
         );
     }
@@ -571,4 +720,179 @@ mod tests {
             &hazards,
         );
     }
+
+    fn structured_request(route: Route) -> Request {
+        let route = route
+            .with_parameters(
+                AscotParameters::new()
+                    .u8("scene", 0)
+                    .list(
+                        "schedule",
+                        ParameterKind::U8 {
+                            default: 0,
+                            min: 0,
+                            max: 100,
+                        },
+                    )
+                    .object(
+                        "resolution",
+                        AscotParameters::new().u16("width", 640).u16("height", 480),
+                    ),
+            )
+            .serialize_data();
+
+        Request::new(ADDRESS_ROUTE, "light/", DeviceEnvironment::Os, route)
+    }
+
+    #[test]
+    fn create_requests_with_structured_parameters() {
+        let mut resolution = Parameters::new();
+        resolution.u16("width", 1920).u16("height", 1080);
+        let mut parameters = Parameters::new();
+        parameters
+            .list("schedule", [10u8, 20])
+            .object("resolution", &resolution);
+
+        // Lists and objects are passed through the query string.
+        let request = structured_request(Route::get("Route", "/route"));
+        assert_eq!(
+            request.create_request(&parameters).unwrap().request,
+            format!(
+                "{COMPLETE_ROUTE}/0?schedule=%5B%2210%22%2C%2220%22%5D\
+                 &resolution=%7B%22width%22%3A%221920%22%2C%22height%22%3A%221080%22%7D"
+            )
+        );
+
+        let request = structured_request(Route::post("Route", "/route"));
+        let request_data = request.create_request(&parameters).unwrap();
+        assert_eq!(request_data.parameters["schedule"], json!(["10", "20"]));
+        assert_eq!(
+            request_data.parameters["resolution"],
+            json!({"width": "1920", "height": "1080"})
+        );
+
+        // Default values.
+        let request_data = request.create_request(&Parameters::new()).unwrap();
+        assert_eq!(request_data.parameters["schedule"], json!([]));
+        assert_eq!(
+            request_data.parameters["resolution"],
+            json!({"width": "640", "height": "480"})
+        );
+
+        // Nested invalid values.
+        assert_eq!(
+            request
+                .create_request(Parameters::new().list("schedule", [101u8]))
+                .unwrap_err()
+                .validation()
+                .unwrap()
+                .to_string(),
+            "`schedule[0]` value `101` is outside of [0, 100]"
+        );
+    }
+
+    #[test]
+    fn create_request_with_constrained_parameters() {
+        let route = Route::post("Route", "/route")
+            .with_parameters(
+                AscotParameters::new()
+                    .u8("brightness", 0)
+                    .required("brightness")
+                    .bool("timer", false)
+                    .u64("duration", 0)
+                    .enabled_when("duration", "timer", true),
+            )
+            .serialize_data();
+        let request = Request::new(ADDRESS_ROUTE, "light/", DeviceEnvironment::Os, route);
+
+        // Required parameters are not replaced with their defaults.
+        assert_eq!(
+            request.create_request(&Parameters::new()),
+            Err(parameter_error(ValidationError {
+                name: "brightness".into(),
+                value: ParameterValue::U8(0),
+                violation: Violation::Required,
+            }))
+        );
+
+        // Constrained parameters are only sent when supplied.
+        let request_data = request
+            .create_request(Parameters::new().u8("brightness", 5))
+            .unwrap();
+        assert_eq!(
+            request_data.parameters,
+            HashMap::from([
+                ("brightness".into(), Value::from("5")),
+                ("timer".into(), Value::from("false")),
+            ])
+        );
+
+        assert_eq!(
+            request
+                .create_request(Parameters::new().u8("brightness", 5).u64("duration", 10))
+                .unwrap_err()
+                .to_string(),
+            "Wrong Parameter: `duration` is only allowed when `timer` is `true`"
+        );
+        assert!(
+            request
+                .create_request(
+                    Parameters::new()
+                        .u8("brightness", 5)
+                        .bool("timer", true)
+                        .u64("duration", 10)
+                )
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn create_get_request_with_constrained_parameters() {
+        let route = Route::get("Route", "/route")
+            .with_parameters(
+                AscotParameters::new()
+                    .u8("brightness", 0)
+                    .u8("duration", 0)
+                    .u8("period", 0)
+                    .excludes("duration", "period"),
+            )
+            .serialize_data();
+        let request = Request::new(ADDRESS_ROUTE, "light/", DeviceEnvironment::Os, route);
+
+        // Constrained path segments are never replaced with their defaults.
+        assert_eq!(
+            request.create_request(Parameters::new().u8("duration", 10)),
+            Err(parameter_error(ValidationError {
+                name: "period".into(),
+                value: ParameterValue::U8(0),
+                violation: Violation::Required,
+            }))
+        );
+    }
+
+    #[test]
+    fn create_requests_with_confirmation() {
+        let route_configs = RouteConfigs::new()
+            .insert(Route::put("Lock", "/lock").serialize_data())
+            .insert(Route::put("Unlock", "/unlock").serialize_data());
+
+        let door_lock = create_requests(
+            route_configs.clone(),
+            ADDRESS_ROUTE,
+            "door-lock/",
+            DeviceKind::DoorLock,
+            DeviceEnvironment::Os,
+        );
+        assert!(!door_lock["/lock"].requires_confirmation());
+        assert!(door_lock["/unlock"].requires_confirmation());
+
+        let light = create_requests(
+            route_configs,
+            ADDRESS_ROUTE,
+            "light/",
+            DeviceKind::Light,
+            DeviceEnvironment::Os,
+        );
+        assert!(!light["/unlock"].requires_confirmation());
+    }
 }
@@ -1,6 +1,1117 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Timelike};
+
+use futures_util::StreamExt;
+use futures_util::stream::FuturesUnordered;
+
+use tokio::sync::Notify;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use tracing::{error, info, warn};
+
+use crate::controller::Controller;
+use crate::error::{Error, ErrorKind};
+use crate::parameters::Parameters;
+use crate::response::Response;
+
+// Maximum number of years explored while searching the next run of a
+// cron schedule.
+const CRON_SEARCH_YEARS: i64 = 5;
+
+fn scheduler_error(error: impl Into<Cow<'static, str>>) -> Error {
+    Error::new(ErrorKind::Scheduler, error)
+}
+
+fn time_delta(duration: Duration) -> TimeDelta {
+    TimeDelta::from_std(duration).unwrap_or(TimeDelta::MAX)
+}
+
+// Converts a naive local date and time into a local date and time.
+//
+// When the given time does not exist because of a daylight saving time
+// transition, the earliest valid time is considered.
+fn local_time(time: NaiveDateTime) -> Option<DateTime<Local>> {
+    Local.from_local_datetime(&time).earliest()
+}
+
+fn parse_number(value: &str, min: u32, max: u32) -> Result<u32, Error> {
+    let number = value
+        .parse::<u32>()
+        .map_err(|_| scheduler_error(format!("`{value}` is not a valid cron value")))?;
+
+    if number < min || number > max {
+        return Err(scheduler_error(format!(
+            "`{number}` is outside the [{min}, {max}] cron range"
+        )));
+    }
+
+    Ok(number)
+}
+
+// Parses a cron field returning the bitmask of its admitted values.
+//
+// A field is a comma-separated list of elements. Each element can be
+// `*`, a number, or a range `a-b`, optionally followed by a step `/n`.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, Error> {
+    let mut mask = 0;
+    for element in field.split(',') {
+        let (range, step) = match element.split_once('/') {
+            Some((range, step)) => (range, parse_number(step, 1, max)?),
+            None => (element, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_number(start, min, max)?, parse_number(end, min, max)?)
+        } else {
+            let value = parse_number(range, min, max)?;
+            // A single value followed by a step runs until the maximum value.
+            (value, if element.contains('/') { max } else { value })
+        };
+
+        if start > end {
+            return Err(scheduler_error(format!(
+                "`{element}` is not a valid cron range"
+            )));
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+const fn contains(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// A cron-style schedule.
+///
+/// A schedule is composed of five fields separated by whitespaces:
+///
+/// ```text
+/// minute (0-59) hour (0-23) day-of-month (1-31) month (1-12) day-of-week (0-7)
+/// ```
+///
+/// Both `0` and `7` represent Sunday in the day-of-week field.
+/// Each field accepts `*`, single values, ranges (`1-5`), lists (`1,3,5`),
+/// and steps (`*/15`, `0-30/10`).
+///
+/// As in the classic cron semantic, when both day-of-month and day-of-week
+/// fields are restricted, a day matches if it satisfies **either** of them.
+///
+/// All times are evaluated in the local time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    restricted_days_of_month: bool,
+    restricted_days_of_week: bool,
+}
+
+impl CronSchedule {
+    /// Parses a [`CronSchedule`] from a cron expression.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the expression does not contain exactly
+    /// five fields or when a field contains invalid values.
+    pub fn parse(expression: &str) -> Result<Self, Error> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            return Err(scheduler_error(format!(
+                "`{expression}` must contain exactly five cron fields"
+            )));
+        };
+
+        let mut days_of_week_mask = parse_field(days_of_week, 0, 7)?;
+        // Sunday can be expressed both as 0 and 7.
+        if contains(days_of_week_mask, 7) {
+            days_of_week_mask = (days_of_week_mask | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            minutes: parse_field(minutes, 0, 59)?,
+            hours: parse_field(hours, 0, 23)?,
+            days_of_month: parse_field(days_of_month, 1, 31)?,
+            months: parse_field(months, 1, 12)?,
+            days_of_week: days_of_week_mask,
+            restricted_days_of_month: days_of_month != "*",
+            restricted_days_of_week: days_of_week != "*",
+        })
+    }
+
+    /// Creates a [`CronSchedule`] which runs every day at the given time.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the hour or the minute are out of range.
+    pub fn daily_at(hour: u32, minute: u32) -> Result<Self, Error> {
+        Self::parse(&format!("{minute} {hour} * * *"))
+    }
+
+    /// Creates a [`CronSchedule`] which runs from Monday to Friday at the
+    /// given time.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the hour or the minute are out of range.
+    pub fn weekdays_at(hour: u32, minute: u32) -> Result<Self, Error> {
+        Self::parse(&format!("{minute} {hour} * * 1-5"))
+    }
+
+    /// Returns the first time matching the schedule strictly after the
+    /// given time.
+    ///
+    /// If [`None`], the schedule never matches.
+    #[must_use]
+    pub fn next_after(&self, time: DateTime<Local>) -> Option<DateTime<Local>> {
+        let mut naive = time.naive_local();
+        loop {
+            let next = self.next_naive_after(naive)?;
+            // Skip times which do not exist in the local time zone.
+            if let Some(next) = local_time(next) {
+                return Some(next);
+            }
+            naive = next;
+        }
+    }
+
+    fn next_naive_after(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut time = time.with_second(0)?.with_nanosecond(0)? + TimeDelta::minutes(1);
+        let limit = time + TimeDelta::days(366 * CRON_SEARCH_YEARS);
+
+        while time < limit {
+            if !contains(self.months, time.month()) {
+                let (year, month) = if time.month() == 12 {
+                    (time.year() + 1, 1)
+                } else {
+                    (time.year(), time.month() + 1)
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+
+            if !self.matches_day(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+
+            if !contains(self.hours, time.hour()) {
+                time = time.date().and_hms_opt(time.hour(), 0, 0)? + TimeDelta::hours(1);
+                continue;
+            }
+
+            if !contains(self.minutes, time.minute()) {
+                time += TimeDelta::minutes(1);
+                continue;
+            }
+
+            return Some(time);
+        }
+        None
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day_of_month = contains(self.days_of_month, date.day());
+        let day_of_week = contains(self.days_of_week, date.weekday().num_days_from_sunday());
+
+        match (self.restricted_days_of_month, self.restricted_days_of_week) {
+            (true, true) => day_of_month || day_of_week,
+            (true, false) => day_of_month,
+            (false, true) => day_of_week,
+            (false, false) => true,
+        }
+    }
+}
+
+/// A job identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JobId(u64);
+
+impl std::fmt::Display for JobId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The condition which triggers the execution of a job.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trigger {
+    /// Runs the job only once at the given time.
+    At(DateTime<Local>),
+    /// Runs the job only once, after the given delay has elapsed from the
+    /// job registration.
+    After(Duration),
+    /// Runs the job periodically with the given interval. The first
+    /// execution occurs an interval after the job registration.
+    ///
+    /// The interval must be greater than zero.
+    Interval(Duration),
+    /// Runs the job according to a [`CronSchedule`].
+    Cron(CronSchedule),
+    /// Runs the job after the given delay has elapsed from each execution
+    /// of another job.
+    AfterJob {
+        /// The identifier of the job which precedes this one.
+        job: JobId,
+        /// The delay between the two executions.
+        delay: Duration,
+    },
+}
+
+impl Trigger {
+    fn first_run(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        match self {
+            Self::At(time) => Some(*time),
+            Self::After(delay) | Self::Interval(delay) => Some(now + time_delta(*delay)),
+            Self::Cron(schedule) => schedule.next_after(now),
+            Self::AfterJob { .. } => None,
+        }
+    }
+}
+
+/// A job.
+///
+/// It describes a request to be sent to a device when its [`Trigger`]
+/// fires.
+///
+/// A job is always sent through the [`Controller`], hence the controller
+/// privacy policy is applied to each execution.
+#[derive(Debug, Clone)]
+pub struct Job {
+    device: usize,
+    route: String,
+    parameters: Option<Parameters<'static>>,
+    trigger: Trigger,
+    confirmed: bool,
+}
+
+impl Job {
+    /// Creates a [`Job`] which sends the request identified by the given
+    /// route to the [`crate::device::Device`] with the given identifier.
+    #[must_use]
+    #[inline]
+    pub fn new(device: usize, route: impl Into<String>, trigger: Trigger) -> Self {
+        Self {
+            device,
+            route: route.into(),
+            parameters: None,
+            trigger,
+            confirmed: false,
+        }
+    }
+
+    /// Sets the [`Parameters`] sent together with the request.
+    #[must_use]
+    #[inline]
+    pub fn parameters(mut self, parameters: Parameters<'static>) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    /// Explicitly confirms the sending of the request.
+    ///
+    /// Requests performing safety-critical operations, such as unlocking a
+    /// door, are not sent until they are confirmed.
+    #[must_use]
+    #[inline]
+    pub const fn confirm(mut self) -> Self {
+        self.confirmed = true;
+        self
+    }
+
+    async fn execute(&self, controller: &Controller) -> Result<Response, Error> {
+        let device_sender = controller.device(self.device)?;
+        let mut request_sender = device_sender.request(&self.route)?;
+        if self.confirmed {
+            request_sender = request_sender.confirm();
+        }
+
+        match &self.parameters {
+            Some(parameters) => request_sender.send_with_parameters(parameters).await,
+            None => request_sender.send().await,
+        }
+    }
+}
+
+/// The status of a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    /// The job is waiting for its next execution.
+    Scheduled,
+    /// The job has been paused, so it is not executed until resumed.
+    Paused,
+    /// The job will not be executed anymore.
+    Completed,
+    /// The job has been cancelled.
+    Cancelled,
+}
+
+/// Job information.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobInfo {
+    /// Job identifier.
+    pub id: JobId,
+    /// Device identifier.
+    pub device: usize,
+    /// Request route.
+    pub route: String,
+    /// Job trigger.
+    pub trigger: Trigger,
+    /// Job status.
+    pub status: JobStatus,
+    /// Next execution time.
+    ///
+    /// If [`None`], no execution is planned.
+    pub next_run: Option<DateTime<Local>>,
+    /// Number of executions.
+    pub runs: u64,
+}
+
+/// The result of a job execution.
+pub struct JobResult {
+    /// Job identifier.
+    pub job: JobId,
+    /// Execution time.
+    pub executed_at: DateTime<Local>,
+    /// Device response.
+    pub response: Result<Response, Error>,
+}
+
+/// A stream of [`JobResult`]s.
+pub struct JobResults(UnboundedReceiver<JobResult>);
+
+impl JobResults {
+    /// Waits for the next [`JobResult`].
+    ///
+    /// If [`None`], the [`Scheduler`] has been dropped and no other
+    /// results will be produced.
+    pub async fn next(&mut self) -> Option<JobResult> {
+        self.0.recv().await
+    }
+}
+
+#[derive(Debug)]
+struct ScheduledJob {
+    job: Job,
+    status: JobStatus,
+    next_run: Option<DateTime<Local>>,
+    runs: u64,
+    // Whether the job is being executed.
+    running: bool,
+}
+
+impl ScheduledJob {
+    fn info(&self, id: JobId) -> JobInfo {
+        JobInfo {
+            id,
+            device: self.job.device,
+            route: self.job.route.clone(),
+            trigger: self.job.trigger.clone(),
+            status: self.status,
+            next_run: self.next_run,
+            runs: self.runs,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    jobs: BTreeMap<JobId, ScheduledJob>,
+    next_id: u64,
+    stopped: bool,
+}
+
+impl SchedulerState {
+    fn add(&mut self, job: Job, now: DateTime<Local>) -> Result<JobId, Error> {
+        let id = JobId(self.next_id);
+
+        match job.trigger {
+            // A job repeated without any interval would be executed
+            // continuously.
+            Trigger::Interval(interval) if interval.is_zero() => {
+                return Err(scheduler_error("The interval of a job must not be zero."));
+            }
+            Trigger::AfterJob { job: parent, .. } if parent == id => {
+                return Err(scheduler_error(format!(
+                    "The job {id} cannot run after itself."
+                )));
+            }
+            Trigger::AfterJob { job: parent, .. } if !self.jobs.contains_key(&parent) => {
+                return Err(scheduler_error(format!("The job {parent} does not exist.")));
+            }
+            _ => {}
+        }
+
+        self.next_id += 1;
+
+        let next_run = job.trigger.first_run(now);
+        let status = if next_run.is_some() || matches!(job.trigger, Trigger::AfterJob { .. }) {
+            JobStatus::Scheduled
+        } else {
+            warn!("The job {id} never runs because its trigger never fires.");
+            JobStatus::Completed
+        };
+
+        self.jobs.insert(
+            id,
+            ScheduledJob {
+                job,
+                status,
+                next_run,
+                runs: 0,
+                running: false,
+            },
+        );
+
+        Ok(id)
+    }
+
+    fn get_mut(&mut self, id: JobId) -> Result<&mut ScheduledJob, Error> {
+        self.jobs
+            .get_mut(&id)
+            .ok_or_else(|| scheduler_error(format!("The job {id} does not exist.")))
+    }
+
+    fn pause(&mut self, id: JobId) -> Result<(), Error> {
+        let job = self.get_mut(id)?;
+        if job.status != JobStatus::Scheduled {
+            return Err(scheduler_error(format!(
+                "The job {id} cannot be paused because it is not scheduled."
+            )));
+        }
+        job.status = JobStatus::Paused;
+        Ok(())
+    }
+
+    fn resume(&mut self, id: JobId, now: DateTime<Local>) -> Result<(), Error> {
+        let job = self.get_mut(id)?;
+        if job.status != JobStatus::Paused {
+            return Err(scheduler_error(format!(
+                "The job {id} cannot be resumed because it is not paused."
+            )));
+        }
+        job.status = JobStatus::Scheduled;
+
+        // Executions of periodic jobs missed during the pause are skipped.
+        if job.next_run.is_some_and(|next_run| next_run < now) {
+            match &job.job.trigger {
+                Trigger::Interval(_) | Trigger::Cron(_) => {
+                    job.next_run = job.job.trigger.first_run(now);
+                }
+                Trigger::AfterJob { .. } => job.next_run = None,
+                Trigger::At(_) | Trigger::After(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn cancel(&mut self, id: JobId) -> Result<(), Error> {
+        let job = self.get_mut(id)?;
+        if matches!(job.status, JobStatus::Completed | JobStatus::Cancelled) {
+            return Err(scheduler_error(format!(
+                "The job {id} cannot be cancelled because it is already terminated."
+            )));
+        }
+        job.status = JobStatus::Cancelled;
+        job.next_run = None;
+        self.complete_dependents(id);
+        Ok(())
+    }
+
+    // Returns the jobs which must be executed at the given time, marking
+    // them as running until they are executed.
+    fn due(&mut self, now: DateTime<Local>) -> Vec<(JobId, Job)> {
+        self.jobs
+            .iter_mut()
+            .filter(|(_, job)| {
+                job.status == JobStatus::Scheduled
+                    && !job.running
+                    && job.next_run.is_some_and(|next_run| next_run <= now)
+            })
+            .map(|(id, job)| {
+                job.running = true;
+                (*id, job.job.clone())
+            })
+            .collect()
+    }
+
+    // Returns the time to wait before the next execution.
+    //
+    // If [`None`], no execution is planned.
+    fn next_wait(&self, now: DateTime<Local>) -> Option<Duration> {
+        self.jobs
+            .values()
+            .filter(|job| job.status == JobStatus::Scheduled && !job.running)
+            .filter_map(|job| job.next_run)
+            .min()
+            .map(|next_run| (next_run - now).to_std().unwrap_or(Duration::ZERO))
+    }
+
+    fn has_active_jobs(&self) -> bool {
+        self.jobs
+            .values()
+            .any(|job| matches!(job.status, JobStatus::Scheduled | JobStatus::Paused))
+    }
+
+    // Updates a job after its execution.
+    fn executed(&mut self, id: JobId, now: DateTime<Local>) {
+        let parent_terminated = self.jobs.get(&id).is_some_and(|job| {
+            if let Trigger::AfterJob { job: parent, .. } = job.job.trigger {
+                self.jobs.get(&parent).is_none_or(|parent| {
+                    matches!(parent.status, JobStatus::Completed | JobStatus::Cancelled)
+                })
+            } else {
+                false
+            }
+        });
+
+        let Some(job) = self.jobs.get_mut(&id) else {
+            return;
+        };
+
+        job.runs += 1;
+        job.running = false;
+
+        // The job might have been cancelled during its execution.
+        if job.status == JobStatus::Cancelled {
+            return;
+        }
+
+        job.next_run = match &job.job.trigger {
+            Trigger::Interval(interval) => Some(now + time_delta(*interval)),
+            Trigger::Cron(schedule) => schedule.next_after(now),
+            Trigger::At(_) | Trigger::After(_) | Trigger::AfterJob { .. } => None,
+        };
+
+        // A dependent job completes once its parent cannot run anymore.
+        let completed = match job.job.trigger {
+            Trigger::At(_) | Trigger::After(_) => true,
+            Trigger::Interval(_) | Trigger::Cron(_) => job.next_run.is_none(),
+            Trigger::AfterJob { .. } => parent_terminated,
+        };
+        if completed {
+            job.status = JobStatus::Completed;
+        }
+
+        // Plan the executions of the dependent jobs.
+        for dependent in self.jobs.values_mut() {
+            if let Trigger::AfterJob { job: parent, delay } = dependent.job.trigger
+                && parent == id
+                && dependent.status == JobStatus::Scheduled
+            {
+                dependent.next_run = Some(now + time_delta(delay));
+            }
+        }
+
+        if completed {
+            self.complete_dependents(id);
+        }
+    }
+
+    // Completes the jobs which depend on a terminated job and that have no
+    // other execution planned.
+    fn complete_dependents(&mut self, id: JobId) {
+        let mut terminated = vec![id];
+        while let Some(parent_id) = terminated.pop() {
+            for (dependent_id, dependent) in &mut self.jobs {
+                if let Trigger::AfterJob { job: parent, .. } = dependent.job.trigger
+                    && parent == parent_id
+                    && dependent.next_run.is_none()
+                    && matches!(dependent.status, JobStatus::Scheduled | JobStatus::Paused)
+                {
+                    dependent.status = JobStatus::Completed;
+                    terminated.push(*dependent_id);
+                }
+            }
+        }
+    }
+}
+
 /// A programs scheduler.
 ///
-/// It controls requests sending.
+/// It controls requests sending, executing [`Job`]s at specific times.
 ///
 /// Useful for batch processing.
-pub struct Scheduler;
+///
+/// Jobs can be added, paused, resumed, and cancelled even while the
+/// scheduler is running, since all methods take an immutable reference.
+pub struct Scheduler {
+    state: Mutex<SchedulerState>,
+    notify: Notify,
+    results_sender: UnboundedSender<JobResult>,
+    results: Mutex<Option<JobResults>>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    /// Creates an empty [`Scheduler`].
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        let (results_sender, results_receiver) = unbounded_channel();
+        Self {
+            state: Mutex::new(SchedulerState::default()),
+            notify: Notify::new(),
+            results_sender,
+            results: Mutex::new(Some(JobResults(results_receiver))),
+        }
+    }
+
+    /// Adds a [`Job`], returning its identifier.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the job depends on a non-existent job, or
+    /// when it is repeated with a zero interval.
+    pub fn add_job(&self, job: Job) -> Result<JobId, Error> {
+        let id = self.lock().add(job, Local::now())?;
+        self.notify.notify_one();
+        Ok(id)
+    }
+
+    /// Returns the information about all jobs, ordered by identifier.
+    #[must_use]
+    pub fn jobs(&self) -> Vec<JobInfo> {
+        self.lock()
+            .jobs
+            .iter()
+            .map(|(id, job)| job.info(*id))
+            .collect()
+    }
+
+    /// Returns the information about the job with the given identifier.
+    ///
+    /// If [`None`], the job **does not** exist.
+    #[must_use]
+    pub fn job(&self, id: JobId) -> Option<JobInfo> {
+        self.lock().jobs.get(&id).map(|job| job.info(id))
+    }
+
+    /// Pauses a scheduled job.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the job does not exist or it is not
+    /// scheduled.
+    pub fn pause(&self, id: JobId) -> Result<(), Error> {
+        self.lock().pause(id)?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    /// Resumes a paused job.
+    ///
+    /// Periodic executions missed during the pause are skipped.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the job does not exist or it is not paused.
+    pub fn resume(&self, id: JobId) -> Result<(), Error> {
+        self.lock().resume(id, Local::now())?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    /// Cancels a job.
+    ///
+    /// The jobs which depend on the cancelled one, and that have no other
+    /// execution planned, are completed.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned when the job does not exist or it is already
+    /// terminated.
+    pub fn cancel(&self, id: JobId) -> Result<(), Error> {
+        self.lock().cancel(id)?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    /// Stops a running [`Scheduler`].
+    #[inline]
+    pub fn stop(&self) {
+        self.lock().stopped = true;
+        self.notify.notify_one();
+    }
+
+    /// Returns the stream of [`JobResult`]s.
+    ///
+    /// If [`None`], the stream has already been taken.
+    #[must_use]
+    pub fn results(&self) -> Option<JobResults> {
+        self.results
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take()
+    }
+
+    /// Runs the jobs, sending their requests through the given
+    /// [`Controller`].
+    ///
+    /// This method returns when [`Self::stop`] is called or when all jobs
+    /// have been completed or cancelled. The jobs being executed at that
+    /// time are completed before returning.
+    pub async fn run(&self, controller: &Controller) {
+        {
+            // A previous stop request does not affect a new run.
+            self.lock().stopped = false;
+        }
+
+        // Jobs run concurrently with the scheduling of the others, so a slow
+        // request does not delay the jobs which fall due in the meantime.
+        let mut running = FuturesUnordered::new();
+
+        loop {
+            let now = Local::now();
+            let (due, wait) = {
+                let mut jobs = self.lock();
+                if jobs.stopped || !jobs.has_active_jobs() {
+                    break;
+                }
+                (jobs.due(now), jobs.next_wait(now))
+            };
+
+            for (id, job) in due {
+                running.push(self.execute(controller, id, job));
+            }
+
+            tokio::select! {
+                () = Self::sleep(wait) => {}
+                () = self.notify.notified() => {}
+                Some(()) = running.next(), if !running.is_empty() => {}
+            }
+        }
+
+        while running.next().await.is_some() {}
+        info!("Scheduler stopped.");
+    }
+
+    // Waits for the given time.
+    //
+    // If [`None`], it waits forever.
+    async fn sleep(wait: Option<Duration>) {
+        match wait {
+            Some(wait) => tokio::time::sleep(wait).await,
+            None => std::future::pending().await,
+        }
+    }
+
+    async fn execute(&self, controller: &Controller, id: JobId, job: Job) {
+        info!("Running job {id} for route `{}`", job.route);
+        let response = job.execute(controller).await;
+        if let Err(e) = &response {
+            error!("Job {id} failed: {e}");
+        }
+
+        let executed_at = Local::now();
+        self.lock().executed(id, executed_at);
+
+        // The receiver might have been dropped, so results are simply
+        // discarded.
+        let _ = self.results_sender.send(JobResult {
+            job: id,
+            executed_at,
+            response,
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SchedulerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{DateTime, Local, NaiveDate, TimeDelta};
+
+    use crate::controller::Controller;
+    use crate::discovery::tests::configure_discovery;
+
+    use super::{
+        CronSchedule, Job, JobId, JobStatus, Scheduler, SchedulerState, Trigger, local_time,
+    };
+
+    fn time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        local_time(
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn cron_parsing() {
+        assert!(CronSchedule::parse("* * * * *").is_ok());
+        assert!(CronSchedule::parse("*/15 0-6,22,23 1 */2 0-7").is_ok());
+
+        // Wrong number of fields.
+        assert!(CronSchedule::parse("* * * *").is_err());
+        // Out of range values.
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("* * 0 * *").is_err());
+        // Inverted range.
+        assert!(CronSchedule::parse("* 5-1 * * *").is_err());
+        // Invalid value.
+        assert!(CronSchedule::parse("a * * * *").is_err());
+
+        // Sunday can be expressed both as 0 and 7.
+        assert_eq!(
+            CronSchedule::parse("0 7 * * 7"),
+            CronSchedule::parse("0 7 * * 0")
+        );
+    }
+
+    #[test]
+    fn cron_next_run() {
+        // 2025-01-03 is a Friday.
+        let weekdays = CronSchedule::weekdays_at(7, 0).unwrap();
+        assert_eq!(
+            weekdays.next_after(time(2025, 1, 3, 6, 30)),
+            Some(time(2025, 1, 3, 7, 0))
+        );
+        // Skip the weekend.
+        assert_eq!(
+            weekdays.next_after(time(2025, 1, 3, 7, 0)),
+            Some(time(2025, 1, 6, 7, 0))
+        );
+
+        let every_quarter = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(
+            every_quarter.next_after(time(2025, 1, 3, 23, 50)),
+            Some(time(2025, 1, 4, 0, 0))
+        );
+
+        // Last day of the year.
+        let new_year = CronSchedule::parse("0 0 1 1 *").unwrap();
+        assert_eq!(
+            new_year.next_after(time(2025, 3, 1, 0, 0)),
+            Some(time(2026, 1, 1, 0, 0))
+        );
+
+        // Day-of-month or day-of-week.
+        let either = CronSchedule::parse("0 12 10 * 1").unwrap();
+        assert_eq!(
+            either.next_after(time(2025, 1, 3, 0, 0)),
+            Some(time(2025, 1, 6, 12, 0))
+        );
+        assert_eq!(
+            either.next_after(time(2025, 1, 6, 12, 0)),
+            Some(time(2025, 1, 10, 12, 0))
+        );
+
+        // A schedule which never matches.
+        let never = CronSchedule::parse("0 0 31 2 *").unwrap();
+        assert_eq!(never.next_after(time(2025, 1, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn one_shot_and_interval_jobs() {
+        let now = time(2025, 1, 3, 7, 0);
+        let mut jobs = SchedulerState::default();
+
+        let once = jobs
+            .add(
+                Job::new(0, "/on", Trigger::After(Duration::from_secs(60))),
+                now,
+            )
+            .unwrap();
+        let interval = jobs
+            .add(
+                Job::new(0, "/toggle", Trigger::Interval(Duration::from_secs(30))),
+                now,
+            )
+            .unwrap();
+
+        assert_eq!(jobs.next_wait(now), Some(Duration::from_secs(30)));
+        assert!(jobs.due(now).is_empty());
+
+        let later = now + TimeDelta::seconds(60);
+        let due: Vec<JobId> = jobs.due(later).into_iter().map(|(id, _)| id).collect();
+        assert_eq!(due, vec![once, interval]);
+
+        // Running jobs are not due again until they are executed.
+        assert!(jobs.due(later).is_empty());
+        assert_eq!(jobs.next_wait(later), None);
+
+        jobs.executed(once, later);
+        jobs.executed(interval, later);
+
+        assert_eq!(jobs.jobs[&once].status, JobStatus::Completed);
+        assert_eq!(jobs.jobs[&once].runs, 1);
+        assert_eq!(jobs.jobs[&interval].status, JobStatus::Scheduled);
+        assert_eq!(
+            jobs.jobs[&interval].next_run,
+            Some(later + TimeDelta::seconds(30))
+        );
+    }
+
+    #[test]
+    fn dependent_jobs() {
+        let now = time(2025, 1, 3, 7, 0);
+        let mut jobs = SchedulerState::default();
+
+        // A job repeated without an interval.
+        assert!(
+            jobs.add(
+                Job::new(0, "/toggle", Trigger::Interval(Duration::ZERO)),
+                now
+            )
+            .is_err()
+        );
+
+        // A job which runs after itself.
+        assert!(
+            jobs.add(
+                Job::new(
+                    0,
+                    "/toggle",
+                    Trigger::AfterJob {
+                        job: JobId(jobs.next_id),
+                        delay: Duration::ZERO
+                    }
+                ),
+                now
+            )
+            .is_err()
+        );
+
+        // Non-existent job.
+        assert!(
+            jobs.add(
+                Job::new(
+                    0,
+                    "/off",
+                    Trigger::AfterJob {
+                        job: JobId(10),
+                        delay: Duration::ZERO
+                    }
+                ),
+                now
+            )
+            .is_err()
+        );
+
+        let on = jobs.add(Job::new(0, "/on", Trigger::At(now)), now).unwrap();
+        let off = jobs
+            .add(
+                Job::new(
+                    0,
+                    "/off",
+                    Trigger::AfterJob {
+                        job: on,
+                        delay: Duration::from_secs(30 * 60),
+                    },
+                ),
+                now,
+            )
+            .unwrap();
+
+        // The dependent job waits for its parent.
+        assert_eq!(jobs.jobs[&off].next_run, None);
+        assert_eq!(jobs.jobs[&off].status, JobStatus::Scheduled);
+
+        jobs.executed(on, now);
+        assert_eq!(jobs.jobs[&off].next_run, Some(now + TimeDelta::minutes(30)));
+
+        let later = now + TimeDelta::minutes(30);
+        jobs.executed(off, later);
+        assert_eq!(jobs.jobs[&off].status, JobStatus::Completed);
+        assert!(!jobs.has_active_jobs());
+    }
+
+    #[test]
+    fn pause_resume_cancel() {
+        let scheduler = Scheduler::new();
+
+        let id = scheduler
+            .add_job(Job::new(
+                0,
+                "/toggle",
+                Trigger::Interval(Duration::from_secs(10)),
+            ))
+            .unwrap();
+
+        // Only a scheduled job can be paused.
+        scheduler.pause(id).unwrap();
+        assert!(scheduler.pause(id).is_err());
+        assert_eq!(scheduler.job(id).unwrap().status, JobStatus::Paused);
+
+        // Only a paused job can be resumed.
+        scheduler.resume(id).unwrap();
+        assert!(scheduler.resume(id).is_err());
+        assert_eq!(scheduler.job(id).unwrap().status, JobStatus::Scheduled);
+
+        scheduler.cancel(id).unwrap();
+        assert!(scheduler.cancel(id).is_err());
+
+        let jobs = scheduler.jobs();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].status, JobStatus::Cancelled);
+        assert_eq!(jobs[0].next_run, None);
+
+        // Non-existent job.
+        assert!(scheduler.pause(JobId(42)).is_err());
+        assert!(scheduler.job(JobId(42)).is_none());
+
+        // The results stream can be taken only once.
+        assert!(scheduler.results().is_some());
+        assert!(scheduler.results().is_none());
+    }
+
+    #[tokio::test]
+    async fn run_without_devices() {
+        let controller = Controller::new(configure_discovery());
+        let scheduler = Scheduler::new();
+        let mut results = scheduler.results().unwrap();
+
+        let id = scheduler
+            .add_job(Job::new(0, "/on", Trigger::After(Duration::ZERO)))
+            .unwrap();
+        let other = scheduler
+            .add_job(Job::new(1, "/off", Trigger::After(Duration::ZERO)))
+            .unwrap();
+
+        let start = Local::now();
+
+        // The scheduler terminates once all jobs are completed.
+        scheduler.run(&controller).await;
+
+        // Both due jobs are executed in the same run.
+        let mut executed = Vec::new();
+        for _ in 0..2 {
+            let result = results.next().await.unwrap();
+            // No devices have been discovered.
+            assert!(result.response.is_err());
+            // The execution time is recorded after the request.
+            assert!(result.executed_at >= start);
+            executed.push(result.job);
+        }
+        executed.sort_by_key(|job| job.0);
+        assert_eq!(executed, [id, other]);
+
+        assert_eq!(scheduler.job(id).unwrap().status, JobStatus::Completed);
+        assert_eq!(scheduler.job(other).unwrap().status, JobStatus::Completed);
+    }
+}
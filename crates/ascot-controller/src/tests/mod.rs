@@ -2,10 +2,11 @@ use std::net::Ipv4Addr;
 use std::time::Duration;
 
 use ascot::device::{DeviceEnvironment, DeviceKind};
-use ascot::hazards::{Hazard, Hazards};
+use ascot::hazards::{Hazard, HazardLevels, Hazards};
 use ascot::parameters::{ParameterKind, Parameters, ParametersData};
+use ascot::protocol::UUID_PROPERTY;
 use ascot::response::ResponseKind;
-use ascot::route::{LightOffRoute, LightOnRoute, RestKind, Route};
+use ascot::route::{LightOffRoute, LightOnRoute, RestKind, Route, RouteTranslations};
 
 use ascot_os::actions::error::ErrorResponse;
 use ascot_os::actions::ok::{OkResponse, mandatory_ok_stateless};
@@ -28,6 +29,9 @@ const PORT_TWO: u16 = 4000;
 const FIRST_DEVICE_ROUTE: &str = "/with-toggle";
 const SECOND_DEVICE_ROUTE: &str = "/without-toggle";
 
+const MANUFACTURER: &str = "Ascot";
+const FIRMWARE_VERSION: &str = "1.0.0";
+
 pub(crate) const DOMAIN: &str = "ascot";
 
 async fn turn_light_on() -> Result<OkResponse, ErrorResponse> {
@@ -70,8 +74,10 @@ async fn light(
     let device = Light::new()
         // This method is mandatory, if not called, a compiler error is raised.
         .turn_light_on(light_on_route, mandatory_ok_stateless(turn_light_on))
+        .unwrap()
         // This method is mandatory, if not called, a compiler error is raised.
-        .turn_light_off(light_off_route, mandatory_ok_stateless(turn_light_off));
+        .turn_light_off(light_off_route, mandatory_ok_stateless(turn_light_off))
+        .unwrap();
 
     let device = if with_toggle {
         // Toggle `PUT` route.
@@ -98,17 +104,23 @@ async fn light(
     );
 
     // Run a discovery service and the device on the server.
-    Server::new(device.into_device())
-        .address(Ipv4Addr::UNSPECIFIED)
-        .port(port)
-        .well_known_service(id)
-        .discovery_service(ServiceConfig::mdns_sd(id).hostname("ascot").domain(DOMAIN))
-        .with_graceful_shutdown(async move {
-            _ = close_rx.await;
-        })
-        .run()
-        .await
-        .expect("Error in running a device server.");
+    Server::new(
+        device
+            .into_device()
+            .manufacturer(MANUFACTURER)
+            .firmware_version(FIRMWARE_VERSION),
+    )
+    .address(Ipv4Addr::UNSPECIFIED)
+    .port(port)
+    .well_known_service(id)
+    .state_directory(std::env::temp_dir().join("ascot-controller-tests"))
+    .discovery_service(ServiceConfig::mdns_sd(id).hostname("ascot").domain(DOMAIN))
+    .with_graceful_shutdown(async move {
+        _ = close_rx.await;
+    })
+    .run()
+    .await
+    .expect("Error in running a device server.");
 }
 
 pub(crate) async fn light_with_toggle(close_rx: tokio::sync::oneshot::Receiver<()>) {
@@ -144,11 +156,15 @@ fn check_request(
         Some(&Request {
             kind,
             hazards,
+            hazard_levels: HazardLevels::new(),
+            conditional_hazards: Vec::new(),
             route: build_route(device, route),
             description: Some(description.to_string()),
+            translations: RouteTranslations::new(),
             parameters_data,
             response_kind,
             device_environment: DeviceEnvironment::Os,
+            confirmation: false,
         })
     );
 }
@@ -170,6 +186,16 @@ pub(crate) fn compare_device_data(device: &Device) {
             || path == "/.well-known/light-without-toggle")
     );
 
+    // Check device identifier.
+    let uuid = device.network_info().properties.get(UUID_PROPERTY);
+    assert!(uuid.is_some_and(|uuid| {
+        device
+            .description()
+            .identity
+            .uuid
+            .is_some_and(|identity_uuid| identity_uuid.to_string() == *uuid)
+    }));
+
     // Check device main route.
     assert!(
         device.description().main_route == FIRST_DEVICE_ROUTE
@@ -180,6 +206,12 @@ pub(crate) fn compare_device_data(device: &Device) {
     assert_eq!(device.description().kind, DeviceKind::Light);
     assert_eq!(device.description().environment, DeviceEnvironment::Os);
 
+    // Check device identity.
+    let identity = &device.description().identity;
+    assert_eq!(identity.manufacturer.as_deref(), Some(MANUFACTURER));
+    assert_eq!(identity.firmware_version.as_deref(), Some(FIRMWARE_VERSION));
+    assert_eq!(identity.model, None);
+
     // Check requests number.
     assert!(
         device.description().main_route == FIRST_DEVICE_ROUTE && device.requests_count() == 3
@@ -2,8 +2,7 @@ use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 
-use ascot::device::{DeviceData, DeviceEnvironment, DeviceKind};
-use ascot::hazards::Hazard;
+use ascot::device::{DeviceData, DeviceEnvironment, DeviceKind, DeviceProfile, Uuid};
 use ascot::route::{Route, RouteConfigs};
 
 use esp_wifi::wifi::WifiDevice;
@@ -18,8 +17,8 @@ use crate::state::{State, ValueFromRef};
 // Default main route.
 const MAIN_ROUTE: &str = "/light";
 
-// Allowed hazards.
-const ALLOWED_HAZARDS: &[Hazard] = &[Hazard::FireHazard, Hazard::ElectricEnergyConsumption];
+// Device profile.
+const PROFILE: &DeviceProfile = DeviceKind::Light.profile();
 
 /// A `light` device.
 ///
@@ -145,7 +144,57 @@ where
         self
     }
 
+    /// Sets the manufacturer.
+    #[must_use]
+    #[inline]
+    pub fn manufacturer(mut self, manufacturer: &'static str) -> Self {
+        self.device.identity.manufacturer = Some(Cow::Borrowed(manufacturer));
+        self
+    }
+
+    /// Sets the model.
+    #[must_use]
+    #[inline]
+    pub fn model(mut self, model: &'static str) -> Self {
+        self.device.identity.model = Some(Cow::Borrowed(model));
+        self
+    }
+
+    /// Sets the hardware revision.
+    #[must_use]
+    #[inline]
+    pub fn hardware_revision(mut self, hardware_revision: &'static str) -> Self {
+        self.device.identity.hardware_revision = Some(Cow::Borrowed(hardware_revision));
+        self
+    }
+
+    /// Sets the firmware version.
+    #[must_use]
+    #[inline]
+    pub fn firmware_version(mut self, firmware_version: &'static str) -> Self {
+        self.device.identity.firmware_version = Some(Cow::Borrowed(firmware_version));
+        self
+    }
+
+    /// Sets the serial number.
+    #[must_use]
+    #[inline]
+    pub fn serial_number(mut self, serial_number: &'static str) -> Self {
+        self.device.identity.serial_number = Some(Cow::Borrowed(serial_number));
+        self
+    }
+
+    /// Sets the stable unique identifier.
+    #[must_use]
+    #[inline]
+    pub fn uuid(mut self, uuid: Uuid) -> Self {
+        self.device.identity.uuid = Some(uuid);
+        self
+    }
+
     /// Adds a [`Route`] with a stateless handler.
+    ///
+    /// A route which does not comply with the device profile is discarded.
     #[must_use]
     pub fn stateless_route<F, Fut>(mut self, route: Route, func: F) -> Self
     where
@@ -153,9 +202,17 @@ where
         Fut: Future<Output = Response> + Send + Sync + 'static,
     {
         let route_config = route
-            .remove_prohibited_hazards(ALLOWED_HAZARDS)
+            .remove_prohibited_hazards(PROFILE.allowed_hazards)
             .serialize_data();
 
+        if let Err(e) = PROFILE.check_route(&route_config) {
+            error!(
+                "The route with prefix `{}` does not comply with the device profile, removed: {e}",
+                route_config.data.path
+            );
+            return self;
+        }
+
         if self.device.route_configs.contains(&route_config) {
             error!(
                 "The route with prefix `{}` already exists!",
@@ -174,6 +231,8 @@ where
     }
 
     /// Adds a [`Route`] with a stateful handler.
+    ///
+    /// A route which does not comply with the device profile is discarded.
     #[must_use]
     pub fn stateful_route<F, Fut>(mut self, route: Route, func: F) -> Self
     where
@@ -181,9 +240,17 @@ where
         Fut: Future<Output = Response> + Send + Sync + 'static,
     {
         let route_config = route
-            .remove_prohibited_hazards(ALLOWED_HAZARDS)
+            .remove_prohibited_hazards(PROFILE.allowed_hazards)
             .serialize_data();
 
+        if let Err(e) = PROFILE.check_route(&route_config) {
+            error!(
+                "The route with prefix `{}` does not comply with the device profile, removed: {e}",
+                route_config.data.path
+            );
+            return self;
+        }
+
         if self.device.route_configs.contains(&route_config) {
             error!(
                 "The route with prefix `{}` already exists!",
@@ -220,13 +287,13 @@ where
         let id = wifi_interface.mac_address();
 
         let device = DeviceData::new(
-            DeviceKind::Light,
+            PROFILE.kind,
             DeviceEnvironment::Esp32,
             MAIN_ROUTE,
             RouteConfigs::new(),
             Some(id),
             None,
-            2,
+            u8::try_from(PROFILE.mandatory_routes.len()).unwrap_or(u8::MAX),
         )
         .description("A light device.");
 
@@ -1,2 +1,4 @@
 /// A `light` device.
 pub mod light;
+/// A `smart plug` device.
+pub mod smart_plug;
@@ -0,0 +1,358 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use ascot::device::{DeviceData, DeviceEnvironment, DeviceKind, DeviceProfile, Uuid};
+use ascot::route::{Route, RouteConfigs};
+
+use esp_wifi::wifi::WifiDevice;
+
+use log::error;
+
+use crate::device::Device;
+use crate::response::Response;
+use crate::server::{FuncIndex, FuncType, InputFn, InputStateFn};
+use crate::state::{State, ValueFromRef};
+
+// Default main route.
+const MAIN_ROUTE: &str = "/smart-plug";
+
+// Device profile.
+const PROFILE: &DeviceProfile = DeviceKind::SmartPlug.profile();
+
+/// A `smart plug` device.
+///
+/// The first placeholder to construct a [`CompleteSmartPlug`].
+pub struct SmartPlug<S = ()>(CompleteSmartPlug<S>)
+where
+    S: ValueFromRef + Send + Sync + 'static;
+
+impl SmartPlug<()> {
+    /// Creates a [`SmartPlug`] without a [`State`].
+    #[must_use]
+    #[inline]
+    pub fn new(wifi_interface: &WifiDevice<'_>) -> Self {
+        Self(CompleteSmartPlug::with_state(wifi_interface, ()))
+    }
+}
+
+impl<S> SmartPlug<S>
+where
+    S: ValueFromRef + Send + Sync + 'static,
+{
+    /// Creates a [`SmartPlug`] with a [`State`].
+    #[inline]
+    pub fn with_state(wifi_interface: &WifiDevice<'_>, state: S) -> Self {
+        Self(CompleteSmartPlug::with_state(wifi_interface, state))
+    }
+
+    /// Turns a smart plug on using a stateless handler.
+    #[must_use]
+    #[inline]
+    pub fn turn_on_stateless<F, Fut>(
+        self,
+        route: ascot::route::SmartPlugOnRoute,
+        func: F,
+    ) -> SmartPlugOnRoute<S>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        SmartPlugOnRoute(self.0.stateless_route(route.into_route(), func))
+    }
+
+    /// Turns a smart plug on using a stateful handler.
+    #[must_use]
+    #[inline]
+    pub fn turn_on_stateful<F, Fut>(
+        self,
+        route: ascot::route::SmartPlugOnRoute,
+        func: F,
+    ) -> SmartPlugOnRoute<S>
+    where
+        F: Fn(State<S>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        SmartPlugOnRoute(self.0.stateful_route(route.into_route(), func))
+    }
+}
+
+/// A `smart plug` placeholder containing only the route to turn the plug on.
+///
+/// All of its methods constructs a [`SmartPlugOffRoute`].
+pub struct SmartPlugOnRoute<S = ()>(CompleteSmartPlug<S>)
+where
+    S: ValueFromRef + Send + Sync + 'static;
+
+impl<S> SmartPlugOnRoute<S>
+where
+    S: ValueFromRef + Send + Sync + 'static,
+{
+    /// Turns a smart plug off using a stateless handler.
+    #[must_use]
+    #[inline]
+    pub fn turn_off_stateless<F, Fut>(
+        self,
+        route: ascot::route::SmartPlugOffRoute,
+        func: F,
+    ) -> SmartPlugOffRoute<S>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        SmartPlugOffRoute(self.0.stateless_route(route.into_route(), func))
+    }
+
+    /// Turns a smart plug off using a stateful handler.
+    #[must_use]
+    #[inline]
+    pub fn turn_off_stateful<F, Fut>(
+        self,
+        route: ascot::route::SmartPlugOffRoute,
+        func: F,
+    ) -> SmartPlugOffRoute<S>
+    where
+        F: Fn(State<S>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        SmartPlugOffRoute(self.0.stateful_route(route.into_route(), func))
+    }
+}
+
+/// A `smart plug` placeholder containing the routes to turn the plug on
+/// and off.
+///
+/// All of its methods constructs a [`CompleteSmartPlug`].
+pub struct SmartPlugOffRoute<S = ()>(CompleteSmartPlug<S>)
+where
+    S: ValueFromRef + Send + Sync + 'static;
+
+impl<S> SmartPlugOffRoute<S>
+where
+    S: ValueFromRef + Send + Sync + 'static,
+{
+    /// Measures the smart plug consumption using a stateless handler.
+    ///
+    /// The handler is expected to return a serialized
+    /// [`PowerMeasurement`](ascot::energy::PowerMeasurement).
+    #[must_use]
+    #[inline]
+    pub fn measurement_stateless<F, Fut>(
+        self,
+        route: ascot::route::SmartPlugMeasurementRoute,
+        func: F,
+    ) -> CompleteSmartPlug<S>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        self.0.stateless_route(route.into_route(), func)
+    }
+
+    /// Measures the smart plug consumption using a stateful handler.
+    ///
+    /// The handler is expected to return a serialized
+    /// [`PowerMeasurement`](ascot::energy::PowerMeasurement).
+    #[must_use]
+    #[inline]
+    pub fn measurement_stateful<F, Fut>(
+        self,
+        route: ascot::route::SmartPlugMeasurementRoute,
+        func: F,
+    ) -> CompleteSmartPlug<S>
+    where
+        F: Fn(State<S>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        self.0.stateful_route(route.into_route(), func)
+    }
+}
+
+/// A `smart plug` device with methods to turn a plug on and off and to
+/// measure its consumption.
+pub struct CompleteSmartPlug<S = ()>
+where
+    S: ValueFromRef + Send + Sync + 'static,
+{
+    main_route: &'static str,
+    state: State<S>,
+    routes_functions: (Vec<InputFn>, Vec<InputStateFn<S>>),
+    device: DeviceData,
+    index_array: Vec<FuncIndex>,
+}
+
+impl<S> CompleteSmartPlug<S>
+where
+    S: ValueFromRef + Send + Sync + 'static,
+{
+    /// Changes the main route.
+    #[must_use]
+    #[inline]
+    pub fn main_route(mut self, main_route: &'static str) -> Self {
+        self.main_route = main_route;
+        self.device.main_route = Cow::Borrowed(main_route);
+        self
+    }
+
+    /// Sets the manufacturer.
+    #[must_use]
+    #[inline]
+    pub fn manufacturer(mut self, manufacturer: &'static str) -> Self {
+        self.device.identity.manufacturer = Some(Cow::Borrowed(manufacturer));
+        self
+    }
+
+    /// Sets the model.
+    #[must_use]
+    #[inline]
+    pub fn model(mut self, model: &'static str) -> Self {
+        self.device.identity.model = Some(Cow::Borrowed(model));
+        self
+    }
+
+    /// Sets the hardware revision.
+    #[must_use]
+    #[inline]
+    pub fn hardware_revision(mut self, hardware_revision: &'static str) -> Self {
+        self.device.identity.hardware_revision = Some(Cow::Borrowed(hardware_revision));
+        self
+    }
+
+    /// Sets the firmware version.
+    #[must_use]
+    #[inline]
+    pub fn firmware_version(mut self, firmware_version: &'static str) -> Self {
+        self.device.identity.firmware_version = Some(Cow::Borrowed(firmware_version));
+        self
+    }
+
+    /// Sets the serial number.
+    #[must_use]
+    #[inline]
+    pub fn serial_number(mut self, serial_number: &'static str) -> Self {
+        self.device.identity.serial_number = Some(Cow::Borrowed(serial_number));
+        self
+    }
+
+    /// Sets the stable unique identifier.
+    #[must_use]
+    #[inline]
+    pub fn uuid(mut self, uuid: Uuid) -> Self {
+        self.device.identity.uuid = Some(uuid);
+        self
+    }
+
+    /// Adds a [`Route`] with a stateless handler.
+    ///
+    /// A route which does not comply with the device profile is discarded.
+    #[must_use]
+    pub fn stateless_route<F, Fut>(mut self, route: Route, func: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        let route_config = route
+            .remove_prohibited_hazards(PROFILE.allowed_hazards)
+            .serialize_data();
+
+        if let Err(e) = PROFILE.check_route(&route_config) {
+            error!(
+                "The route with prefix `{}` does not comply with the device profile, removed: {e}",
+                route_config.data.path
+            );
+            return self;
+        }
+
+        if self.device.route_configs.contains(&route_config) {
+            error!(
+                "The route with prefix `{}` already exists!",
+                route_config.data.path
+            );
+        }
+
+        let func: InputFn = Box::new(move || Box::pin(func()));
+        self.routes_functions.0.push(func);
+        self.device.route_configs.add(route_config);
+        self.index_array.push(FuncIndex::new(
+            FuncType::First,
+            self.routes_functions.0.len() - 1,
+        ));
+        self
+    }
+
+    /// Adds a [`Route`] with a stateful handler.
+    ///
+    /// A route which does not comply with the device profile is discarded.
+    #[must_use]
+    pub fn stateful_route<F, Fut>(mut self, route: Route, func: F) -> Self
+    where
+        F: Fn(State<S>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Response> + Send + Sync + 'static,
+    {
+        let route_config = route
+            .remove_prohibited_hazards(PROFILE.allowed_hazards)
+            .serialize_data();
+
+        if let Err(e) = PROFILE.check_route(&route_config) {
+            error!(
+                "The route with prefix `{}` does not comply with the device profile, removed: {e}",
+                route_config.data.path
+            );
+            return self;
+        }
+
+        if self.device.route_configs.contains(&route_config) {
+            error!(
+                "The route with prefix `{}` already exists!",
+                route_config.data.path
+            );
+        }
+
+        let func: InputStateFn<S> = Box::new(move |state| Box::pin(func(state)));
+        self.routes_functions.1.push(func);
+        self.device.route_configs.add(route_config);
+        self.index_array.push(FuncIndex::new(
+            FuncType::Second,
+            self.routes_functions.1.len() - 1,
+        ));
+        self
+    }
+
+    /// Builds a [`Device`].
+    #[must_use]
+    #[inline]
+    pub fn build(self) -> Device<S> {
+        Device::new(
+            self.main_route,
+            self.state,
+            self.routes_functions,
+            self.index_array,
+            Response::json(&self.device),
+            self.device.route_configs,
+        )
+    }
+
+    #[inline]
+    fn with_state(wifi_interface: &WifiDevice<'_>, state: S) -> Self {
+        let id = wifi_interface.mac_address();
+
+        let device = DeviceData::new(
+            PROFILE.kind,
+            DeviceEnvironment::Esp32,
+            MAIN_ROUTE,
+            RouteConfigs::new(),
+            Some(id),
+            None,
+            u8::try_from(PROFILE.mandatory_routes.len()).unwrap_or(u8::MAX),
+        )
+        .description("A smart plug device.");
+
+        Self {
+            main_route: MAIN_ROUTE,
+            state: State(state),
+            routes_functions: (Vec::new(), Vec::new()),
+            device,
+            index_array: Vec::new(),
+        }
+    }
+}
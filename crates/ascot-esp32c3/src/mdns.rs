@@ -1,6 +1,12 @@
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
 use core::cell::OnceCell;
 use core::net::{Ipv4Addr, Ipv6Addr};
 
+use ascot::protocol::{PROTOCOL_VERSION, PROTOCOL_VERSION_PROPERTY};
+
 use esp_hal::rng::Rng;
 
 use embassy_executor::Spawner;
@@ -70,6 +76,9 @@ impl Mdns {
     }
 
     /// Sets the `mDNS-SD` properties.
+    ///
+    /// The protocol version property is always advertised, hence it must
+    /// not be contained in the given properties.
     #[must_use]
     pub const fn properties(mut self, properties: &'static [(&'static str, &'static str)]) -> Self {
         self.properties = properties;
@@ -108,6 +117,16 @@ impl Mdns {
             self.domain_name
         );
 
+        // Advertise the protocol version along with the other properties.
+        //
+        // The service lives for the whole program, so leaking its
+        // properties is harmless.
+        let protocol_version: &'static str =
+            Box::leak(PROTOCOL_VERSION.to_string().into_boxed_str());
+        let mut properties = Vec::with_capacity(self.properties.len() + 1);
+        properties.push((PROTOCOL_VERSION_PROPERTY, protocol_version));
+        properties.extend_from_slice(self.properties);
+
         let service = Service {
             name: self.domain_name,
             priority: 1,
@@ -116,7 +135,7 @@ impl Mdns {
             protocol: "_tcp",
             port,
             service_subtypes: &[],
-            txt_kvs: self.properties,
+            txt_kvs: Box::leak(properties.into_boxed_slice()),
         };
 
         spawner
@@ -13,6 +13,7 @@ use ascot_os::actions::error::ErrorResponse;
 use ascot_os::actions::info::{InfoResponse, info_stateful};
 use ascot_os::actions::ok::{OkResponse, mandatory_ok_stateful, ok_stateful};
 use ascot_os::actions::serial::{SerialResponse, mandatory_serial_stateful, serial_stateful};
+use ascot_os::actions::validation::{validated, validated_mandatory};
 use ascot_os::devices::light::Light;
 use ascot_os::error::Error;
 use ascot_os::extract::{FromRef, Json, State};
@@ -263,10 +264,17 @@ async fn main() -> Result<(), Error> {
     // A light device which is going to be run on the server.
     let device = Light::with_state(state)
         // This method is mandatory, if not called, a compiler error is raised.
-        .turn_light_on(light_on_route, mandatory_serial_stateful(turn_light_on))
+        // Input parameters are validated before invoking the handler.
+        .turn_light_on(
+            light_on_route,
+            validated_mandatory(mandatory_serial_stateful(turn_light_on)),
+        )?
         // This method is mandatory, if not called, a compiler error is raised.
-        .turn_light_off(light_off_route, mandatory_ok_stateful(turn_light_off))
-        .add_action(serial_stateful(light_on_post_route, turn_light_on))?
+        .turn_light_off(light_off_route, mandatory_ok_stateful(turn_light_off))?
+        .add_action(validated(serial_stateful(
+            light_on_post_route,
+            turn_light_on,
+        )))?
         .add_action(ok_stateful(toggle_route, toggle))?
         .add_info_action(info_stateful(info_route, info))
         .add_info_action(info_stateful(
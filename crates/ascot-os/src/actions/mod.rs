@@ -10,6 +10,8 @@ pub mod serial;
 /// of bytes.
 #[cfg(feature = "stream")]
 pub mod stream;
+/// Validation of the input parameters of an action.
+pub mod validation;
 
 use ascot::hazards::{Hazard, Hazards};
 use ascot::parameters::ParametersData;
@@ -47,9 +49,14 @@ macro_rules! all_the_tuples {
 
 pub(super) use all_the_tuples;
 
+// Lists and objects cannot be expressed as path segments, so they are
+// passed through the query string.
 fn build_get_route(route: &str, parameters: &ParametersData) -> String {
     let mut route = String::from(route);
-    for (name, _) in parameters {
+    for (name, _) in parameters
+        .iter()
+        .filter(|(_, parameter)| !parameter.kind.is_structured())
+    {
         // TODO: Consider returning `Option<String>`
         if let Err(e) = write!(route, "/{{{name}}}") {
             error!("Error in adding a path to a route : {e}");
@@ -188,7 +195,7 @@ impl MandatoryAction<true> {
 
 #[cfg(test)]
 mod tests {
-    use ascot::parameters::Parameters;
+    use ascot::parameters::{ParameterKind, Parameters};
 
     use super::{Route, build_get_route};
 
@@ -208,4 +215,21 @@ mod tests {
             "/route/{rangeu64}/{rangef64}"
         );
     }
+
+    #[test]
+    fn test_build_get_route_with_structured_parameters() {
+        let route = Route::get("Route", "/route")
+            .with_parameters(
+                Parameters::new()
+                    .u8("u8", 0)
+                    .list("list", ParameterKind::Bool { default: false })
+                    .object("object", Parameters::new().bool("bool", false)),
+            )
+            .serialize_data();
+
+        assert_eq!(
+            &build_get_route(&route.data.path, &route.data.parameters),
+            "/route/{u8}"
+        );
+    }
 }
@@ -0,0 +1,587 @@
+use std::sync::Arc;
+
+use ascot::parameters::{Color, ParameterId, ParameterKind, ParameterValue, ParametersData};
+use ascot::route::{RestKind, Route};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{FromRequest, FromRequestParts, Query, RawPathParams, Request},
+    middleware::{Next, from_fn},
+    response::{IntoResponse, Response},
+};
+
+use serde_json::{Map, Value};
+
+use tracing::error;
+
+use super::{DeviceAction, MandatoryAction, error::ErrorResponse};
+
+// Error description returned for invalid input parameters.
+const INVALID_PARAMETERS: &str = "Invalid input parameters";
+
+fn invalid_parameters(error: &str) -> Response {
+    error!("{INVALID_PARAMETERS}: {error}");
+    ErrorResponse::invalid_data_with_error(INVALID_PARAMETERS, error).into_response()
+}
+
+fn wrong_type(name: &str, parameter_kind: &ParameterKind) -> String {
+    format!(
+        "`{name}` must be of type `{}`",
+        ParameterId::from_parameter_kind(parameter_kind).as_type()
+    )
+}
+
+// Parses a textual value according to a parameter kind.
+fn parse_str(parameter_kind: &ParameterKind, value: &str) -> Option<ParameterValue> {
+    match parameter_kind {
+        ParameterKind::Bool { .. } => value.parse::<bool>().ok().map(Into::into),
+        ParameterKind::U8 { .. } => value.parse::<u8>().ok().map(Into::into),
+        ParameterKind::U16 { .. } => value.parse::<u16>().ok().map(Into::into),
+        ParameterKind::U32 { .. } => value.parse::<u32>().ok().map(Into::into),
+        ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. } => {
+            value.parse::<u64>().ok().map(Into::into)
+        }
+        ParameterKind::I8 { .. } => value.parse::<i8>().ok().map(Into::into),
+        ParameterKind::I16 { .. } => value.parse::<i16>().ok().map(Into::into),
+        ParameterKind::I32 { .. } => value.parse::<i32>().ok().map(Into::into),
+        ParameterKind::I64 { .. } | ParameterKind::RangeI64 { .. } => {
+            value.parse::<i64>().ok().map(Into::into)
+        }
+        ParameterKind::F32 { .. } => value.parse::<f32>().ok().map(Into::into),
+        ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. } => {
+            value.parse::<f64>().ok().map(Into::into)
+        }
+        ParameterKind::CharsSequence { .. } | ParameterKind::Choice { .. } => Some(value.into()),
+        ParameterKind::Color { .. } => value.parse::<Color>().ok().map(Into::into),
+        // Structured values are expressed as JSON text.
+        ParameterKind::List { .. } | ParameterKind::Object { .. } => {
+            match serde_json::from_str::<Value>(value).ok()? {
+                value @ (Value::Array(_) | Value::Object(_)) => parse_json(parameter_kind, &value),
+                _ => None,
+            }
+        }
+    }
+}
+
+// Parses a JSON value according to a parameter kind.
+//
+// Numbers and booleans can also be expressed as strings, since
+// controllers might send all values in their textual form.
+#[allow(clippy::cast_possible_truncation)]
+fn parse_json(parameter_kind: &ParameterKind, value: &Value) -> Option<ParameterValue> {
+    match (parameter_kind, value) {
+        (_, Value::String(value)) => parse_str(parameter_kind, value),
+        (ParameterKind::Bool { .. }, Value::Bool(value)) => Some((*value).into()),
+        (ParameterKind::U8 { .. }, Value::Number(value)) => value
+            .as_u64()
+            .and_then(|v| u8::try_from(v).ok())
+            .map(Into::into),
+        (ParameterKind::U16 { .. }, Value::Number(value)) => value
+            .as_u64()
+            .and_then(|v| u16::try_from(v).ok())
+            .map(Into::into),
+        (ParameterKind::U32 { .. }, Value::Number(value)) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .map(Into::into),
+        (ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. }, Value::Number(value)) => {
+            value.as_u64().map(Into::into)
+        }
+        (ParameterKind::I8 { .. }, Value::Number(value)) => value
+            .as_i64()
+            .and_then(|v| i8::try_from(v).ok())
+            .map(Into::into),
+        (ParameterKind::I16 { .. }, Value::Number(value)) => value
+            .as_i64()
+            .and_then(|v| i16::try_from(v).ok())
+            .map(Into::into),
+        (ParameterKind::I32 { .. }, Value::Number(value)) => value
+            .as_i64()
+            .and_then(|v| i32::try_from(v).ok())
+            .map(Into::into),
+        (ParameterKind::I64 { .. } | ParameterKind::RangeI64 { .. }, Value::Number(value)) => {
+            value.as_i64().map(Into::into)
+        }
+        (ParameterKind::F32 { .. }, Value::Number(value)) => {
+            value.as_f64().map(|v| (v as f32).into())
+        }
+        (ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. }, Value::Number(value)) => {
+            value.as_f64().map(Into::into)
+        }
+        (ParameterKind::List { item, .. }, Value::Array(values)) => values
+            .iter()
+            .map(|value| parse_json(item, value))
+            .collect::<Option<_>>()
+            .map(ParameterValue::List),
+        (ParameterKind::Object { fields }, Value::Object(values)) => values
+            .iter()
+            .map(|(name, value)| Some((name.clone(), parse_json(&fields.get(name)?.kind, value)?)))
+            .collect::<Option<_>>()
+            .map(ParameterValue::Object),
+        _ => None,
+    }
+}
+
+fn to_json(value: ParameterValue) -> Value {
+    match value {
+        ParameterValue::Bool(v) => v.into(),
+        ParameterValue::U8(v) => v.into(),
+        ParameterValue::U16(v) => v.into(),
+        ParameterValue::U32(v) => v.into(),
+        ParameterValue::U64(v) => v.into(),
+        ParameterValue::I8(v) => v.into(),
+        ParameterValue::I16(v) => v.into(),
+        ParameterValue::I32(v) => v.into(),
+        ParameterValue::I64(v) => v.into(),
+        ParameterValue::F32(v) => v.into(),
+        ParameterValue::F64(v) => v.into(),
+        ParameterValue::CharsSequence(v) => v.into(),
+        ParameterValue::Color(v) => v.to_string().into(),
+        ParameterValue::List(values) => values.into_iter().map(to_json).collect(),
+        ParameterValue::Object(fields) => fields
+            .into_iter()
+            .map(|(name, value)| (name, to_json(value)))
+            .collect::<Map<_, _>>()
+            .into(),
+    }
+}
+
+// Validates named values against the declared parameters.
+//
+// It returns the values converted according to their parameter kinds.
+fn validate<'a>(
+    parameters: &ParametersData,
+    values: impl Iterator<Item = (&'a str, &'a Value)>,
+) -> Result<Vec<(&'a str, ParameterValue)>, String> {
+    let mut parsed = Vec::new();
+    for (name, value) in values {
+        let Some(parameter) = parameters.get(name) else {
+            return Err(format!("`{name}` does not exist"));
+        };
+        let value =
+            parse_json(&parameter.kind, value).ok_or_else(|| wrong_type(name, &parameter.kind))?;
+        parsed.push((name, value));
+    }
+
+    parameters
+        .validate(parsed.iter().map(|(name, value)| (*name, value)))
+        .map_err(|e| e.to_string())?;
+
+    Ok(parsed)
+}
+
+async fn validate_path(parameters: &ParametersData, request: Request) -> Result<Request, Response> {
+    let (mut parts, body) = request.into_parts();
+    let path_parameters = RawPathParams::from_request_parts(&mut parts, &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    // Structured parameters are retrieved from the query string.
+    let query = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+        .map_err(IntoResponse::into_response)?;
+
+    let values: Vec<(&str, Value)> = path_parameters
+        .iter()
+        .chain(
+            query
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        )
+        .map(|(name, value)| (name, Value::String(value.into())))
+        .collect();
+    let parsed = validate(
+        parameters,
+        values.iter().map(|(name, value)| (*name, value)),
+    )
+    .map_err(|e| invalid_parameters(&e))?;
+
+    // Lists and objects are optional in the query string, so they might
+    // miss although required.
+    let supplied: Vec<(&str, &ParameterValue)> =
+        parsed.iter().map(|(name, value)| (*name, value)).collect();
+    parameters
+        .validate_constraints(&supplied)
+        .map_err(|e| invalid_parameters(&e.to_string()))?;
+
+    Ok(Request::from_parts(parts, body))
+}
+
+async fn validate_body(parameters: &ParametersData, request: Request) -> Result<Request, Response> {
+    let (parts, body) = request.into_parts();
+
+    // The body is buffered within the `DefaultBodyLimit` of the router, which
+    // is stored among the request extensions. Bodies exceeding it are
+    // rejected with `413 Payload Too Large`.
+    let mut body_request = Request::new(body);
+    *body_request.extensions_mut() = parts.extensions.clone();
+    let bytes = Bytes::from_request(body_request, &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    let Ok(Value::Object(values)) = serde_json::from_slice::<Value>(&bytes) else {
+        return Err(invalid_parameters("Input parameters must be a JSON object"));
+    };
+
+    let parsed = validate(
+        parameters,
+        values.iter().map(|(name, value)| (name.as_str(), value)),
+    )
+    .map_err(|e| invalid_parameters(&e))?;
+
+    let supplied: Vec<(&str, &ParameterValue)> =
+        parsed.iter().map(|(name, value)| (*name, value)).collect();
+    parameters
+        .validate_constraints(&supplied)
+        .map_err(|e| invalid_parameters(&e.to_string()))?;
+
+    // Values are re-encoded with their declared types, and missing values
+    // are replaced with their defaults, so handlers always receive
+    // consistent inputs. Constrained parameters are never replaced, since
+    // their absence is meaningful.
+    let mut values: Map<String, Value> = parsed
+        .into_iter()
+        .map(|(name, value)| (name.into(), to_json(value)))
+        .collect();
+    for (name, parameter) in parameters {
+        if !values.contains_key(name) && !parameters.is_constrained(name) {
+            values.insert(
+                name.clone(),
+                to_json(ParameterValue::from_parameter_kind(&parameter.kind)),
+            );
+        }
+    }
+    let body = serde_json::to_vec(&values).map_err(|e| invalid_parameters(&e.to_string()))?;
+
+    Ok(Request::from_parts(parts, Body::from(body)))
+}
+
+impl DeviceAction {
+    fn with_validation(mut self) -> Self {
+        let parameters = &self.route_config.data.parameters;
+        if parameters.is_empty() {
+            return self;
+        }
+
+        let parameters = Arc::new(parameters.clone());
+        let rest_kind = self.route_config.rest_kind;
+
+        self.router = self
+            .router
+            .layer(from_fn(move |request: Request, next: Next| {
+                let parameters = parameters.clone();
+                async move {
+                    let request = if rest_kind == RestKind::Get {
+                        validate_path(&parameters, request).await
+                    } else {
+                        validate_body(&parameters, request).await
+                    };
+
+                    match request {
+                        Ok(request) => next.run(request).await,
+                        Err(response) => response,
+                    }
+                }
+            }));
+
+        self
+    }
+}
+
+/// Validates the input parameters of a [`DeviceAction`] before invoking its
+/// handler.
+///
+/// Input parameters are checked against the [`ascot::parameters::Parameters`]
+/// declared by the action route. When a parameter is missing, undeclared,
+/// or out of its domain, an [`ErrorResponse::invalid_data_with_error`] is
+/// returned and the handler is not invoked.
+///
+/// Input parameters of `GET` routes are retrieved from the route path,
+/// except for lists and objects which are retrieved from the query string
+/// as JSON text. Required parameters and parameter dependencies are checked
+/// for all routes. When retrieved from a JSON body, missing optional
+/// parameters are replaced with their default values, unless their presence
+/// is constrained by a dependency.
+#[inline]
+pub fn validated<S>(action: impl FnOnce(S) -> DeviceAction) -> impl FnOnce(S) -> DeviceAction {
+    move |state: S| action(state).with_validation()
+}
+
+/// Validates the input parameters of a [`MandatoryAction`] before invoking
+/// its handler.
+///
+/// The validation rules are the same of [`validated`].
+#[inline]
+pub fn validated_mandatory<S>(
+    action: impl FnOnce(Route, S) -> MandatoryAction<false>,
+) -> impl FnOnce(Route, S) -> MandatoryAction<false> {
+    move |route: Route, state: S| {
+        MandatoryAction::new(action(route, state).device_action.with_validation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ascot::parameters::Parameters;
+    use ascot::route::Route;
+
+    use axum::{
+        body::{Body, to_bytes},
+        extract::{Json, Path},
+        http::{Request, StatusCode},
+    };
+
+    use serde::Deserialize;
+
+    use tower::ServiceExt;
+
+    use crate::actions::error::ErrorResponse;
+    use crate::actions::ok::{OkResponse, ok_stateless};
+    use crate::actions::serial::{SerialResponse, serial_stateless};
+
+    use super::{
+        Color, ParameterKind, ParameterValue, Value, parse_json, parse_str, to_json, validated,
+    };
+
+    #[derive(Deserialize)]
+    struct Inputs {
+        brightness: u64,
+        #[serde(rename = "save-energy")]
+        save_energy: bool,
+    }
+
+    async fn turn_light_on(
+        Json(inputs): Json<Inputs>,
+    ) -> Result<SerialResponse<(u64, bool)>, ErrorResponse> {
+        Ok(SerialResponse::new((inputs.brightness, inputs.save_energy)))
+    }
+
+    async fn brightness(Path(brightness): Path<u64>) -> Result<OkResponse, ErrorResponse> {
+        assert!(brightness <= 20);
+        Ok(OkResponse::ok())
+    }
+
+    fn parameters() -> Parameters {
+        Parameters::new()
+            .rangeu64("brightness", (0, 20, 2))
+            .required("brightness")
+            .bool("save-energy", false)
+    }
+
+    async fn send(route: Route, request: Request<Body>) -> (StatusCode, String) {
+        let action = if matches!(route.kind(), ascot::route::RestKind::Get) {
+            validated(ok_stateless(route, brightness))(())
+        } else {
+            validated(serial_stateless(route, turn_light_on))(())
+        };
+
+        let response = action.router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn post(body: &str) -> Request<Body> {
+        Request::post("/on")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_owned()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn validate_body() {
+        let route = || Route::post("On", "/on").with_parameters(parameters());
+
+        // Values expressed as strings are converted to their declared types.
+        let (status, body) = send(
+            route(),
+            post(r#"{"brightness": "4", "save-energy": "true"}"#),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("[4,true]"));
+
+        // Out of range.
+        let (status, body) =
+            send(route(), post(r#"{"brightness": 40, "save-energy": true}"#)).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(body.contains("outside of [0, 20]"));
+
+        // Not aligned to the step.
+        let (_, body) = send(route(), post(r#"{"brightness": 5, "save-energy": true}"#)).await;
+        assert!(body.contains("with step"));
+
+        // Missing optional parameters are replaced with their defaults.
+        let (status, body) = send(route(), post(r#"{"brightness": 4}"#)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("[4,false]"));
+
+        // Missing required parameter.
+        let (_, body) = send(route(), post(r#"{"save-energy": true}"#)).await;
+        assert!(body.contains("`brightness` is required"));
+
+        // Missing constrained parameters are not replaced with defaults.
+        let send_echo = |request: Request<Body>| async {
+            let route = Route::post("Timer", "/on").with_parameters(
+                Parameters::new()
+                    .u8("duration", 0)
+                    .u8("period", 0)
+                    .bool("fade", false)
+                    .excludes("duration", "period"),
+            );
+            let action = validated(serial_stateless(route, echo))(());
+            let response = action.router.oneshot(request).await.unwrap();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            String::from_utf8(body.to_vec()).unwrap()
+        };
+        let body = send_echo(post(r#"{"duration": 10}"#)).await;
+        assert!(body.contains(r#"{"duration":10,"fade":false}"#));
+
+        // Wrong type.
+        let (_, body) = send(
+            route(),
+            post(r#"{"brightness": "high", "save-energy": true}"#),
+        )
+        .await;
+        assert!(body.contains("must be of type `u64`"));
+
+        // Not a JSON object.
+        let (_, body) = send(route(), post("[]")).await;
+        assert!(body.contains("JSON object"));
+
+        // Body larger than the default limit.
+        let (status, _) = send(route(), post(&" ".repeat(3 * 1024 * 1024))).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn validate_path() {
+        let route = || {
+            Route::get("Brightness", "/brightness")
+                .with_parameters(Parameters::new().rangeu64("brightness", (0, 20, 1)))
+        };
+        let get = |uri: &str| Request::get(uri).body(Body::empty()).unwrap();
+
+        let (status, _) = send(route(), get("/brightness/20")).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send(route(), get("/brightness/21")).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(body.contains("outside of [0, 20]"));
+    }
+
+    #[test]
+    fn color_encoding() {
+        let kind = ParameterKind::Color {
+            default: Color::kelvin(4000),
+            min_kelvin: 2700,
+            max_kelvin: 6500,
+        };
+
+        let value = parse_json(&kind, &Value::from("kelvin:3000")).unwrap();
+        assert_eq!(value, ParameterValue::Color(Color::kelvin(3000)));
+        assert_eq!(to_json(value), Value::from("kelvin:3000"));
+
+        assert_eq!(
+            parse_str(&kind, "#ff8000"),
+            Some(ParameterValue::Color(Color::rgb(255, 128, 0)))
+        );
+        assert_eq!(parse_str(&kind, "hsv:400,0,0"), None);
+        assert_eq!(parse_json(&kind, &Value::from(3000)), None);
+    }
+
+    async fn echo(Json(inputs): Json<Value>) -> Result<SerialResponse<Value>, ErrorResponse> {
+        Ok(SerialResponse::new(inputs))
+    }
+
+    async fn scene(Path(id): Path<u8>) -> Result<OkResponse, ErrorResponse> {
+        assert_eq!(id, 1);
+        Ok(OkResponse::ok())
+    }
+
+    fn structured_parameters() -> Parameters {
+        Parameters::new()
+            .list_with_length(
+                "schedule",
+                ParameterKind::U8 {
+                    default: 0,
+                    min: 0,
+                    max: 100,
+                },
+                1,
+                4,
+            )
+            .object(
+                "resolution",
+                Parameters::new()
+                    .u16_with_limits("width", 640, 0, 1920)
+                    .u16_with_limits("height", 480, 0, 1080),
+            )
+    }
+
+    #[tokio::test]
+    async fn validate_structured_parameters() {
+        let send = |request: Request<Body>| async {
+            let route = Route::post("On", "/on").with_parameters(structured_parameters());
+            let action = validated(serial_stateless(route, echo))(());
+            let response = action.router.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        };
+
+        // Leaves expressed as strings are converted to their declared types.
+        let (status, body) = send(post(
+            r#"{"schedule": ["10", 20], "resolution": {"width": "1920", "height": 1080}}"#,
+        ))
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(r#"{"schedule":[10,20],"resolution":{"width":1920,"height":1080}}"#));
+
+        let (status, body) = send(post(
+            r#"{"schedule": [10, 200], "resolution": {"width": 640, "height": 480}}"#,
+        ))
+        .await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(body.contains("`schedule[1]` value `200` is outside of [0, 100]"));
+
+        let (_, body) = send(post(r#"{"schedule": [10], "resolution": {"width": 640}}"#)).await;
+        assert!(body.contains("misses the `height` field"));
+
+        let (_, body) = send(post(r#"{"schedule": 10, "resolution": {}}"#)).await;
+        assert!(body.contains("must be of type `List`"));
+    }
+
+    #[tokio::test]
+    async fn validate_query() {
+        let send = |uri: &str| {
+            let route =
+                Route::get("Scene", "/scene").with_parameters(structured_parameters().u8("id", 0));
+            let action = validated(ok_stateless(route, scene))(());
+            let request = Request::get(uri).body(Body::empty()).unwrap();
+            async move { action.router.oneshot(request).await.unwrap() }
+        };
+
+        let response = send(
+            "/scene/1?schedule=%5B1%2C2%5D&resolution=%7B%22width%22%3A1%2C%22height%22%3A1%7D",
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = send("/scene/1?schedule=%5B%5D").await;
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        // Required parameters missing from the query string.
+        let route = Route::get("Scene", "/scene")
+            .with_parameters(structured_parameters().u8("id", 0).required("schedule"));
+        let action = validated(ok_stateless(route, scene))(());
+        let request = Request::get("/scene/1").body(Body::empty()).unwrap();
+        let response = action.router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(
+            String::from_utf8(body.to_vec())
+                .unwrap()
+                .contains("`schedule` is required")
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use ascot::device::{DeviceData, DeviceEnvironment, DeviceKind};
+use std::borrow::Cow;
+
+use ascot::device::{DeviceData, DeviceEnvironment, DeviceIdentity, DeviceKind, Uuid};
+use ascot::hazards::{CustomHazard, CustomHazardData, Hazard};
 use ascot::route::RouteConfigs;
 
 use axum::Router;
@@ -6,6 +9,7 @@ use axum::Router;
 use tracing::{info, warn};
 
 use crate::actions::DeviceAction;
+use crate::error::{Error, Result};
 use crate::mac::get_mac_addresses;
 
 // Default main route for a device.
@@ -25,8 +29,12 @@ where
     pub(crate) state: S,
     // Kind.
     kind: DeviceKind,
+    // Identity.
+    identity: DeviceIdentity,
     // All device routes and their hazards.
     route_configs: RouteConfigs,
+    // Custom hazards declared by the device vendor.
+    custom_hazards: Vec<CustomHazardData>,
     /// Number of mandatory routes.
     num_mandatory_routes: u8,
 }
@@ -64,6 +72,74 @@ where
         self
     }
 
+    /// Sets the device manufacturer.
+    #[must_use]
+    #[inline]
+    pub fn manufacturer(mut self, manufacturer: impl Into<Cow<'static, str>>) -> Self {
+        self.identity = self.identity.manufacturer(manufacturer);
+        self
+    }
+
+    /// Sets the device model.
+    #[must_use]
+    #[inline]
+    pub fn model(mut self, model: impl Into<Cow<'static, str>>) -> Self {
+        self.identity = self.identity.model(model);
+        self
+    }
+
+    /// Sets the device hardware revision.
+    #[must_use]
+    #[inline]
+    pub fn hardware_revision(mut self, hardware_revision: impl Into<Cow<'static, str>>) -> Self {
+        self.identity = self.identity.hardware_revision(hardware_revision);
+        self
+    }
+
+    /// Sets the device firmware version.
+    #[must_use]
+    #[inline]
+    pub fn firmware_version(mut self, firmware_version: impl Into<Cow<'static, str>>) -> Self {
+        self.identity = self.identity.firmware_version(firmware_version);
+        self
+    }
+
+    /// Sets the device serial number.
+    #[must_use]
+    #[inline]
+    pub fn serial_number(mut self, serial_number: impl Into<Cow<'static, str>>) -> Self {
+        self.identity = self.identity.serial_number(serial_number);
+        self
+    }
+
+    /// Sets the device stable unique identifier.
+    #[must_use]
+    pub const fn uuid(mut self, uuid: Uuid) -> Self {
+        self.identity.uuid = Some(uuid);
+        self
+    }
+
+    /// Declares a custom hazard which can be associated with the [`Device`]
+    /// routes.
+    ///
+    /// A custom hazard declared twice is described by the last declaration.
+    /// A route using a custom hazard which has not been declared, or which
+    /// has been declared with a different category, prevents the device
+    /// from running.
+    #[must_use]
+    pub fn custom_hazard(
+        mut self,
+        hazard: CustomHazard,
+        name: impl Into<Cow<'static, str>>,
+        description: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.custom_hazards
+            .retain(|custom_hazard| custom_hazard.hazard.id() != hazard.id());
+        self.custom_hazards
+            .push(CustomHazardData::new(hazard, name, description));
+        self
+    }
+
     /// Adds an action to the [`Device`].
     #[must_use]
     #[inline]
@@ -84,7 +160,9 @@ where
             main_route: DEFAULT_MAIN_ROUTE,
             router: Router::new(),
             kind,
+            identity: DeviceIdentity::empty(),
             route_configs: RouteConfigs::new(),
+            custom_hazards: Vec::new(),
             state,
             num_mandatory_routes: 0,
         }
@@ -111,10 +189,61 @@ where
         self
     }
 
-    pub(crate) fn finalize(self) -> (&'static str, DeviceData, Router) {
+    // Checks whether all custom hazards used by the routes have been
+    // declared with the same category.
+    fn check_custom_hazards(&self) -> Result<()> {
+        for route in &self.route_configs {
+            let hazards = route.data.hazards.iter().copied().chain(
+                route
+                    .data
+                    .conditional_hazards
+                    .iter()
+                    .map(|conditional_hazard| conditional_hazard.hazard),
+            );
+            for hazard in hazards {
+                let Hazard::Custom(custom_hazard) = hazard else {
+                    continue;
+                };
+                match self
+                    .custom_hazards
+                    .iter()
+                    .find(|declared| declared.hazard.id() == custom_hazard.id())
+                {
+                    None => {
+                        return Err(Error::device(
+                            self.kind,
+                            format!(
+                                "The route `{}` uses the undeclared {hazard}",
+                                route.data.path
+                            ),
+                        ));
+                    }
+                    Some(declared) if declared.hazard.category() != custom_hazard.category() => {
+                        return Err(Error::device(
+                            self.kind,
+                            format!(
+                                "The route `{}` uses the {hazard} with the `{}` category, but it has been declared with the `{}` category",
+                                route.data.path,
+                                custom_hazard.category(),
+                                declared.hazard.category()
+                            ),
+                        ));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn finalize(self) -> Result<(&'static str, DeviceData, Router)> {
+        self.check_custom_hazards()?;
+
         let (wifi_mac, ethernet_mac) = get_mac_addresses();
         if wifi_mac.is_none() && ethernet_mac.is_none() {
-            warn!("Unable to retrieve any Wi-Fi or Ethernet MAC address.");
+            warn!(
+                "Unable to retrieve any Wi-Fi or Ethernet MAC address, the device will be identified only by its unique identifier."
+            );
         }
 
         for route in &self.route_configs {
@@ -124,7 +253,7 @@ where
             );
         }
 
-        (
+        Ok((
             self.main_route,
             DeviceData::new(
                 self.kind,
@@ -134,9 +263,11 @@ where
                 wifi_mac,
                 ethernet_mac,
                 self.num_mandatory_routes,
-            ),
+            )
+            .identity(self.identity)
+            .custom_hazards(self.custom_hazards),
             self.router,
-        )
+        ))
     }
 }
 
@@ -146,8 +277,9 @@ mod tests {
 
     use core::ops::{Deref, DerefMut};
 
-    use ascot::device::DeviceInfo;
+    use ascot::device::{DeviceIdentity, DeviceInfo, Uuid};
     use ascot::energy::Energy;
+    use ascot::hazards::{Category, CustomHazard, CustomHazardData, Hazard};
     use ascot::route::Route;
 
     use async_lock::Mutex;
@@ -372,6 +504,96 @@ mod tests {
             ));
     }
 
+    #[test]
+    fn with_identity() {
+        let uuid = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+
+        let (_, device_data, _) = Device::new()
+            .manufacturer("Ascot")
+            .model("Generic")
+            .hardware_revision("rev. A")
+            .firmware_version(env!("CARGO_PKG_VERSION"))
+            .serial_number("SN-0001")
+            .uuid(uuid)
+            .finalize()
+            .unwrap();
+
+        assert_eq!(
+            device_data.identity,
+            DeviceIdentity::empty()
+                .manufacturer("Ascot")
+                .model("Generic")
+                .hardware_revision("rev. A")
+                .firmware_version(env!("CARGO_PKG_VERSION"))
+                .serial_number("SN-0001")
+                .uuid(uuid)
+        );
+    }
+
+    #[test]
+    fn with_custom_hazards() {
+        let dosing = CustomHazard::new(1000, Category::Safety).unwrap();
+        let exposure = CustomHazard::new(1001, Category::Safety).unwrap();
+
+        let (_, device_data, _) = Device::new()
+            .custom_hazard(dosing, "Dosing", "Doses chemicals.")
+            .custom_hazard(exposure, "UV Exposure", "Emits ultraviolet light.")
+            .custom_hazard(
+                dosing,
+                "Chemical Dosing",
+                "Doses chemicals into the pool water.",
+            )
+            .finalize()
+            .unwrap();
+
+        assert_eq!(
+            device_data.custom_hazards,
+            vec![
+                CustomHazardData::new(exposure, "UV Exposure", "Emits ultraviolet light."),
+                CustomHazardData::new(
+                    dosing,
+                    "Chemical Dosing",
+                    "Doses chemicals into the pool water."
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_undeclared_custom_hazards() {
+        let dosing = CustomHazard::new(1000, Category::Safety).unwrap();
+        let dose = || {
+            serial_stateless(
+                Route::put("Dose", "/dose").with_hazard(Hazard::Custom(dosing)),
+                serial_action_without_state,
+            )
+        };
+
+        // A custom hazard must be declared.
+        assert!(Device::new().add_action(dose()).finalize().is_err());
+
+        // A custom hazard must be declared with the same category.
+        assert!(
+            Device::new()
+                .custom_hazard(
+                    CustomHazard::new(1000, Category::Privacy).unwrap(),
+                    "Dosing",
+                    "Doses chemicals.",
+                )
+                .add_action(dose())
+                .finalize()
+                .is_err()
+        );
+
+        assert!(
+            Device::new()
+                .custom_hazard(dosing, "Dosing", "Doses chemicals.")
+                .add_action(dose())
+                .finalize()
+                .is_ok()
+        );
+    }
+
     #[test]
     fn without_state() {
         let routes = create_routes();
@@ -0,0 +1,297 @@
+use ascot::device::DeviceKind;
+use ascot::route::{CameraSnapshotRoute, CameraStreamRoute, Route};
+
+use crate::actions::{DeviceAction, MandatoryAction};
+use crate::device::Device;
+use crate::error::Result;
+
+use super::{check_action_hazards, check_mandatory_action};
+
+// The default main route for a camera.
+const CAMERA_MAIN_ROUTE: &str = "/camera";
+
+/// A smart home camera.
+///
+/// The default server main route for a camera is `camera`.
+///
+/// If a smart home needs more cameras, each camera **MUST** provide a
+/// **different** main route in order to be registered.
+pub struct Camera<const M1: bool, const M2: bool, S = ()>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    // Internal device.
+    device: Device<S>,
+    // Live stream action.
+    stream: MandatoryAction<M1>,
+    // Snapshot action.
+    snapshot: MandatoryAction<M2>,
+}
+
+impl Default for Camera<false, false, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Camera<false, false, ()> {
+    /// Creates a [`Camera`] instance without a state.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_state(())
+    }
+}
+
+impl<S> Camera<false, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Creates a [`Camera`] instance with a state.
+    #[inline]
+    pub fn with_state(state: S) -> Self {
+        // Create a new device.
+        let device = Device::init(DeviceKind::Camera, state).main_route(CAMERA_MAIN_ROUTE);
+
+        Self {
+            device,
+            stream: MandatoryAction::empty(),
+            snapshot: MandatoryAction::empty(),
+        }
+    }
+
+    /// Adds a live stream action for a [`Camera`].
+    ///
+    /// The action must return a stream response, hence it has to be created
+    /// either through
+    /// [`mandatory_stream_stateful`](crate::actions::stream::mandatory_stream_stateful)
+    /// or through
+    /// [`mandatory_stream_stateless`](crate::actions::stream::mandatory_stream_stateless).
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the camera
+    /// profile, for example when it does not return a stream response.
+    pub fn stream(
+        self,
+        route: CameraStreamRoute,
+        stream: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<Camera<true, false, S>> {
+        let stream = stream(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::Camera, &stream.device_action)?;
+
+        Ok(Camera {
+            device: self.device,
+            stream: MandatoryAction::init(stream.device_action),
+            snapshot: self.snapshot,
+        })
+    }
+}
+
+impl<S> Camera<true, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Adds a snapshot action for a [`Camera`].
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the camera
+    /// profile.
+    pub fn snapshot(
+        self,
+        route: CameraSnapshotRoute,
+        snapshot: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<Camera<true, true, S>> {
+        let snapshot = snapshot(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::Camera, &snapshot.device_action)?;
+
+        Ok(Camera {
+            device: self.device,
+            stream: self.stream,
+            snapshot: MandatoryAction::init(snapshot.device_action),
+        })
+    }
+}
+
+impl<S> Camera<true, true, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Sets a new main route.
+    #[must_use]
+    #[inline]
+    pub fn main_route(mut self, main_route: &'static str) -> Self {
+        self.device = self.device.main_route(main_route);
+        self
+    }
+
+    /// Adds an additional action for a [`Camera`].
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether one or more hazards are not allowed for
+    /// the [`Camera`] device.
+    pub fn add_action(mut self, camera_action: impl FnOnce(S) -> DeviceAction) -> Result<Self> {
+        let camera_action = camera_action(self.device.state.clone());
+
+        // Return an error if action hazards are not allowed.
+        check_action_hazards(DeviceKind::Camera, &camera_action)?;
+
+        self.device = self.device.add_device_action(camera_action);
+
+        Ok(self)
+    }
+
+    /// Adds an informative action for [`Camera`].
+    #[must_use]
+    pub fn add_info_action(
+        mut self,
+        camera_info_action: impl FnOnce(S, ()) -> DeviceAction,
+    ) -> Self {
+        let camera_info_action = camera_info_action(self.device.state.clone(), ());
+
+        self.device = self.device.add_device_action(camera_info_action);
+
+        self
+    }
+
+    /// Converts a [`Camera`] into a [`Device`].
+    pub fn into_device(self) -> Device<S> {
+        self.device
+            .add_mandatory_actions([self.stream.device_action, self.snapshot.device_action])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use ascot::hazards::Hazard;
+    use ascot::route::Route;
+
+    use axum::extract::State;
+
+    use crate::actions::error::ErrorResponse;
+    use crate::actions::ok::{OkResponse, mandatory_ok_stateless, ok_stateful, ok_stateless};
+    use crate::actions::stream::{
+        StreamResponse, mandatory_stream_stateful, mandatory_stream_stateless,
+    };
+    use crate::devices::camera::{CameraSnapshotRoute, CameraStreamRoute};
+
+    use super::Camera;
+
+    #[derive(Clone)]
+    struct CameraState;
+
+    async fn stream(State(_state): State<CameraState>) -> Result<StreamResponse, ErrorResponse> {
+        Ok(StreamResponse::from_reader(&b"frame"[..]))
+    }
+
+    async fn stream_stateless() -> Result<StreamResponse, ErrorResponse> {
+        Ok(StreamResponse::from_reader(&b"frame"[..]))
+    }
+
+    async fn record(State(_state): State<CameraState>) -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    async fn record_stateless() -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    struct Routes {
+        stream: CameraStreamRoute,
+        snapshot: CameraSnapshotRoute,
+        record: Route,
+        unlock: Route,
+    }
+
+    #[inline]
+    fn create_routes() -> Routes {
+        Routes {
+            stream: CameraStreamRoute::get("Stream")
+                .description("Show the camera live stream.")
+                .with_hazard(Hazard::VideoDisplay),
+
+            snapshot: CameraSnapshotRoute::get("Snapshot")
+                .description("Take a picture.")
+                .with_hazard(Hazard::TakePictures),
+
+            record: Route::put("Record", "/record")
+                .description("Record a video.")
+                .with_hazard(Hazard::VideoRecordAndStore),
+
+            unlock: Route::put("Unlock", "/unlock")
+                .description("Unlock the door next to the camera.")
+                .with_hazard(Hazard::UnauthorisedPhysicalAccess),
+        }
+    }
+
+    #[test]
+    fn complete_with_state() {
+        let routes = create_routes();
+
+        Camera::with_state(CameraState {})
+            .stream(routes.stream, mandatory_stream_stateful(stream))
+            .unwrap()
+            .snapshot(routes.snapshot, mandatory_stream_stateful(stream))
+            .unwrap()
+            .add_action(ok_stateful(routes.record, record))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn complete_without_state() {
+        let routes = create_routes();
+
+        Camera::new()
+            .stream(routes.stream, mandatory_stream_stateless(stream_stateless))
+            .unwrap()
+            .snapshot(
+                routes.snapshot,
+                mandatory_stream_stateless(stream_stateless),
+            )
+            .unwrap()
+            .add_action(ok_stateless(routes.record, record_stateless))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn not_allowed_hazard() {
+        let routes = create_routes();
+
+        assert!(
+            Camera::new()
+                .stream(routes.stream, mandatory_stream_stateless(stream_stateless))
+                .unwrap()
+                .snapshot(
+                    routes.snapshot,
+                    mandatory_stream_stateless(stream_stateless)
+                )
+                .unwrap()
+                .add_action(ok_stateless(routes.unlock, record_stateless))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn stream_without_stream_response() {
+        let routes = create_routes();
+
+        assert!(
+            Camera::new()
+                .stream(routes.stream, mandatory_ok_stateless(record_stateless))
+                .is_err_and(|e| e.to_string().contains("must return a Stream response"))
+        );
+    }
+}
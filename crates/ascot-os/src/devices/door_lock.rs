@@ -0,0 +1,332 @@
+use ascot::device::DeviceKind;
+use ascot::route::{DoorLockLockRoute, DoorLockStatusRoute, DoorLockUnlockRoute, Route};
+
+use crate::actions::{DeviceAction, MandatoryAction};
+use crate::device::Device;
+use crate::error::Result;
+
+use super::{check_action_hazards, check_mandatory_action};
+
+// The default main route for a door lock.
+const DOOR_LOCK_MAIN_ROUTE: &str = "/door-lock";
+
+/// A smart home door lock.
+///
+/// The default server main route for a door lock is `door-lock`.
+///
+/// If a smart home needs more door locks, each door lock **MUST** provide a
+/// **different** main route in order to be registered.
+pub struct DoorLock<const M1: bool, const M2: bool, const M3: bool, S = ()>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    // Internal device.
+    device: Device<S>,
+    // Lock action.
+    lock: MandatoryAction<M1>,
+    // Unlock action.
+    unlock: MandatoryAction<M2>,
+    // Status action.
+    status: MandatoryAction<M3>,
+}
+
+impl Default for DoorLock<false, false, false, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DoorLock<false, false, false, ()> {
+    /// Creates a [`DoorLock`] instance without a state.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_state(())
+    }
+}
+
+impl<S> DoorLock<false, false, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Creates a [`DoorLock`] instance with a state.
+    #[inline]
+    pub fn with_state(state: S) -> Self {
+        // Create a new device.
+        let device = Device::init(DeviceKind::DoorLock, state).main_route(DOOR_LOCK_MAIN_ROUTE);
+
+        Self {
+            device,
+            lock: MandatoryAction::empty(),
+            unlock: MandatoryAction::empty(),
+            status: MandatoryAction::empty(),
+        }
+    }
+
+    /// Adds a lock action for a [`DoorLock`].
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the door
+    /// lock profile.
+    pub fn lock(
+        self,
+        route: DoorLockLockRoute,
+        lock: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<DoorLock<true, false, false, S>> {
+        let lock = lock(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::DoorLock, &lock.device_action)?;
+
+        Ok(DoorLock {
+            device: self.device,
+            lock: MandatoryAction::init(lock.device_action),
+            unlock: self.unlock,
+            status: self.status,
+        })
+    }
+}
+
+impl<S> DoorLock<true, false, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Adds an unlock action for a [`DoorLock`].
+    ///
+    /// An unlock action **MUST** always declare the
+    /// [`UnauthorisedPhysicalAccess`](ascot::hazards::Hazard::UnauthorisedPhysicalAccess)
+    /// hazard.
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the unlock action does not comply with the
+    /// door lock profile, for example when it does not declare the
+    /// [`UnauthorisedPhysicalAccess`](ascot::hazards::Hazard::UnauthorisedPhysicalAccess)
+    /// hazard.
+    pub fn unlock(
+        self,
+        route: DoorLockUnlockRoute,
+        unlock: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<DoorLock<true, true, false, S>> {
+        let unlock = unlock(route.into_route(), self.device.state.clone());
+
+        // Return an error if the unlock action misses a required hazard.
+        check_mandatory_action(DeviceKind::DoorLock, &unlock.device_action)?;
+
+        Ok(DoorLock {
+            device: self.device,
+            lock: self.lock,
+            unlock: MandatoryAction::init(unlock.device_action),
+            status: self.status,
+        })
+    }
+}
+
+impl<S> DoorLock<true, true, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Adds a status action for a [`DoorLock`].
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the door
+    /// lock profile.
+    pub fn status(
+        self,
+        route: DoorLockStatusRoute,
+        status: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<DoorLock<true, true, true, S>> {
+        let status = status(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::DoorLock, &status.device_action)?;
+
+        Ok(DoorLock {
+            device: self.device,
+            lock: self.lock,
+            unlock: self.unlock,
+            status: MandatoryAction::init(status.device_action),
+        })
+    }
+}
+
+impl<S> DoorLock<true, true, true, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Sets a new main route.
+    #[must_use]
+    #[inline]
+    pub fn main_route(mut self, main_route: &'static str) -> Self {
+        self.device = self.device.main_route(main_route);
+        self
+    }
+
+    /// Adds an additional action for a [`DoorLock`].
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether one or more hazards are not allowed for
+    /// the [`DoorLock`] device.
+    pub fn add_action(mut self, door_lock_action: impl FnOnce(S) -> DeviceAction) -> Result<Self> {
+        let door_lock_action = door_lock_action(self.device.state.clone());
+
+        // Return an error if action hazards are not allowed.
+        check_action_hazards(DeviceKind::DoorLock, &door_lock_action)?;
+
+        self.device = self.device.add_device_action(door_lock_action);
+
+        Ok(self)
+    }
+
+    /// Adds an informative action for [`DoorLock`].
+    #[must_use]
+    pub fn add_info_action(
+        mut self,
+        door_lock_info_action: impl FnOnce(S, ()) -> DeviceAction,
+    ) -> Self {
+        let door_lock_info_action = door_lock_info_action(self.device.state.clone(), ());
+
+        self.device = self.device.add_device_action(door_lock_info_action);
+
+        self
+    }
+
+    /// Converts a [`DoorLock`] into a [`Device`].
+    pub fn into_device(self) -> Device<S> {
+        self.device.add_mandatory_actions([
+            self.lock.device_action,
+            self.unlock.device_action,
+            self.status.device_action,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use ascot::hazards::Hazard;
+    use ascot::route::Route;
+
+    use axum::extract::State;
+
+    use crate::actions::error::ErrorResponse;
+    use crate::actions::ok::{
+        OkResponse, mandatory_ok_stateful, mandatory_ok_stateless, ok_stateless,
+    };
+    use crate::devices::door_lock::{DoorLockLockRoute, DoorLockStatusRoute, DoorLockUnlockRoute};
+
+    use super::DoorLock;
+
+    #[derive(Clone)]
+    struct DoorLockState;
+
+    async fn action(State(_state): State<DoorLockState>) -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    async fn action_stateless() -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    struct Routes {
+        lock: DoorLockLockRoute,
+        unlock: DoorLockUnlockRoute,
+        status: DoorLockStatusRoute,
+        log: Route,
+        picture: Route,
+    }
+
+    #[inline]
+    fn create_routes() -> Routes {
+        Routes {
+            lock: DoorLockLockRoute::put("Lock").description("Lock the door."),
+
+            unlock: DoorLockUnlockRoute::put("Unlock")
+                .description("Unlock the door.")
+                .with_hazard(Hazard::UnauthorisedPhysicalAccess),
+
+            status: DoorLockStatusRoute::get("Status").description("Door lock status."),
+
+            log: Route::put("Log", "/log")
+                .description("Log the issued commands.")
+                .with_hazard(Hazard::RecordIssuedCommands),
+
+            picture: Route::put("Picture", "/picture")
+                .description("Take a picture of the visitor.")
+                .with_hazard(Hazard::TakePictures),
+        }
+    }
+
+    #[test]
+    fn complete_with_state() {
+        let routes = create_routes();
+
+        DoorLock::with_state(DoorLockState {})
+            .lock(routes.lock, mandatory_ok_stateful(action))
+            .unwrap()
+            .unlock(routes.unlock, mandatory_ok_stateful(action))
+            .unwrap()
+            .status(routes.status, mandatory_ok_stateful(action))
+            .unwrap()
+            .add_action(ok_stateless(routes.log, action_stateless))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn complete_without_state() {
+        let routes = create_routes();
+
+        DoorLock::new()
+            .lock(routes.lock, mandatory_ok_stateless(action_stateless))
+            .unwrap()
+            .unlock(routes.unlock, mandatory_ok_stateless(action_stateless))
+            .unwrap()
+            .status(routes.status, mandatory_ok_stateless(action_stateless))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn unlock_without_hazard() {
+        let routes = create_routes();
+
+        assert!(
+            DoorLock::new()
+                .lock(routes.lock, mandatory_ok_stateless(action_stateless))
+                .unwrap()
+                .unlock(
+                    DoorLockUnlockRoute::put("Unlock"),
+                    mandatory_ok_stateless(action_stateless),
+                )
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn not_allowed_hazard() {
+        let routes = create_routes();
+
+        assert!(
+            DoorLock::new()
+                .lock(routes.lock, mandatory_ok_stateless(action_stateless))
+                .unwrap()
+                .unlock(routes.unlock, mandatory_ok_stateless(action_stateless))
+                .unwrap()
+                .status(routes.status, mandatory_ok_stateless(action_stateless))
+                .unwrap()
+                .add_action(ok_stateless(routes.picture, action_stateless))
+                .is_err()
+        );
+    }
+}
@@ -1,17 +1,15 @@
 use ascot::device::DeviceKind;
-use ascot::hazards::Hazard;
 use ascot::route::{LightOffRoute, LightOnRoute, Route};
 
 use crate::actions::{DeviceAction, MandatoryAction};
 use crate::device::Device;
-use crate::error::{Error, Result};
+use crate::error::Result;
+
+use super::{check_action_hazards, check_mandatory_action};
 
 // The default main route for a light.
 const LIGHT_MAIN_ROUTE: &str = "/light";
 
-// Allowed hazards.
-const ALLOWED_HAZARDS: &[Hazard] = &[Hazard::FireHazard, Hazard::ElectricEnergyConsumption];
-
 /// A smart home light.
 ///
 /// The default server main route for a light is `light`.
@@ -28,8 +26,6 @@ where
     turn_light_on: MandatoryAction<M1>,
     // Turn light off action.
     turn_light_off: MandatoryAction<M2>,
-    // Allowed light hazards.
-    allowed_hazards: &'static [Hazard],
 }
 
 impl Default for Light<false, false, ()> {
@@ -61,7 +57,6 @@ where
             device,
             turn_light_on: MandatoryAction::empty(),
             turn_light_off: MandatoryAction::empty(),
-            allowed_hazards: ALLOWED_HAZARDS,
         }
     }
 
@@ -69,19 +64,25 @@ where
     ///
     /// **This method is mandatory, if not called, a compilation
     /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the light
+    /// profile.
     pub fn turn_light_on(
         self,
         route: LightOnRoute,
         turn_light_on: impl FnOnce(Route, S) -> MandatoryAction<false>,
-    ) -> Light<true, false, S> {
+    ) -> Result<Light<true, false, S>> {
         let turn_light_on = turn_light_on(route.into_route(), self.device.state.clone());
 
-        Light {
+        check_mandatory_action(DeviceKind::Light, &turn_light_on.device_action)?;
+
+        Ok(Light {
             device: self.device,
             turn_light_on: MandatoryAction::init(turn_light_on.device_action),
             turn_light_off: self.turn_light_off,
-            allowed_hazards: ALLOWED_HAZARDS,
-        }
+        })
     }
 }
 
@@ -94,19 +95,25 @@ where
     ///
     /// **This method is mandatory, if not called, a compilation
     /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the light
+    /// profile.
     pub fn turn_light_off(
         self,
         route: LightOffRoute,
         turn_light_off: impl FnOnce(Route, S) -> MandatoryAction<false>,
-    ) -> Light<true, true, S> {
+    ) -> Result<Light<true, true, S>> {
         let turn_light_off = turn_light_off(route.into_route(), self.device.state.clone());
 
-        Light {
+        check_mandatory_action(DeviceKind::Light, &turn_light_off.device_action)?;
+
+        Ok(Light {
             device: self.device,
             turn_light_on: self.turn_light_on,
             turn_light_off: MandatoryAction::init(turn_light_off.device_action),
-            allowed_hazards: ALLOWED_HAZARDS,
-        }
+        })
     }
 }
 
@@ -131,15 +138,8 @@ where
     pub fn add_action(mut self, light_action: impl FnOnce(S) -> DeviceAction) -> Result<Self> {
         let light_action = light_action(self.device.state.clone());
 
-        // Return an error if action hazards are not a subset of allowed hazards.
-        for hazard in light_action.hazards() {
-            if !self.allowed_hazards.contains(hazard) {
-                return Err(Error::device(
-                    DeviceKind::Light,
-                    format!("{hazard} hazard is not allowed for light"),
-                ));
-            }
-        }
+        // Return an error if action hazards are not allowed.
+        check_action_hazards(DeviceKind::Light, &light_action)?;
 
         self.device = self.device.add_device_action(light_action);
 
@@ -285,7 +285,9 @@ mod tests {
 
         Light::with_state(LightState {})
             .turn_light_on(routes.light_on, mandatory_serial_stateful(turn_light_on))
+            .unwrap()
             .turn_light_off(routes.light_off, mandatory_ok_stateful(turn_light_off))
+            .unwrap()
             .add_action(serial_stateful(routes.light_on_post, turn_light_on))
             .unwrap()
             .add_action(ok_stateful(routes.toggle, toggle))
@@ -299,7 +301,9 @@ mod tests {
 
         Light::with_state(LightState {})
             .turn_light_on(routes.light_on, mandatory_serial_stateful(turn_light_on))
+            .unwrap()
             .turn_light_off(routes.light_off, mandatory_ok_stateful(turn_light_off))
+            .unwrap()
             .into_device();
     }
 
@@ -309,7 +313,9 @@ mod tests {
 
         Light::with_state(LightState {})
             .turn_light_on(routes.light_on, mandatory_serial_stateful(turn_light_on))
+            .unwrap()
             .turn_light_off(routes.light_off, mandatory_ok_stateful(turn_light_off))
+            .unwrap()
             .add_action(serial_stateful(routes.light_on_post, turn_light_on))
             .unwrap()
             .add_action(ok_stateless(routes.toggle, toggle_stateless))
@@ -326,10 +332,12 @@ mod tests {
                 routes.light_on,
                 mandatory_serial_stateless(turn_light_on_stateless),
             )
+            .unwrap()
             .turn_light_off(
                 routes.light_off,
                 mandatory_ok_stateless(turn_light_off_stateless),
             )
+            .unwrap()
             .add_action(serial_stateless(
                 routes.light_on_post,
                 turn_light_on_stateless,
@@ -349,10 +357,12 @@ mod tests {
                 routes.light_on,
                 mandatory_serial_stateless(turn_light_on_stateless),
             )
+            .unwrap()
             .turn_light_off(
                 routes.light_off,
                 mandatory_ok_stateless(turn_light_off_stateless),
             )
+            .unwrap()
             .into_device();
     }
 }
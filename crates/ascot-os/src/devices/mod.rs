@@ -1,2 +1,35 @@
+use ascot::device::DeviceKind;
+
+use crate::actions::DeviceAction;
+use crate::error::{Error, Result};
+
+/// A camera device.
+///
+/// A camera always exposes a live stream, hence it requires the `stream`
+/// feature.
+#[cfg(feature = "stream")]
+pub mod camera;
+/// A door lock device.
+pub mod door_lock;
 /// A light device.
 pub mod light;
+/// A smart plug device.
+pub mod smart_plug;
+/// A thermostat device.
+pub mod thermostat;
+
+// Checks whether a mandatory action complies with the profile of a device
+// kind.
+fn check_mandatory_action(kind: DeviceKind, action: &DeviceAction) -> Result<()> {
+    kind.profile()
+        .check_route(&action.route_config)
+        .map_err(|e| Error::device(kind, e.to_string()))
+}
+
+// Checks whether the hazards of an additional action are allowed by the
+// profile of a device kind.
+fn check_action_hazards(kind: DeviceKind, action: &DeviceAction) -> Result<()> {
+    kind.profile()
+        .check_route_hazards(&action.route_config.data)
+        .map_err(|e| Error::device(kind, e.to_string()))
+}
@@ -0,0 +1,351 @@
+use ascot::device::DeviceKind;
+use ascot::route::{Route, SmartPlugMeasurementRoute, SmartPlugOffRoute, SmartPlugOnRoute};
+
+use crate::actions::{DeviceAction, MandatoryAction};
+use crate::device::Device;
+use crate::error::Result;
+
+use super::{check_action_hazards, check_mandatory_action};
+
+// The default main route for a smart plug.
+const SMART_PLUG_MAIN_ROUTE: &str = "/smart-plug";
+
+/// A smart home plug with energy metering.
+///
+/// The default server main route for a smart plug is `smart-plug`.
+///
+/// If a smart home needs more smart plugs, each smart plug **MUST** provide a
+/// **different** main route in order to be registered.
+pub struct SmartPlug<const M1: bool, const M2: bool, const M3: bool, S = ()>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    // Internal device.
+    device: Device<S>,
+    // Turn plug on action.
+    turn_on: MandatoryAction<M1>,
+    // Turn plug off action.
+    turn_off: MandatoryAction<M2>,
+    // Power measurement action.
+    measurement: MandatoryAction<M3>,
+}
+
+impl Default for SmartPlug<false, false, false, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SmartPlug<false, false, false, ()> {
+    /// Creates a [`SmartPlug`] instance without a state.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_state(())
+    }
+}
+
+impl<S> SmartPlug<false, false, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Creates a [`SmartPlug`] instance with a state.
+    #[inline]
+    pub fn with_state(state: S) -> Self {
+        // Create a new device.
+        let device = Device::init(DeviceKind::SmartPlug, state).main_route(SMART_PLUG_MAIN_ROUTE);
+
+        Self {
+            device,
+            turn_on: MandatoryAction::empty(),
+            turn_off: MandatoryAction::empty(),
+            measurement: MandatoryAction::empty(),
+        }
+    }
+
+    /// Adds a turn on action for a [`SmartPlug`].
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the smart
+    /// plug profile.
+    pub fn turn_on(
+        self,
+        route: SmartPlugOnRoute,
+        turn_on: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<SmartPlug<true, false, false, S>> {
+        let turn_on = turn_on(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::SmartPlug, &turn_on.device_action)?;
+
+        Ok(SmartPlug {
+            device: self.device,
+            turn_on: MandatoryAction::init(turn_on.device_action),
+            turn_off: self.turn_off,
+            measurement: self.measurement,
+        })
+    }
+}
+
+impl<S> SmartPlug<true, false, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Adds a turn off action for a [`SmartPlug`].
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the smart
+    /// plug profile.
+    pub fn turn_off(
+        self,
+        route: SmartPlugOffRoute,
+        turn_off: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<SmartPlug<true, true, false, S>> {
+        let turn_off = turn_off(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::SmartPlug, &turn_off.device_action)?;
+
+        Ok(SmartPlug {
+            device: self.device,
+            turn_on: self.turn_on,
+            turn_off: MandatoryAction::init(turn_off.device_action),
+            measurement: self.measurement,
+        })
+    }
+}
+
+impl<S> SmartPlug<true, true, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Adds a power measurement action for a [`SmartPlug`].
+    ///
+    /// The action must return a serial response containing a
+    /// [`PowerMeasurement`](ascot::energy::PowerMeasurement), hence it has
+    /// to be created either through
+    /// [`mandatory_serial_stateful`](crate::actions::serial::mandatory_serial_stateful)
+    /// or through
+    /// [`mandatory_serial_stateless`](crate::actions::serial::mandatory_serial_stateless).
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the smart
+    /// plug profile, for example when it does not return a serial response.
+    pub fn measurement(
+        self,
+        route: SmartPlugMeasurementRoute,
+        measurement: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<SmartPlug<true, true, true, S>> {
+        let measurement = measurement(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::SmartPlug, &measurement.device_action)?;
+
+        Ok(SmartPlug {
+            device: self.device,
+            turn_on: self.turn_on,
+            turn_off: self.turn_off,
+            measurement: MandatoryAction::init(measurement.device_action),
+        })
+    }
+}
+
+impl<S> SmartPlug<true, true, true, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Sets a new main route.
+    #[must_use]
+    #[inline]
+    pub fn main_route(mut self, main_route: &'static str) -> Self {
+        self.device = self.device.main_route(main_route);
+        self
+    }
+
+    /// Adds an additional action for a [`SmartPlug`].
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether one or more hazards are not allowed for
+    /// the [`SmartPlug`] device.
+    pub fn add_action(mut self, plug_action: impl FnOnce(S) -> DeviceAction) -> Result<Self> {
+        let plug_action = plug_action(self.device.state.clone());
+
+        // Return an error if action hazards are not allowed.
+        check_action_hazards(DeviceKind::SmartPlug, &plug_action)?;
+
+        self.device = self.device.add_device_action(plug_action);
+
+        Ok(self)
+    }
+
+    /// Adds an informative action for [`SmartPlug`].
+    #[must_use]
+    pub fn add_info_action(mut self, plug_info_action: impl FnOnce(S, ()) -> DeviceAction) -> Self {
+        let plug_info_action = plug_info_action(self.device.state.clone(), ());
+
+        self.device = self.device.add_device_action(plug_info_action);
+
+        self
+    }
+
+    /// Converts a [`SmartPlug`] into a [`Device`].
+    pub fn into_device(self) -> Device<S> {
+        self.device.add_mandatory_actions([
+            self.turn_on.device_action,
+            self.turn_off.device_action,
+            self.measurement.device_action,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use ascot::energy::PowerMeasurement;
+    use ascot::hazards::Hazard;
+    use ascot::route::Route;
+
+    use axum::extract::State;
+
+    use crate::actions::error::ErrorResponse;
+    use crate::actions::ok::{
+        OkResponse, mandatory_ok_stateful, mandatory_ok_stateless, ok_stateless,
+    };
+    use crate::actions::serial::{
+        SerialResponse, mandatory_serial_stateful, mandatory_serial_stateless,
+    };
+    use crate::devices::smart_plug::{
+        SmartPlugMeasurementRoute, SmartPlugOffRoute, SmartPlugOnRoute,
+    };
+
+    use super::SmartPlug;
+
+    #[derive(Clone)]
+    struct PlugState;
+
+    async fn switch(State(_state): State<PlugState>) -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    async fn switch_stateless() -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    async fn measurement(
+        State(_state): State<PlugState>,
+    ) -> Result<SerialResponse<PowerMeasurement>, ErrorResponse> {
+        Ok(SerialResponse::new(PowerMeasurement::new(60., 230., 1.5)))
+    }
+
+    async fn measurement_stateless() -> Result<SerialResponse<PowerMeasurement>, ErrorResponse> {
+        Ok(SerialResponse::new(PowerMeasurement::new(60., 230., 1.5)))
+    }
+
+    struct Routes {
+        on: SmartPlugOnRoute,
+        off: SmartPlugOffRoute,
+        measurement: SmartPlugMeasurementRoute,
+        log: Route,
+        unlock: Route,
+    }
+
+    #[inline]
+    fn create_routes() -> Routes {
+        Routes {
+            on: SmartPlugOnRoute::put("On")
+                .description("Turn the plug on.")
+                .with_hazard(Hazard::ElectricEnergyConsumption),
+
+            off: SmartPlugOffRoute::put("Off").description("Turn the plug off."),
+
+            measurement: SmartPlugMeasurementRoute::get("Measurement")
+                .description("Measure the plug consumption."),
+
+            log: Route::put("Log", "/log")
+                .description("Log the energy consumption.")
+                .with_hazard(Hazard::LogEnergyConsumption),
+
+            unlock: Route::put("Unlock", "/unlock")
+                .description("Unlock a door.")
+                .with_hazard(Hazard::UnauthorisedPhysicalAccess),
+        }
+    }
+
+    #[test]
+    fn complete_with_state() {
+        let routes = create_routes();
+
+        SmartPlug::with_state(PlugState {})
+            .turn_on(routes.on, mandatory_ok_stateful(switch))
+            .unwrap()
+            .turn_off(routes.off, mandatory_ok_stateful(switch))
+            .unwrap()
+            .measurement(routes.measurement, mandatory_serial_stateful(measurement))
+            .unwrap()
+            .add_action(ok_stateless(routes.log, switch_stateless))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn complete_without_state() {
+        let routes = create_routes();
+
+        SmartPlug::new()
+            .turn_on(routes.on, mandatory_ok_stateless(switch_stateless))
+            .unwrap()
+            .turn_off(routes.off, mandatory_ok_stateless(switch_stateless))
+            .unwrap()
+            .measurement(
+                routes.measurement,
+                mandatory_serial_stateless(measurement_stateless),
+            )
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn not_allowed_hazard() {
+        let routes = create_routes();
+
+        assert!(
+            SmartPlug::new()
+                .turn_on(routes.on, mandatory_ok_stateless(switch_stateless))
+                .unwrap()
+                .turn_off(routes.off, mandatory_ok_stateless(switch_stateless))
+                .unwrap()
+                .measurement(
+                    routes.measurement,
+                    mandatory_serial_stateless(measurement_stateless),
+                )
+                .unwrap()
+                .add_action(ok_stateless(routes.unlock, switch_stateless))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn measurement_without_serial_response() {
+        let routes = create_routes();
+
+        assert!(
+            SmartPlug::new()
+                .turn_on(routes.on, mandatory_ok_stateless(switch_stateless))
+                .unwrap()
+                .turn_off(routes.off, mandatory_ok_stateless(switch_stateless))
+                .unwrap()
+                .measurement(routes.measurement, mandatory_ok_stateless(switch_stateless))
+                .is_err_and(|e| e.to_string().contains("must return a Serial response"))
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use ascot::device::DeviceKind;
+use ascot::route::{Route, ThermostatCurrentTemperatureRoute, ThermostatTargetTemperatureRoute};
+
+use crate::actions::{DeviceAction, MandatoryAction};
+use crate::device::Device;
+use crate::error::Result;
+
+use super::{check_action_hazards, check_mandatory_action};
+
+// The default main route for a thermostat.
+const THERMOSTAT_MAIN_ROUTE: &str = "/thermostat";
+
+/// A smart home thermostat.
+///
+/// The default server main route for a thermostat is `thermostat`.
+///
+/// If a smart home needs more thermostats, each thermostat **MUST** provide a
+/// **different** main route in order to be registered.
+pub struct Thermostat<const M1: bool, const M2: bool, S = ()>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    // Internal device.
+    device: Device<S>,
+    // Set target temperature action.
+    set_target_temperature: MandatoryAction<M1>,
+    // Get current temperature action.
+    current_temperature: MandatoryAction<M2>,
+}
+
+impl Default for Thermostat<false, false, ()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Thermostat<false, false, ()> {
+    /// Creates a [`Thermostat`] instance without a state.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_state(())
+    }
+}
+
+impl<S> Thermostat<false, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Creates a [`Thermostat`] instance with a state.
+    #[inline]
+    pub fn with_state(state: S) -> Self {
+        // Create a new device.
+        let device = Device::init(DeviceKind::Thermostat, state).main_route(THERMOSTAT_MAIN_ROUTE);
+
+        Self {
+            device,
+            set_target_temperature: MandatoryAction::empty(),
+            current_temperature: MandatoryAction::empty(),
+        }
+    }
+
+    /// Adds a set target temperature action for a [`Thermostat`].
+    ///
+    /// The action route must declare a `temperature` input parameter.
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the
+    /// thermostat profile, for example when it does not declare a `temperature`
+    /// input parameter.
+    pub fn set_target_temperature(
+        self,
+        route: ThermostatTargetTemperatureRoute,
+        set_target_temperature: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<Thermostat<true, false, S>> {
+        let set_target_temperature =
+            set_target_temperature(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(
+            DeviceKind::Thermostat,
+            &set_target_temperature.device_action,
+        )?;
+
+        Ok(Thermostat {
+            device: self.device,
+            set_target_temperature: MandatoryAction::init(set_target_temperature.device_action),
+            current_temperature: self.current_temperature,
+        })
+    }
+}
+
+impl<S> Thermostat<true, false, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Adds a get current temperature action for a [`Thermostat`].
+    ///
+    /// **This method is mandatory, if not called, a compilation
+    /// error is raised.**.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether the action does not comply with the
+    /// thermostat profile.
+    pub fn current_temperature(
+        self,
+        route: ThermostatCurrentTemperatureRoute,
+        current_temperature: impl FnOnce(Route, S) -> MandatoryAction<false>,
+    ) -> Result<Thermostat<true, true, S>> {
+        let current_temperature =
+            current_temperature(route.into_route(), self.device.state.clone());
+
+        check_mandatory_action(DeviceKind::Thermostat, &current_temperature.device_action)?;
+
+        Ok(Thermostat {
+            device: self.device,
+            set_target_temperature: self.set_target_temperature,
+            current_temperature: MandatoryAction::init(current_temperature.device_action),
+        })
+    }
+}
+
+impl<S> Thermostat<true, true, S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Sets a new main route.
+    #[must_use]
+    #[inline]
+    pub fn main_route(mut self, main_route: &'static str) -> Self {
+        self.device = self.device.main_route(main_route);
+        self
+    }
+
+    /// Adds an additional action for a [`Thermostat`].
+    ///
+    /// # Errors
+    ///
+    /// It returns an error whether one or more hazards are not allowed for
+    /// the [`Thermostat`] device.
+    pub fn add_action(mut self, thermostat_action: impl FnOnce(S) -> DeviceAction) -> Result<Self> {
+        let thermostat_action = thermostat_action(self.device.state.clone());
+
+        // Return an error if action hazards are not allowed.
+        check_action_hazards(DeviceKind::Thermostat, &thermostat_action)?;
+
+        self.device = self.device.add_device_action(thermostat_action);
+
+        Ok(self)
+    }
+
+    /// Adds an informative action for [`Thermostat`].
+    #[must_use]
+    pub fn add_info_action(
+        mut self,
+        thermostat_info_action: impl FnOnce(S, ()) -> DeviceAction,
+    ) -> Self {
+        let thermostat_info_action = thermostat_info_action(self.device.state.clone(), ());
+
+        self.device = self.device.add_device_action(thermostat_info_action);
+
+        self
+    }
+
+    /// Converts a [`Thermostat`] into a [`Device`].
+    pub fn into_device(self) -> Device<S> {
+        self.device.add_mandatory_actions([
+            self.set_target_temperature.device_action,
+            self.current_temperature.device_action,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use ascot::hazards::{Hazard, Hazards};
+    use ascot::parameters::Parameters;
+    use ascot::route::Route;
+
+    use axum::extract::{Json, State};
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::actions::error::ErrorResponse;
+    use crate::actions::ok::{OkResponse, ok_stateful, ok_stateless};
+    use crate::actions::serial::{
+        SerialResponse, mandatory_serial_stateful, mandatory_serial_stateless,
+    };
+    use crate::devices::thermostat::{
+        ThermostatCurrentTemperatureRoute, ThermostatTargetTemperatureRoute,
+    };
+
+    use super::Thermostat;
+
+    #[derive(Clone)]
+    struct ThermostatState;
+
+    #[derive(Deserialize)]
+    struct Inputs {
+        temperature: f64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct TemperatureResponse {
+        temperature: f64,
+    }
+
+    async fn set_target_temperature(
+        State(_state): State<ThermostatState>,
+        Json(inputs): Json<Inputs>,
+    ) -> Result<SerialResponse<TemperatureResponse>, ErrorResponse> {
+        Ok(SerialResponse::new(TemperatureResponse {
+            temperature: inputs.temperature,
+        }))
+    }
+
+    async fn set_target_temperature_stateless(
+        Json(inputs): Json<Inputs>,
+    ) -> Result<SerialResponse<TemperatureResponse>, ErrorResponse> {
+        Ok(SerialResponse::new(TemperatureResponse {
+            temperature: inputs.temperature,
+        }))
+    }
+
+    async fn current_temperature(
+        State(_state): State<ThermostatState>,
+    ) -> Result<SerialResponse<TemperatureResponse>, ErrorResponse> {
+        Ok(SerialResponse::new(TemperatureResponse {
+            temperature: 20.,
+        }))
+    }
+
+    async fn current_temperature_stateless()
+    -> Result<SerialResponse<TemperatureResponse>, ErrorResponse> {
+        Ok(SerialResponse::new(TemperatureResponse {
+            temperature: 20.,
+        }))
+    }
+
+    async fn boost(State(_state): State<ThermostatState>) -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    async fn boost_stateless() -> Result<OkResponse, ErrorResponse> {
+        Ok(OkResponse::ok())
+    }
+
+    struct Routes {
+        target_temperature: ThermostatTargetTemperatureRoute,
+        current_temperature: ThermostatCurrentTemperatureRoute,
+        boost: Route,
+        unlock: Route,
+    }
+
+    #[inline]
+    fn create_routes() -> Routes {
+        Routes {
+            target_temperature: ThermostatTargetTemperatureRoute::put("Target temperature")
+                .description("Set the target temperature.")
+                .with_hazard(Hazard::GasConsumption)
+                .with_parameters(Parameters::new().rangef64("temperature", (5., 30., 0.5))),
+
+            current_temperature: ThermostatCurrentTemperatureRoute::get("Current temperature")
+                .description("Get the current temperature."),
+
+            boost: Route::put("Boost", "/boost")
+                .description("Heat at full power for a while.")
+                .with_hazards(
+                    Hazards::init(Hazard::FireHazard).insert(Hazard::ElectricEnergyConsumption),
+                ),
+
+            unlock: Route::put("Unlock", "/unlock")
+                .description("Unlock the heating controller.")
+                .with_hazard(Hazard::UnauthorisedPhysicalAccess),
+        }
+    }
+
+    #[test]
+    fn complete_with_state() {
+        let routes = create_routes();
+
+        Thermostat::with_state(ThermostatState {})
+            .set_target_temperature(
+                routes.target_temperature,
+                mandatory_serial_stateful(set_target_temperature),
+            )
+            .unwrap()
+            .current_temperature(
+                routes.current_temperature,
+                mandatory_serial_stateful(current_temperature),
+            )
+            .unwrap()
+            .add_action(ok_stateful(routes.boost, boost))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn complete_without_state() {
+        let routes = create_routes();
+
+        Thermostat::new()
+            .set_target_temperature(
+                routes.target_temperature,
+                mandatory_serial_stateless(set_target_temperature_stateless),
+            )
+            .unwrap()
+            .current_temperature(
+                routes.current_temperature,
+                mandatory_serial_stateless(current_temperature_stateless),
+            )
+            .unwrap()
+            .add_action(ok_stateless(routes.boost, boost_stateless))
+            .unwrap()
+            .into_device();
+    }
+
+    #[test]
+    fn target_temperature_without_parameter() {
+        assert!(
+            Thermostat::new()
+                .set_target_temperature(
+                    ThermostatTargetTemperatureRoute::put("Target temperature")
+                        .with_hazard(Hazard::GasConsumption),
+                    mandatory_serial_stateless(set_target_temperature_stateless),
+                )
+                .is_err_and(|e| e.to_string().contains("`temperature` parameter"))
+        );
+    }
+
+    #[test]
+    fn not_allowed_hazard() {
+        let routes = create_routes();
+
+        assert!(
+            Thermostat::new()
+                .set_target_temperature(
+                    routes.target_temperature,
+                    mandatory_serial_stateless(set_target_temperature_stateless),
+                )
+                .unwrap()
+                .current_temperature(
+                    routes.current_temperature,
+                    mandatory_serial_stateless(current_temperature_stateless),
+                )
+                .unwrap()
+                .add_action(ok_stateless(routes.unlock, boost_stateless))
+                .is_err()
+        );
+    }
+}
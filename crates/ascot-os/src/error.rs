@@ -13,6 +13,8 @@ pub enum ErrorKind {
     Serialization,
     /// A device error.
     Device,
+    /// Device identifier error.
+    Identifier,
     /// External error.
     ///
     /// An error caused by an external dependency.
@@ -26,6 +28,7 @@ impl ErrorKind {
             Self::NotFoundAddress => "Not Found Address",
             Self::Serialization => "Serialization",
             Self::Device => "Device",
+            Self::Identifier => "Identifier",
             Self::External => "External",
         }
     }
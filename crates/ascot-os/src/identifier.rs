@@ -0,0 +1,138 @@
+use std::fs;
+use std::io::ErrorKind as IoErrorKind;
+use std::path::Path;
+
+use ascot::device::{DeviceKind, Uuid};
+
+use tracing::info;
+
+use crate::error::{Error, ErrorKind, Result};
+
+// Extension of the files containing the device identifiers.
+const IDENTIFIER_EXTENSION: &str = "uuid";
+
+// Returns the name identifying a device within the state directory.
+//
+// Devices of the same kind sharing the same service are told apart by their
+// main route, which must be unique within a smart home. The name contains
+// only lowercase alphanumeric characters separated by hyphens, so it is a
+// valid file name on every platform.
+pub(crate) fn device_name(service: &str, kind: DeviceKind, main_route: &str) -> String {
+    let name = format!("{service} {kind} {main_route}");
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// Loads the identifier saved in the state directory for the device with the
+// given name.
+//
+// If no identifier exists, a new one is generated and saved, so that a
+// device keeps the same identifier across restarts.
+pub(crate) fn load_or_create_uuid(state_directory: &Path, name: &str) -> Result<Uuid> {
+    let path = state_directory.join(format!("{name}.{IDENTIFIER_EXTENSION}"));
+
+    match fs::read_to_string(&path) {
+        Ok(content) => Uuid::parse_str(content.trim()).map_err(|e| {
+            Error::new(
+                ErrorKind::Identifier,
+                format!("Invalid identifier in `{}`: {e}", path.display()),
+            )
+        }),
+        Err(e) if e.kind() == IoErrorKind::NotFound => {
+            let uuid = Uuid::new_v4();
+
+            fs::create_dir_all(state_directory)
+                .and_then(|()| fs::write(&path, uuid.to_string()))
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Identifier,
+                        format!(
+                            "Impossible to save the identifier in `{}`: {e}",
+                            path.display()
+                        ),
+                    )
+                })?;
+
+            info!("Generated device identifier {uuid} in `{}`", path.display());
+
+            Ok(uuid)
+        }
+        Err(e) => Err(Error::new(
+            ErrorKind::Identifier,
+            format!(
+                "Impossible to read the identifier in `{}`: {e}",
+                path.display()
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use ascot::device::{DeviceKind, Uuid};
+
+    use super::{device_name, load_or_create_uuid};
+
+    fn state_directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ascot-{name}-{}", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn persistent_uuid() {
+        let state_directory = state_directory("persistent");
+
+        let uuid = load_or_create_uuid(&state_directory, "light").unwrap();
+
+        // The same identifier is loaded again.
+        assert_eq!(
+            load_or_create_uuid(&state_directory, "light").unwrap(),
+            uuid
+        );
+
+        // A different device obtains a different identifier.
+        assert_ne!(
+            load_or_create_uuid(&state_directory, "camera").unwrap(),
+            uuid
+        );
+
+        fs::remove_dir_all(state_directory).unwrap();
+    }
+
+    #[test]
+    fn device_names() {
+        assert_eq!(
+            device_name("ascot", DeviceKind::Light, "/light"),
+            "ascot-light-light"
+        );
+
+        // Devices of the same kind with different main routes.
+        assert_ne!(
+            device_name("ascot", DeviceKind::DoorLock, "/door-lock/front"),
+            device_name("ascot", DeviceKind::DoorLock, "/door-lock/back")
+        );
+
+        // Devices of different kinds with the same main route.
+        assert_ne!(
+            device_name("ascot", DeviceKind::Light, "/main"),
+            device_name("ascot", DeviceKind::Camera, "/main")
+        );
+    }
+
+    #[test]
+    fn invalid_uuid() {
+        let state_directory = state_directory("invalid");
+
+        fs::create_dir_all(&state_directory).unwrap();
+        fs::write(state_directory.join("light.uuid"), "not-an-identifier").unwrap();
+
+        assert!(load_or_create_uuid(&state_directory, "light").is_err());
+
+        fs::remove_dir_all(state_directory).unwrap();
+    }
+}
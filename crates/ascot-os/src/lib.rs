@@ -61,4 +61,5 @@ pub mod extract {
 
 mod services;
 
+mod identifier;
 mod mac;
@@ -1,5 +1,8 @@
 use std::future::Future;
 use std::net::Ipv4Addr;
+use std::path::PathBuf;
+
+use ascot::protocol::{PROTOCOL_VERSION, PROTOCOL_VERSION_PROPERTY, UUID_PROPERTY};
 
 use axum::{Router, response::Redirect};
 
@@ -7,6 +10,7 @@ use tracing::info;
 
 use crate::device::Device;
 use crate::error::Result;
+use crate::identifier::{device_name, load_or_create_uuid};
 use crate::service::{Service, ServiceConfig};
 
 // Default HTTP address.
@@ -28,6 +32,9 @@ const DEFAULT_SCHEME: &str = "http";
 // at URLs consistent well-known locations across servers.
 const DEFAULT_WELL_KNOWN_SERVICE: &str = "ascot";
 
+// Default directory where the server state is persisted.
+const DEFAULT_STATE_DIRECTORY: &str = ".ascot";
+
 #[derive(Debug)]
 struct ServerData<'a, S>
 where
@@ -43,6 +50,8 @@ where
     well_known_service: &'a str,
     // Service configurator.
     service_config: Option<ServiceConfig<'a>>,
+    // State directory.
+    state_directory: Option<PathBuf>,
     // Device.
     device: Device<S>,
 }
@@ -69,6 +78,7 @@ where
                 scheme: DEFAULT_SCHEME,
                 well_known_service: DEFAULT_WELL_KNOWN_SERVICE,
                 service_config: None,
+                state_directory: None,
                 device,
             },
         }
@@ -110,6 +120,21 @@ where
         self
     }
 
+    /// Sets the directory where the server persists its state.
+    ///
+    /// The device identifier is saved in this directory, so that a device
+    /// keeps the same identifier across restarts. Each device is saved in a
+    /// different file, named after its service, kind and main route.
+    ///
+    /// The default directory is `.ascot`, relative to the current working
+    /// directory.
+    #[must_use]
+    #[inline]
+    pub fn state_directory(mut self, state_directory: impl Into<PathBuf>) -> Self {
+        self.data.state_directory = Some(state_directory.into());
+        self
+    }
+
     /// Enables a server with a graceful shutdown operation being performed
     /// by the [`Future`] passed as input.
     #[must_use]
@@ -163,7 +188,31 @@ where
         let listener_bind = format!("{}:{}", self.data.http_address, self.data.port);
 
         // Consume a device returning all server information.
-        let (device_main_route, device_info, device_router) = self.data.device.finalize();
+        let (device_main_route, mut device_info, device_router) = self.data.device.finalize()?;
+
+        // Identify a device through a persistent identifier, unless one has
+        // been explicitly set.
+        let uuid = if let Some(uuid) = device_info.identity.uuid {
+            uuid
+        } else {
+            let state_directory = self
+                .data
+                .state_directory
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_STATE_DIRECTORY));
+            let name = device_name(
+                self.data.well_known_service,
+                device_info.kind,
+                device_main_route,
+            );
+            let uuid = load_or_create_uuid(&state_directory, &name)?;
+            device_info.identity.uuid = Some(uuid);
+            uuid
+        };
+        info!("Device identifier: {uuid}");
+
+        // Describe device routes through an `OpenAPI` document.
+        #[cfg(feature = "openapi")]
+        let openapi_document = ascot::openapi::openapi_document(&device_info)?;
 
         // Serialize device information returning a json format.
         let device_info = serde_json::to_value(device_info)?;
@@ -179,7 +228,9 @@ where
             // Add server properties.
             let service_config = service_config
                 .property(("scheme", self.data.scheme))
-                .property(("path", well_known_uri.to_string()));
+                .property(("path", well_known_uri.clone()))
+                .property((PROTOCOL_VERSION_PROPERTY, PROTOCOL_VERSION.to_string()))
+                .property((UUID_PROPERTY, uuid.to_string()));
 
             // Run service.
             Service::run(service_config, self.data.http_address, self.data.port)?;
@@ -201,6 +252,17 @@ where
             )
             .nest(device_main_route, device_router);
 
+        // Serve the `OpenAPI` document under the well-known URI.
+        #[cfg(feature = "openapi")]
+        let router = {
+            let openapi_uri = format!("{well_known_uri}/openapi.json");
+            info!("Server route: [GET, \"{}\"]", openapi_uri);
+            router.route(
+                &openapi_uri,
+                axum::routing::get(move || async { axum::Json(openapi_document) }),
+            )
+        };
+
         // Print server Ip and port.
         info!("Device reachable at this HTTP address: {listener_bind}");
 
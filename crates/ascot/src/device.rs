@@ -1,8 +1,21 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
 use serde::{Deserialize, Serialize};
 
+pub use uuid::Uuid;
+
 use crate::economy::Economy;
 use crate::energy::Energy;
-use crate::route::RouteConfigs;
+use crate::hazards::{ALL_HAZARDS, CustomHazard, CustomHazardData, Hazard, Hazards};
+use crate::protocol::{PROTOCOL_VERSION, ProtocolVersion};
+use crate::response::ResponseKind;
+use crate::route::{
+    CameraSnapshotRoute, CameraStreamRoute, DoorLockLockRoute, DoorLockStatusRoute,
+    DoorLockUnlockRoute, LightOffRoute, LightOnRoute, RestKind, RouteConfig, RouteConfigs,
+    RouteData, SmartPlugMeasurementRoute, SmartPlugOffRoute, SmartPlugOnRoute,
+    ThermostatCurrentTemperatureRoute, ThermostatTargetTemperatureRoute,
+};
 
 /// A device kind.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -13,6 +26,12 @@ pub enum DeviceKind {
     Light,
     /// Camera.
     Camera,
+    /// Thermostat.
+    Thermostat,
+    /// Smart plug.
+    SmartPlug,
+    /// Door lock.
+    DoorLock,
 }
 
 impl DeviceKind {
@@ -21,8 +40,36 @@ impl DeviceKind {
             Self::Unknown => "Unknown",
             Self::Light => "Light",
             Self::Camera => "Camera",
+            Self::Thermostat => "Thermostat",
+            Self::SmartPlug => "Smart Plug",
+            Self::DoorLock => "Door Lock",
+        }
+    }
+
+    /// Returns the [`DeviceProfile`] of a [`DeviceKind`].
+    #[must_use]
+    pub const fn profile(self) -> &'static DeviceProfile {
+        match self {
+            Self::Unknown => &UNKNOWN_PROFILE,
+            Self::Light => &LIGHT_PROFILE,
+            Self::Camera => &CAMERA_PROFILE,
+            Self::Thermostat => &THERMOSTAT_PROFILE,
+            Self::SmartPlug => &SMART_PLUG_PROFILE,
+            Self::DoorLock => &DOOR_LOCK_PROFILE,
         }
     }
+
+    /// Checks whether the route with the given path requires an explicit
+    /// confirmation before being invoked on a [`DeviceKind`].
+    ///
+    /// Safety-critical routes, such as the one to unlock a door, must never
+    /// be invoked inadvertently.
+    #[must_use]
+    pub fn requires_confirmation(self, route: &str) -> bool {
+        self.profile()
+            .mandatory_route(route)
+            .is_some_and(|mandatory_route| mandatory_route.confirmation)
+    }
 }
 
 impl core::fmt::Display for DeviceKind {
@@ -31,6 +78,398 @@ impl core::fmt::Display for DeviceKind {
     }
 }
 
+/// A mandatory route of a [`DeviceProfile`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MandatoryRoute {
+    /// Route path.
+    pub path: &'static str,
+    /// Allowed `REST` methods.
+    pub methods: &'static [RestKind],
+    /// Expected response kind.
+    ///
+    /// If [`None`], any response kind is accepted.
+    pub response_kind: Option<ResponseKind>,
+    /// Names of the input parameters a route must declare.
+    pub parameters: &'static [&'static str],
+    /// Hazards a route must always declare.
+    pub required_hazards: &'static [Hazard],
+    /// Whether a route must be explicitly confirmed before being invoked.
+    pub confirmation: bool,
+}
+
+impl MandatoryRoute {
+    /// Creates a [`MandatoryRoute`] with the given path and `REST` methods.
+    #[must_use]
+    pub const fn new(path: &'static str, methods: &'static [RestKind]) -> Self {
+        Self {
+            path,
+            methods,
+            response_kind: None,
+            parameters: &[],
+            required_hazards: &[],
+            confirmation: false,
+        }
+    }
+
+    /// Sets the expected [`ResponseKind`].
+    #[must_use]
+    pub const fn response_kind(mut self, response_kind: ResponseKind) -> Self {
+        self.response_kind = Some(response_kind);
+        self
+    }
+
+    /// Sets the names of the input parameters a route must declare.
+    #[must_use]
+    pub const fn parameters(mut self, parameters: &'static [&'static str]) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Sets the [`Hazard`]s a route must always declare.
+    #[must_use]
+    pub const fn required_hazards(mut self, required_hazards: &'static [Hazard]) -> Self {
+        self.required_hazards = required_hazards;
+        self
+    }
+
+    /// Requires an explicit confirmation before invoking a route.
+    #[must_use]
+    pub const fn confirmation(mut self) -> Self {
+        self.confirmation = true;
+        self
+    }
+
+    fn check(&self, route: &RouteConfig) -> Result<(), ProfileError> {
+        if !self.methods.contains(&route.rest_kind) {
+            return Err(ProfileError::WrongMethod {
+                path: self.path,
+                method: route.rest_kind,
+            });
+        }
+
+        if let Some(expected) = self.response_kind
+            && route.response_kind != expected
+        {
+            return Err(ProfileError::WrongResponseKind {
+                path: self.path,
+                expected,
+            });
+        }
+
+        if let Some(parameter) = self
+            .parameters
+            .iter()
+            .find(|parameter| route.data.parameters.get(**parameter).is_none())
+        {
+            return Err(ProfileError::MissingParameter {
+                path: self.path,
+                parameter,
+            });
+        }
+
+        if let Some(hazard) = self
+            .required_hazards
+            .iter()
+            .find(|hazard| !route.data.hazards.contains(hazard))
+        {
+            return Err(ProfileError::MissingHazard {
+                path: self.path,
+                hazard: *hazard,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// An error raised when a device does not comply with its [`DeviceProfile`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProfileError {
+    /// A mandatory route is missing.
+    MissingRoute {
+        /// Mandatory route path.
+        path: &'static str,
+    },
+    /// A mandatory route uses a `REST` method which is not allowed.
+    WrongMethod {
+        /// Mandatory route path.
+        path: &'static str,
+        /// Used `REST` method.
+        method: RestKind,
+    },
+    /// A mandatory route returns an unexpected response kind.
+    WrongResponseKind {
+        /// Mandatory route path.
+        path: &'static str,
+        /// Expected response kind.
+        expected: ResponseKind,
+    },
+    /// A mandatory route does not declare a required input parameter.
+    MissingParameter {
+        /// Mandatory route path.
+        path: &'static str,
+        /// Parameter name.
+        parameter: &'static str,
+    },
+    /// A mandatory route does not declare a required hazard.
+    MissingHazard {
+        /// Mandatory route path.
+        path: &'static str,
+        /// Missing hazard.
+        hazard: Hazard,
+    },
+    /// A route declares a hazard which is not allowed for a device kind.
+    HazardNotAllowed {
+        /// Device kind.
+        kind: DeviceKind,
+        /// Not allowed hazard.
+        hazard: Hazard,
+    },
+}
+
+impl core::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingRoute { path } => write!(f, "the mandatory `{path}` route is missing"),
+            Self::WrongMethod { path, method } => {
+                write!(f, "the `{path}` route cannot use the {method} method")
+            }
+            Self::WrongResponseKind { path, expected } => {
+                write!(f, "the `{path}` route must return a {expected} response")
+            }
+            Self::MissingParameter { path, parameter } => {
+                write!(
+                    f,
+                    "the `{path}` route must declare the `{parameter}` parameter"
+                )
+            }
+            Self::MissingHazard { path, hazard } => {
+                write!(f, "the `{path}` route must declare the {hazard} hazard")
+            }
+            Self::HazardNotAllowed { kind, hazard } => {
+                write!(f, "{hazard} hazard is not allowed for {kind}")
+            }
+        }
+    }
+}
+
+/// The profile of a [`DeviceKind`].
+///
+/// It describes the routes a device of a determined kind must always provide
+/// and the hazards its additional routes are allowed to declare.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceProfile {
+    /// Device kind.
+    pub kind: DeviceKind,
+    /// Mandatory routes.
+    pub mandatory_routes: &'static [MandatoryRoute],
+    /// Hazards allowed for the additional routes.
+    pub allowed_hazards: &'static [Hazard],
+}
+
+impl DeviceProfile {
+    /// Returns the [`MandatoryRoute`] with the given path.
+    ///
+    /// If [`None`], the route is not mandatory.
+    #[must_use]
+    pub fn mandatory_route(&self, path: &str) -> Option<&MandatoryRoute> {
+        self.mandatory_routes
+            .iter()
+            .find(|mandatory_route| mandatory_route.path == path)
+    }
+
+    /// Checks whether all the given [`Hazards`] are allowed.
+    ///
+    /// A [`CustomHazard`] is unknown to a profile, so it is allowed only
+    /// when its [`Category`](crate::hazards::Category) is the category of
+    /// one of the allowed hazards.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error for the first hazard which is not allowed.
+    pub fn check_hazards(&self, hazards: &Hazards) -> Result<(), ProfileError> {
+        match hazards
+            .iter()
+            .find(|hazard| !self.is_hazard_allowed(**hazard))
+        {
+            Some(hazard) => Err(ProfileError::HazardNotAllowed {
+                kind: self.kind,
+                hazard: *hazard,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn is_hazard_allowed(&self, hazard: Hazard) -> bool {
+        if hazard.is_custom() {
+            self.allowed_hazards
+                .iter()
+                .any(|allowed| allowed.category() == hazard.category())
+        } else {
+            self.allowed_hazards.contains(&hazard)
+        }
+    }
+
+    /// Checks whether a [`RouteConfig`] complies with a [`DeviceProfile`].
+    ///
+    /// A mandatory route is checked against its [`MandatoryRoute`]
+    /// description, while the hazards of an additional route, including its
+    /// conditional hazards, must be allowed.
+    /// Informative routes are not checked.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error when the route does not comply with the profile.
+    pub fn check_route(&self, route: &RouteConfig) -> Result<(), ProfileError> {
+        match self.mandatory_route(&route.data.path) {
+            Some(mandatory_route) => mandatory_route.check(route),
+            None if route.response_kind == ResponseKind::Info => Ok(()),
+            None => self.check_route_hazards(&route.data),
+        }
+    }
+
+    /// Checks whether all the hazards of a route, including its conditional
+    /// hazards, are allowed.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error for the first hazard which is not allowed.
+    pub fn check_route_hazards(&self, route: &RouteData) -> Result<(), ProfileError> {
+        self.check_hazards(&route.hazards)?;
+
+        let mut conditional_hazards = Hazards::new();
+        for conditional_hazard in &route.conditional_hazards {
+            conditional_hazards.add(conditional_hazard.hazard);
+        }
+        self.check_hazards(&conditional_hazards)
+    }
+
+    /// Checks whether all [`RouteConfigs`] of a device comply with a
+    /// [`DeviceProfile`], including the presence of all mandatory routes.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error for the first violation found.
+    pub fn check_route_configs(&self, route_configs: &RouteConfigs) -> Result<(), ProfileError> {
+        if let Some(mandatory_route) = self.mandatory_routes.iter().find(|mandatory_route| {
+            !route_configs
+                .iter()
+                .any(|route| route.data.path == mandatory_route.path)
+        }) {
+            return Err(ProfileError::MissingRoute {
+                path: mandatory_route.path,
+            });
+        }
+
+        route_configs
+            .iter()
+            .try_for_each(|route| self.check_route(route))
+    }
+}
+
+// The response kind of a stream route.
+//
+// Without the `stream` feature, any response kind is accepted.
+const fn stream_route(route: MandatoryRoute) -> MandatoryRoute {
+    #[cfg(feature = "stream")]
+    let route = route.response_kind(ResponseKind::Stream);
+    route
+}
+
+const UNKNOWN_PROFILE: DeviceProfile = DeviceProfile {
+    kind: DeviceKind::Unknown,
+    mandatory_routes: &[],
+    allowed_hazards: ALL_HAZARDS,
+};
+
+const LIGHT_PROFILE: DeviceProfile = DeviceProfile {
+    kind: DeviceKind::Light,
+    mandatory_routes: &[
+        MandatoryRoute::new(LightOnRoute::PATH, LightOnRoute::METHODS),
+        MandatoryRoute::new(LightOffRoute::PATH, LightOffRoute::METHODS),
+    ],
+    allowed_hazards: &[Hazard::FireHazard, Hazard::ElectricEnergyConsumption],
+};
+
+const CAMERA_PROFILE: DeviceProfile = DeviceProfile {
+    kind: DeviceKind::Camera,
+    mandatory_routes: &[
+        stream_route(MandatoryRoute::new(
+            CameraStreamRoute::PATH,
+            CameraStreamRoute::METHODS,
+        )),
+        MandatoryRoute::new(CameraSnapshotRoute::PATH, CameraSnapshotRoute::METHODS),
+    ],
+    allowed_hazards: &[
+        Hazard::AudioVideoDisplay,
+        Hazard::AudioVideoRecordAndStore,
+        Hazard::ElectricEnergyConsumption,
+        Hazard::TakeDeviceScreenshots,
+        Hazard::TakePictures,
+        Hazard::VideoDisplay,
+        Hazard::VideoRecordAndStore,
+    ],
+};
+
+const THERMOSTAT_PROFILE: DeviceProfile = DeviceProfile {
+    kind: DeviceKind::Thermostat,
+    mandatory_routes: &[
+        MandatoryRoute::new(
+            ThermostatTargetTemperatureRoute::PATH,
+            ThermostatTargetTemperatureRoute::METHODS,
+        )
+        .parameters(&["temperature"]),
+        MandatoryRoute::new(
+            ThermostatCurrentTemperatureRoute::PATH,
+            ThermostatCurrentTemperatureRoute::METHODS,
+        ),
+    ],
+    allowed_hazards: &[
+        Hazard::FireHazard,
+        Hazard::ElectricEnergyConsumption,
+        Hazard::GasConsumption,
+        Hazard::LogUsageTime,
+    ],
+};
+
+const SMART_PLUG_PROFILE: DeviceProfile = DeviceProfile {
+    kind: DeviceKind::SmartPlug,
+    mandatory_routes: &[
+        MandatoryRoute::new(SmartPlugOnRoute::PATH, SmartPlugOnRoute::METHODS),
+        MandatoryRoute::new(SmartPlugOffRoute::PATH, SmartPlugOffRoute::METHODS),
+        MandatoryRoute::new(
+            SmartPlugMeasurementRoute::PATH,
+            SmartPlugMeasurementRoute::METHODS,
+        )
+        .response_kind(ResponseKind::Serial),
+    ],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::FireHazard,
+        Hazard::LogEnergyConsumption,
+        Hazard::LogUsageTime,
+        Hazard::PowerOutage,
+        Hazard::PowerSurge,
+    ],
+};
+
+const DOOR_LOCK_PROFILE: DeviceProfile = DeviceProfile {
+    kind: DeviceKind::DoorLock,
+    mandatory_routes: &[
+        MandatoryRoute::new(DoorLockLockRoute::PATH, DoorLockLockRoute::METHODS),
+        MandatoryRoute::new(DoorLockUnlockRoute::PATH, DoorLockUnlockRoute::METHODS)
+            .required_hazards(&[Hazard::UnauthorisedPhysicalAccess])
+            .confirmation(),
+        MandatoryRoute::new(DoorLockStatusRoute::PATH, DoorLockStatusRoute::METHODS),
+    ],
+    allowed_hazards: &[
+        Hazard::ElectricEnergyConsumption,
+        Hazard::LogUsageTime,
+        Hazard::RecordIssuedCommands,
+        Hazard::UnauthorisedPhysicalAccess,
+    ],
+};
+
 /// Device environment.
 ///
 /// Some information about the device environment on which a firmware runs on.
@@ -85,20 +524,134 @@ impl DeviceInfo {
     }
 }
 
+/// Device identity.
+///
+/// All information which identifies what a device actually is.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DeviceIdentity {
+    /// Manufacturer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub manufacturer: Option<Cow<'static, str>>,
+    /// Model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub model: Option<Cow<'static, str>>,
+    /// Hardware revision.
+    #[serde(rename = "hardware revision")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub hardware_revision: Option<Cow<'static, str>>,
+    /// Firmware version.
+    #[serde(rename = "firmware version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub firmware_version: Option<Cow<'static, str>>,
+    /// Serial number.
+    #[serde(rename = "serial number")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub serial_number: Option<Cow<'static, str>>,
+    /// Stable unique identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub uuid: Option<Uuid>,
+}
+
+impl DeviceIdentity {
+    /// Creates an empty [`DeviceIdentity`].
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            manufacturer: None,
+            model: None,
+            hardware_revision: None,
+            firmware_version: None,
+            serial_number: None,
+            uuid: None,
+        }
+    }
+
+    /// Checks whether [`DeviceIdentity`] is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.manufacturer.is_none()
+            && self.model.is_none()
+            && self.hardware_revision.is_none()
+            && self.firmware_version.is_none()
+            && self.serial_number.is_none()
+            && self.uuid.is_none()
+    }
+
+    /// Sets the manufacturer.
+    #[must_use]
+    pub fn manufacturer(mut self, manufacturer: impl Into<Cow<'static, str>>) -> Self {
+        self.manufacturer = Some(manufacturer.into());
+        self
+    }
+
+    /// Sets the model.
+    #[must_use]
+    pub fn model(mut self, model: impl Into<Cow<'static, str>>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Sets the hardware revision.
+    #[must_use]
+    pub fn hardware_revision(mut self, hardware_revision: impl Into<Cow<'static, str>>) -> Self {
+        self.hardware_revision = Some(hardware_revision.into());
+        self
+    }
+
+    /// Sets the firmware version.
+    #[must_use]
+    pub fn firmware_version(mut self, firmware_version: impl Into<Cow<'static, str>>) -> Self {
+        self.firmware_version = Some(firmware_version.into());
+        self
+    }
+
+    /// Sets the serial number.
+    #[must_use]
+    pub fn serial_number(mut self, serial_number: impl Into<Cow<'static, str>>) -> Self {
+        self.serial_number = Some(serial_number.into());
+        self
+    }
+
+    /// Sets the stable unique identifier.
+    #[must_use]
+    pub const fn uuid(mut self, uuid: Uuid) -> Self {
+        self.uuid = Some(uuid);
+        self
+    }
+}
+
 /// Device data.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct DeviceData {
+    /// Protocol version.
+    #[serde(rename = "protocol version")]
+    pub protocol_version: ProtocolVersion,
     /// Device kind.
     pub kind: DeviceKind,
     /// Device environment.
     pub environment: DeviceEnvironment,
     /// Device description.
     pub description: Option<alloc::borrow::Cow<'static, str>>,
+    /// Device identity.
+    #[serde(skip_serializing_if = "DeviceIdentity::is_empty")]
+    #[serde(default = "DeviceIdentity::empty")]
+    pub identity: DeviceIdentity,
     /// Device main route.
     #[serde(rename = "main route")]
     pub main_route: alloc::borrow::Cow<'static, str>,
     /// All device route configurations.
     pub route_configs: RouteConfigs,
+    /// Custom hazards declared by the device vendor.
+    #[serde(rename = "custom hazards")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default = "Vec::new")]
+    pub custom_hazards: Vec<CustomHazardData>,
     /// Wi-Fi MAC address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wifi_mac: Option<[u8; 6]>,
@@ -111,6 +664,9 @@ pub struct DeviceData {
 
 impl DeviceData {
     /// Creates a [`DeviceData`].
+    ///
+    /// The [`DeviceData`] always advertises the [`PROTOCOL_VERSION`]
+    /// implemented by this crate.
     #[must_use]
     pub fn new(
         kind: DeviceKind,
@@ -122,11 +678,14 @@ impl DeviceData {
         mandatory_routes: u8,
     ) -> Self {
         Self {
+            protocol_version: PROTOCOL_VERSION,
             kind,
             environment,
             description: None,
+            identity: DeviceIdentity::empty(),
             main_route: main_route.into(),
             route_configs,
+            custom_hazards: Vec::new(),
             wifi_mac,
             ethernet_mac,
             mandatory_routes,
@@ -139,6 +698,30 @@ impl DeviceData {
         self.description = Some(description.into());
         self
     }
+
+    /// Sets the device identity.
+    #[must_use]
+    pub fn identity(mut self, identity: DeviceIdentity) -> Self {
+        self.identity = identity;
+        self
+    }
+
+    /// Sets the custom hazards declared by the device vendor.
+    #[must_use]
+    pub fn custom_hazards(mut self, custom_hazards: Vec<CustomHazardData>) -> Self {
+        self.custom_hazards = custom_hazards;
+        self
+    }
+
+    /// Returns the [`CustomHazardData`] describing a [`CustomHazard`].
+    ///
+    /// If [`None`], the device has not declared the custom hazard.
+    #[must_use]
+    pub fn custom_hazard(&self, hazard: &CustomHazard) -> Option<&CustomHazardData> {
+        self.custom_hazards
+            .iter()
+            .find(|custom_hazard| custom_hazard.hazard == *hazard)
+    }
 }
 
 #[cfg(test)]
@@ -152,7 +735,19 @@ mod tests {
     };
     use crate::{deserialize, serialize};
 
-    use super::{DeviceData, DeviceEnvironment, DeviceInfo, DeviceKind};
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use crate::hazards::{Category, CustomHazard, CustomHazardData, Hazard};
+    use crate::parameters::{Comparison, Condition, Parameters};
+    use crate::response::ResponseKind;
+    use crate::route::RestKind;
+
+    use crate::protocol::PROTOCOL_VERSION;
+
+    use super::{
+        DeviceData, DeviceEnvironment, DeviceIdentity, DeviceInfo, DeviceKind, ProfileError, Uuid,
+    };
 
     fn energy() -> Energy {
         let energy_efficiencies =
@@ -187,7 +782,14 @@ mod tests {
 
     #[test]
     fn test_device_kind() {
-        for device_kind in &[DeviceKind::Unknown, DeviceKind::Light, DeviceKind::Camera] {
+        for device_kind in &[
+            DeviceKind::Unknown,
+            DeviceKind::Light,
+            DeviceKind::Camera,
+            DeviceKind::Thermostat,
+            DeviceKind::SmartPlug,
+            DeviceKind::DoorLock,
+        ] {
             assert_eq!(
                 deserialize::<DeviceKind>(serialize(device_kind)),
                 *device_kind
@@ -195,6 +797,156 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_device_kind_confirmation() {
+        assert!(DeviceKind::DoorLock.requires_confirmation("/unlock"));
+        assert!(!DeviceKind::DoorLock.requires_confirmation("/lock"));
+        assert!(!DeviceKind::Light.requires_confirmation("/unlock"));
+    }
+
+    #[test]
+    fn test_device_profile_mandatory_routes() {
+        let profile = DeviceKind::SmartPlug.profile();
+        assert_eq!(profile.kind, DeviceKind::SmartPlug);
+
+        let on = Route::put("On", "/on").serialize_data();
+        let off = Route::put("Off", "/off").serialize_data();
+        let mut measurement = Route::get("Measurement", "/measurement").serialize_data();
+
+        assert_eq!(
+            profile.check_route_configs(&RouteConfigs::new().insert(on.clone())),
+            Err(ProfileError::MissingRoute { path: "/off" })
+        );
+
+        assert_eq!(
+            profile.check_route(&measurement),
+            Err(ProfileError::WrongResponseKind {
+                path: "/measurement",
+                expected: ResponseKind::Serial,
+            })
+        );
+        measurement.response_kind = ResponseKind::Serial;
+
+        assert_eq!(
+            profile.check_route_configs(
+                &RouteConfigs::new()
+                    .insert(on)
+                    .insert(off)
+                    .insert(measurement)
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            profile.check_route(&Route::get("On", "/on").serialize_data()),
+            Err(ProfileError::WrongMethod {
+                path: "/on",
+                method: RestKind::Get,
+            })
+        );
+    }
+
+    #[test]
+    fn test_device_profile_requirements() {
+        let unlock = Route::put("Unlock", "/unlock").serialize_data();
+        assert_eq!(
+            DeviceKind::DoorLock.profile().check_route(&unlock),
+            Err(ProfileError::MissingHazard {
+                path: "/unlock",
+                hazard: Hazard::UnauthorisedPhysicalAccess,
+            })
+        );
+
+        let target = Route::put("Target", "/target-temperature").serialize_data();
+        assert_eq!(
+            DeviceKind::Thermostat.profile().check_route(&target),
+            Err(ProfileError::MissingParameter {
+                path: "/target-temperature",
+                parameter: "temperature",
+            })
+        );
+        let target = Route::put("Target", "/target-temperature")
+            .with_parameters(Parameters::new().rangef64("temperature", (5., 30., 0.5)))
+            .serialize_data();
+        assert_eq!(
+            DeviceKind::Thermostat.profile().check_route(&target),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_device_profile_hazards() {
+        let toggle = Route::put("Toggle", "/toggle")
+            .with_hazard(Hazard::TakePictures)
+            .serialize_data();
+
+        assert_eq!(
+            DeviceKind::Light.profile().check_route(&toggle),
+            Err(ProfileError::HazardNotAllowed {
+                kind: DeviceKind::Light,
+                hazard: Hazard::TakePictures,
+            })
+        );
+        assert_eq!(DeviceKind::Unknown.profile().check_route(&toggle), Ok(()));
+
+        // Custom hazards are restricted to the categories of the allowed
+        // hazards.
+        let dose = Route::put("Dose", "/dose")
+            .with_hazard(Hazard::Custom(
+                CustomHazard::new(1000, Category::Safety).unwrap(),
+            ))
+            .serialize_data();
+        assert_eq!(DeviceKind::Light.profile().check_route(&dose), Ok(()));
+
+        let track = Hazard::Custom(CustomHazard::new(1001, Category::Privacy).unwrap());
+        let track_route = Route::put("Track", "/track")
+            .with_hazard(track)
+            .serialize_data();
+        assert_eq!(
+            DeviceKind::Light.profile().check_route(&track_route),
+            Err(ProfileError::HazardNotAllowed {
+                kind: DeviceKind::Light,
+                hazard: track,
+            })
+        );
+        assert_eq!(
+            DeviceKind::Unknown.profile().check_route(&track_route),
+            Ok(())
+        );
+
+        // Conditional hazards must be allowed too.
+        let record = Route::put("Record", "/record")
+            .with_conditional_hazard(
+                Hazard::TakePictures,
+                Condition::new("quality", Comparison::Equal, "high"),
+            )
+            .with_parameters(Parameters::new().choice("quality", &["low", "high"]))
+            .serialize_data();
+        assert_eq!(
+            DeviceKind::Light.profile().check_route(&record),
+            Err(ProfileError::HazardNotAllowed {
+                kind: DeviceKind::Light,
+                hazard: Hazard::TakePictures,
+            })
+        );
+
+        // Informative routes are not checked.
+        let mut info = Route::get("Info", "/info")
+            .with_hazard(Hazard::LogEnergyConsumption)
+            .serialize_data();
+        info.response_kind = ResponseKind::Info;
+        assert_eq!(DeviceKind::Light.profile().check_route(&info), Ok(()));
+
+        assert_eq!(
+            ProfileError::HazardNotAllowed {
+                kind: DeviceKind::Light,
+                hazard: Hazard::TakePictures,
+            }
+            .to_string(),
+            "Take Pictures hazard is not allowed for Light"
+        );
+    }
+
     #[test]
     fn test_device_environment() {
         for device_environment in &[DeviceEnvironment::Os, DeviceEnvironment::Esp32] {
@@ -217,6 +969,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_device_identity() {
+        let identity = DeviceIdentity::empty()
+            .manufacturer("Ascot")
+            .model("L-100")
+            .hardware_revision("rev. B")
+            .firmware_version("1.4.2")
+            .serial_number("SN-0001")
+            .uuid(Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8));
+
+        assert!(DeviceIdentity::empty().is_empty());
+        assert!(!identity.is_empty());
+
+        assert_eq!(
+            deserialize::<DeviceIdentity>(serialize(&identity)),
+            identity
+        );
+
+        // An empty identity is not serialized.
+        let device_data = DeviceData::new(
+            DeviceKind::Light,
+            DeviceEnvironment::Os,
+            "/light",
+            routes(),
+            None,
+            None,
+            2,
+        );
+        assert!(serialize(&device_data).get("identity").is_none());
+
+        let device_data = device_data.identity(identity);
+        assert_eq!(
+            deserialize::<DeviceData>(serialize(&device_data)),
+            device_data
+        );
+    }
+
     #[test]
     fn test_device_data() {
         let device_data = DeviceData::new(
@@ -234,5 +1023,34 @@ mod tests {
             deserialize::<DeviceData>(serialize(&device_data)),
             device_data
         );
+
+        // The protocol version is always serialized.
+        assert_eq!(
+            serialize(&device_data)["protocol version"],
+            serialize(PROTOCOL_VERSION)
+        );
+
+        // Custom hazards are serialized only when declared.
+        assert!(serialize(&device_data).get("custom hazards").is_none());
+
+        let dosing = CustomHazard::new(1000, Category::Safety).unwrap();
+        let device_data = device_data.custom_hazards(vec![CustomHazardData::new(
+            dosing,
+            "Chemical Dosing",
+            "Doses chemicals into the pool water.",
+        )]);
+
+        assert_eq!(
+            deserialize::<DeviceData>(serialize(&device_data)),
+            device_data
+        );
+        assert_eq!(
+            device_data.custom_hazard(&dosing).map(|data| &data.name),
+            Some(&"Chemical Dosing".into())
+        );
+        assert_eq!(
+            device_data.custom_hazard(&CustomHazard::new(1001, Category::Safety).unwrap()),
+            None
+        );
     }
 }
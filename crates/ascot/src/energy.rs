@@ -259,6 +259,41 @@ impl WaterUseEfficiency {
     }
 }
 
+/// Instantaneous electric measurement of a device.
+///
+/// Usually reported by metering devices, such as smart plugs.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct PowerMeasurement {
+    /// Instantaneous power expressed in Watt.
+    pub power: f64,
+    /// Instantaneous voltage expressed in Volt.
+    pub voltage: f64,
+    /// Cumulative energy consumption expressed in kilowatt-hour.
+    pub energy: f64,
+}
+
+impl core::fmt::Display for PowerMeasurement {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "The device draws {} W at {} V and has consumed {} kWh",
+            self.power, self.voltage, self.energy
+        )
+    }
+}
+
+impl PowerMeasurement {
+    /// Creates a [`PowerMeasurement`] instance.
+    #[must_use]
+    pub const fn new(power: f64, voltage: f64, energy: f64) -> Self {
+        Self {
+            power,
+            voltage,
+            energy,
+        }
+    }
+}
+
 /// Energy information of a device.
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Energy {
@@ -360,7 +395,7 @@ mod tests {
 
     use super::{
         CarbonFootprint, CarbonFootprints, EnergyClass, EnergyEfficiencies, EnergyEfficiency,
-        WaterUseEfficiency,
+        PowerMeasurement, WaterUseEfficiency,
     };
 
     fn assert_float_eq(a: f64, b: f64) {
@@ -482,4 +517,14 @@ mod tests {
 
         assert_eq!(deserialize::<Energy>(serialize(&energy)), energy);
     }
+
+    #[test]
+    fn test_power_measurement() {
+        let measurement = PowerMeasurement::new(60.5, 230., 12.25);
+
+        assert_eq!(
+            deserialize::<PowerMeasurement>(serialize(measurement)),
+            measurement
+        );
+    }
 }
@@ -1,12 +1,68 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
 use hashbrown::DefaultHashBuilder;
 
+use indexmap::map::IndexMap;
 use indexmap::set::{IndexSet, IntoIter, Iter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::macros::set;
+use crate::macros::{map, set};
+
+/// All [`Locale`]s in which hazards and categories are described.
+pub const ALL_LOCALES: &[Locale] = &[Locale::English, Locale::Italian, Locale::German];
+
+/// A language in which hazards and categories are described.
+///
+/// A locale is identified by its `ISO 639-1` language code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Locale {
+    /// English.
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    /// Italian.
+    #[serde(rename = "it")]
+    Italian,
+    /// German.
+    #[serde(rename = "de")]
+    German,
+}
+
+impl core::fmt::Display for Locale {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.code().fmt(f)
+    }
+}
+
+impl Locale {
+    /// Returns the `ISO 639-1` language code of a [`Locale`].
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Italian => "it",
+            Self::German => "de",
+        }
+    }
+
+    /// Returns the [`Locale`] of a language tag, such as `it` or `de-CH`.
+    ///
+    /// Only the language subtag is considered, ignoring its case.
+    ///
+    /// The return value is [`None`] when the language is not supported.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['-', '_']).next().unwrap_or_default();
+        ALL_LOCALES
+            .iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(language))
+            .copied()
+    }
+}
 
-/// All [`Hazard`]s.
+/// All built-in [`Hazard`]s.
 pub const ALL_HAZARDS: &[Hazard] = &[
     Hazard::AirPoisoning,
     Hazard::Asphyxia,
@@ -96,6 +152,8 @@ pub enum Hazard {
     WaterConsumption,
     /// The execution enables a device to water usage, which may lead to flood.
     WaterFlooding,
+    /// A hazard declared by a device vendor.
+    Custom(CustomHazard),
 }
 
 impl core::convert::AsRef<Self> for Hazard {
@@ -106,13 +164,16 @@ impl core::convert::AsRef<Self> for Hazard {
 
 impl core::fmt::Debug for Hazard {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        self.name().fmt(f)
+        core::fmt::Display::fmt(self, f)
     }
 }
 
 impl core::fmt::Display for Hazard {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        self.name().fmt(f)
+        match self {
+            Self::Custom(custom) => write!(f, "{} {}", self.name(), custom.id()),
+            _ => self.name().fmt(f),
+        }
     }
 }
 
@@ -145,6 +206,7 @@ impl Hazard {
             Self::VideoRecordAndStore => "Video Record and Store",
             Self::WaterConsumption => "Water Consumption",
             Self::WaterFlooding => "Water Flooding",
+            Self::Custom(_) => "Custom Hazard",
         }
     }
 
@@ -208,6 +270,27 @@ impl Hazard {
             Self::WaterFlooding => {
                 "The execution enables a device to water usage, which may lead to flood."
             }
+            Self::Custom(_) => "The execution may cause a hazard declared by the device vendor.",
+        }
+    }
+
+    /// Returns an [`Hazard`] name in the given [`Locale`].
+    #[must_use]
+    pub const fn localized_name(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::English => self.name(),
+            Locale::Italian => self.italian_name(),
+            Locale::German => self.german_name(),
+        }
+    }
+
+    /// Returns an [`Hazard`] description in the given [`Locale`].
+    #[must_use]
+    pub const fn localized_description(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::English => self.description(),
+            Locale::Italian => self.italian_description(),
+            Locale::German => self.german_description(),
         }
     }
 
@@ -241,6 +324,7 @@ impl Hazard {
             | Self::PaySubscriptionFee
             | Self::SpendMoney
             | Self::WaterConsumption => Category::Financial,
+            Self::Custom(custom) => custom.category(),
         }
     }
 
@@ -272,13 +356,15 @@ impl Hazard {
             Self::VideoRecordAndStore => 21,
             Self::WaterConsumption => 22,
             Self::WaterFlooding => 23,
+            Self::Custom(custom) => custom.id(),
         }
     }
 
-    /// Returns an [`Hazard`] from an integer identifier.
+    /// Returns a built-in [`Hazard`] from an integer identifier.
     ///
     /// The return value is [`None`] when the identifier is not correct or
-    /// it does not exist.
+    /// it does not exist. Since the [`Category`] of a [`CustomHazard`] cannot
+    /// be retrieved from its identifier, custom hazards are never returned.
     #[must_use]
     pub const fn from_id(id: u16) -> Option<Self> {
         match id {
@@ -310,15 +396,240 @@ impl Hazard {
         }
     }
 
+    /// Returns the [`Hazard`]s directly implied by an [`Hazard`].
+    ///
+    /// An hazard implies another one when the latter always occurs together
+    /// with the former, for example, recording and storing a video requires
+    /// displaying it. Custom hazards never imply other hazards.
+    #[must_use]
+    pub const fn implies(&self) -> &'static [Self] {
+        match self {
+            Self::AudioVideoRecordAndStore => &[Self::AudioVideoDisplay, Self::VideoRecordAndStore],
+            Self::AudioVideoDisplay | Self::VideoRecordAndStore => &[Self::VideoDisplay],
+            Self::PaySubscriptionFee => &[Self::SpendMoney],
+            _ => &[],
+        }
+    }
+
+    /// Checks whether an [`Hazard`] is a [`CustomHazard`].
+    #[must_use]
+    pub const fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
     /// Returns the [`HazardData`] of an [`Hazard`].
     #[must_use]
     pub const fn data(&self) -> HazardData {
+        self.localized_data(Locale::English)
+    }
+
+    /// Returns the [`HazardData`] of an [`Hazard`] in the given [`Locale`].
+    #[must_use]
+    pub const fn localized_data(&self, locale: Locale) -> HazardData {
         HazardData {
             id: self.id(),
-            name: self.name(),
-            description: self.description(),
-            category_name: self.category().name(),
-            category_description: self.category().description(),
+            name: self.localized_name(locale),
+            description: self.localized_description(locale),
+            category_name: self.category().localized_name(locale),
+            category_description: self.category().localized_description(locale),
+        }
+    }
+
+    // Returns the Italian name of an [`Hazard`].
+    const fn italian_name(self) -> &'static str {
+        match self {
+            Self::AirPoisoning => "Avvelenamento dell'aria",
+            Self::Asphyxia => "Asfissia",
+            Self::AudioVideoDisplay => "Visualizzazione audio video",
+            Self::AudioVideoRecordAndStore => "Registrazione e archiviazione audio video",
+            Self::ElectricEnergyConsumption => "Consumo di energia elettrica",
+            Self::Explosion => "Esplosione",
+            Self::FireHazard => "Pericolo di incendio",
+            Self::GasConsumption => "Consumo di gas",
+            Self::LogEnergyConsumption => "Registrazione del consumo energetico",
+            Self::LogUsageTime => "Registrazione del tempo di utilizzo",
+            Self::PaySubscriptionFee => "Pagamento di un abbonamento",
+            Self::PowerOutage => "Interruzione di corrente",
+            Self::PowerSurge => "Sovratensione",
+            Self::RecordIssuedCommands => "Registrazione dei comandi impartiti",
+            Self::RecordUserPreferences => "Registrazione delle preferenze dell'utente",
+            Self::SpendMoney => "Spesa di denaro",
+            Self::SpoiledFood => "Cibo avariato",
+            Self::TakeDeviceScreenshots => "Acquisizione di schermate del dispositivo",
+            Self::TakePictures => "Scatto di fotografie",
+            Self::UnauthorisedPhysicalAccess => "Accesso fisico non autorizzato",
+            Self::VideoDisplay => "Visualizzazione video",
+            Self::VideoRecordAndStore => "Registrazione e archiviazione video",
+            Self::WaterConsumption => "Consumo di acqua",
+            Self::WaterFlooding => "Allagamento",
+            Self::Custom(_) => "Pericolo personalizzato",
+        }
+    }
+
+    // Returns the Italian description of an [`Hazard`].
+    const fn italian_description(self) -> &'static str {
+        match self {
+            Self::AirPoisoning => "L'esecuzione può rilasciare gas tossici.",
+            Self::Asphyxia => {
+                "L'esecuzione può causare carenza di ossigeno dovuta a sostanze gassose."
+            }
+            Self::AudioVideoDisplay => {
+                "L'esecuzione autorizza un'applicazione a visualizzare un video con audio proveniente da un dispositivo."
+            }
+            Self::AudioVideoRecordAndStore => {
+                "L'esecuzione autorizza un'applicazione a registrare e salvare su una memoria persistente un video con audio proveniente da un dispositivo."
+            }
+            Self::ElectricEnergyConsumption => {
+                "L'esecuzione attiva un dispositivo che consuma elettricità."
+            }
+            Self::Explosion => "L'esecuzione può causare un'esplosione.",
+            Self::FireHazard => "L'esecuzione può causare un incendio.",
+            Self::GasConsumption => "L'esecuzione attiva un dispositivo che consuma gas.",
+            Self::LogEnergyConsumption => {
+                "L'esecuzione autorizza un'applicazione a ottenere e salvare informazioni sull'impatto energetico di un dispositivo."
+            }
+            Self::LogUsageTime => {
+                "L'esecuzione autorizza un'applicazione a ottenere e salvare informazioni sulla durata di utilizzo di un dispositivo."
+            }
+            Self::PaySubscriptionFee => {
+                "L'esecuzione autorizza un'applicazione a usare le informazioni di pagamento ed effettuare un pagamento periodico."
+            }
+            Self::PowerOutage => {
+                "L'esecuzione può causare un'interruzione della fornitura di elettricità."
+            }
+            Self::PowerSurge => "L'esecuzione può comportare l'esposizione ad alte tensioni.",
+            Self::RecordIssuedCommands => {
+                "L'esecuzione autorizza un'applicazione a ottenere e salvare gli input dell'utente."
+            }
+            Self::RecordUserPreferences => {
+                "L'esecuzione autorizza un'applicazione a ottenere e salvare informazioni sulle preferenze dell'utente."
+            }
+            Self::SpendMoney => {
+                "L'esecuzione autorizza un'applicazione a usare le informazioni di pagamento ed effettuare una transazione."
+            }
+            Self::SpoiledFood => "L'esecuzione può far avariare il cibo.",
+            Self::TakeDeviceScreenshots => {
+                "L'esecuzione autorizza un'applicazione a leggere l'output dello schermo e ad acquisirne delle schermate."
+            }
+            Self::TakePictures => {
+                "L'esecuzione autorizza un'applicazione a usare una fotocamera e scattare foto."
+            }
+            Self::UnauthorisedPhysicalAccess => {
+                "L'esecuzione disattiva un meccanismo di protezione, pertanto persone non autorizzate potrebbero accedere fisicamente all'ambiente."
+            }
+            Self::VideoDisplay => {
+                "L'esecuzione autorizza un'applicazione a visualizzare un video proveniente da un dispositivo."
+            }
+            Self::VideoRecordAndStore => {
+                "L'esecuzione autorizza un'applicazione a registrare e salvare su una memoria persistente un video proveniente da un dispositivo."
+            }
+            Self::WaterConsumption => "L'esecuzione attiva un dispositivo che consuma acqua.",
+            Self::WaterFlooding => {
+                "L'esecuzione attiva un dispositivo che usa acqua, il che può causare un allagamento."
+            }
+            Self::Custom(_) => {
+                "L'esecuzione può causare un pericolo dichiarato dal produttore del dispositivo."
+            }
+        }
+    }
+
+    // Returns the German name of an [`Hazard`].
+    const fn german_name(self) -> &'static str {
+        match self {
+            Self::AirPoisoning => "Luftvergiftung",
+            Self::Asphyxia => "Erstickung",
+            Self::AudioVideoDisplay => "Audio-Video-Anzeige",
+            Self::AudioVideoRecordAndStore => "Audio-Video-Aufzeichnung und -Speicherung",
+            Self::ElectricEnergyConsumption => "Stromverbrauch",
+            Self::Explosion => "Explosion",
+            Self::FireHazard => "Brandgefahr",
+            Self::GasConsumption => "Gasverbrauch",
+            Self::LogEnergyConsumption => "Protokollierung des Energieverbrauchs",
+            Self::LogUsageTime => "Protokollierung der Nutzungsdauer",
+            Self::PaySubscriptionFee => "Zahlung einer Abonnementgebühr",
+            Self::PowerOutage => "Stromausfall",
+            Self::PowerSurge => "Überspannung",
+            Self::RecordIssuedCommands => "Aufzeichnung erteilter Befehle",
+            Self::RecordUserPreferences => "Aufzeichnung von Benutzervorlieben",
+            Self::SpendMoney => "Geld ausgeben",
+            Self::SpoiledFood => "Verdorbene Lebensmittel",
+            Self::TakeDeviceScreenshots => "Bildschirmaufnahmen des Geräts",
+            Self::TakePictures => "Fotos aufnehmen",
+            Self::UnauthorisedPhysicalAccess => "Unbefugter physischer Zugang",
+            Self::VideoDisplay => "Videoanzeige",
+            Self::VideoRecordAndStore => "Videoaufzeichnung und -speicherung",
+            Self::WaterConsumption => "Wasserverbrauch",
+            Self::WaterFlooding => "Überschwemmung",
+            Self::Custom(_) => "Herstellerspezifische Gefahr",
+        }
+    }
+
+    // Returns the German description of an [`Hazard`].
+    const fn german_description(self) -> &'static str {
+        match self {
+            Self::AirPoisoning => "Die Ausführung kann giftige Gase freisetzen.",
+            Self::Asphyxia => {
+                "Die Ausführung kann durch gasförmige Stoffe einen Sauerstoffmangel verursachen."
+            }
+            Self::AudioVideoDisplay => {
+                "Die Ausführung erlaubt einer Anwendung, ein Video mit Ton von einem Gerät anzuzeigen."
+            }
+            Self::AudioVideoRecordAndStore => {
+                "Die Ausführung erlaubt einer Anwendung, ein Video mit Ton von einem Gerät aufzuzeichnen und dauerhaft zu speichern."
+            }
+            Self::ElectricEnergyConsumption => {
+                "Die Ausführung aktiviert ein Gerät, das Strom verbraucht."
+            }
+            Self::Explosion => "Die Ausführung kann eine Explosion verursachen.",
+            Self::FireHazard => "Die Ausführung kann einen Brand verursachen.",
+            Self::GasConsumption => "Die Ausführung aktiviert ein Gerät, das Gas verbraucht.",
+            Self::LogEnergyConsumption => {
+                "Die Ausführung erlaubt einer Anwendung, Informationen über den Energieverbrauch eines Geräts abzurufen und zu speichern."
+            }
+            Self::LogUsageTime => {
+                "Die Ausführung erlaubt einer Anwendung, Informationen über die Nutzungsdauer eines Geräts abzurufen und zu speichern."
+            }
+            Self::PaySubscriptionFee => {
+                "Die Ausführung erlaubt einer Anwendung, Zahlungsinformationen zu verwenden und eine regelmäßige Zahlung zu leisten."
+            }
+            Self::PowerOutage => {
+                "Die Ausführung kann eine Unterbrechung der Stromversorgung verursachen."
+            }
+            Self::PowerSurge => {
+                "Die Ausführung kann dazu führen, dass man hohen Spannungen ausgesetzt ist."
+            }
+            Self::RecordIssuedCommands => {
+                "Die Ausführung erlaubt einer Anwendung, Benutzereingaben abzurufen und zu speichern."
+            }
+            Self::RecordUserPreferences => {
+                "Die Ausführung erlaubt einer Anwendung, Informationen über die Vorlieben des Benutzers abzurufen und zu speichern."
+            }
+            Self::SpendMoney => {
+                "Die Ausführung erlaubt einer Anwendung, Zahlungsinformationen zu verwenden und eine Zahlung durchzuführen."
+            }
+            Self::SpoiledFood => "Die Ausführung kann zum Verderben von Lebensmitteln führen.",
+            Self::TakeDeviceScreenshots => {
+                "Die Ausführung erlaubt einer Anwendung, die Bildschirmausgabe zu lesen und Bildschirmaufnahmen zu erstellen."
+            }
+            Self::TakePictures => {
+                "Die Ausführung erlaubt einer Anwendung, eine Kamera zu verwenden und Fotos aufzunehmen."
+            }
+            Self::UnauthorisedPhysicalAccess => {
+                "Die Ausführung deaktiviert einen Schutzmechanismus, daher könnten sich Unbefugte physischen Zugang zur Umgebung verschaffen."
+            }
+            Self::VideoDisplay => {
+                "Die Ausführung erlaubt einer Anwendung, ein Video von einem Gerät anzuzeigen."
+            }
+            Self::VideoRecordAndStore => {
+                "Die Ausführung erlaubt einer Anwendung, ein Video von einem Gerät aufzuzeichnen und dauerhaft zu speichern."
+            }
+            Self::WaterConsumption => "Die Ausführung aktiviert ein Gerät, das Wasser verbraucht.",
+            Self::WaterFlooding => {
+                "Die Ausführung aktiviert ein Gerät, das Wasser verwendet, was zu einer Überschwemmung führen kann."
+            }
+            Self::Custom(_) => {
+                "Die Ausführung kann eine vom Gerätehersteller angegebene Gefahr verursachen."
+            }
         }
     }
 }
@@ -340,6 +651,290 @@ impl Hazards {
         }
         elements
     }
+
+    /// Returns the [`Hazards`] expanded with all the hazards they imply,
+    /// directly or transitively.
+    ///
+    /// The implied hazards follow the original ones, in the order they are
+    /// found.
+    #[must_use]
+    pub fn closure(&self) -> Self {
+        let mut closure = self.clone();
+
+        let mut index = 0;
+        while let Some(hazard) = closure.0.get_index(index).copied() {
+            for implied in hazard.implies() {
+                closure.add(*implied);
+            }
+            index += 1;
+        }
+
+        closure
+    }
+
+    /// Returns the [`Hazards`] contained either in `self` or in `other`.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0.union(&other.0).copied().collect())
+    }
+
+    /// Returns the [`Hazards`] contained both in `self` and in `other`.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(self.0.intersection(&other.0).copied().collect())
+    }
+
+    /// Returns the [`Hazards`] contained in `self` but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        Self(self.0.difference(&other.0).copied().collect())
+    }
+
+    /// Returns the [`Hazards`] belonging to the given [`Category`].
+    #[must_use]
+    pub fn filter_by_category(&self, category: Category) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|hazard| hazard.category() == category)
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// The first identifier reserved to [`CustomHazard`]s.
+///
+/// The identifiers of the built-in hazards are always lower than this value.
+pub const CUSTOM_HAZARD_FIRST_ID: u16 = 1000;
+
+// The serialized form of a custom hazard, before checking its identifier.
+#[derive(Deserialize)]
+struct UncheckedCustomHazard {
+    id: u16,
+    category: Category,
+}
+
+/// A hazard declared by a device vendor, which is not part of the built-in
+/// catalogue.
+///
+/// A custom hazard is identified by an identifier greater than or equal to
+/// [`CUSTOM_HAZARD_FIRST_ID`], and always belongs to a [`Category`], so that
+/// a controller which does not know it can still apply its policies by
+/// category. Its name and description are described by a
+/// [`CustomHazardData`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedCustomHazard")]
+pub struct CustomHazard {
+    id: u16,
+    category: Category,
+}
+
+impl TryFrom<UncheckedCustomHazard> for CustomHazard {
+    type Error = &'static str;
+
+    fn try_from(hazard: UncheckedCustomHazard) -> Result<Self, Self::Error> {
+        Self::new(hazard.id, hazard.category)
+            .ok_or("the identifier of a custom hazard is reserved to built-in hazards")
+    }
+}
+
+impl CustomHazard {
+    /// Creates a [`CustomHazard`].
+    ///
+    /// The return value is [`None`] when the identifier is lower than
+    /// [`CUSTOM_HAZARD_FIRST_ID`].
+    #[must_use]
+    pub const fn new(id: u16, category: Category) -> Option<Self> {
+        if id >= CUSTOM_HAZARD_FIRST_ID {
+            Some(Self { id, category })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the identifier of a [`CustomHazard`].
+    #[must_use]
+    pub const fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the [`Category`] of a [`CustomHazard`].
+    #[must_use]
+    pub const fn category(&self) -> Category {
+        self.category
+    }
+}
+
+/// The description of a [`CustomHazard`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomHazardData {
+    /// Custom hazard.
+    #[serde(flatten)]
+    pub hazard: CustomHazard,
+    /// Name.
+    pub name: Cow<'static, str>,
+    /// Description.
+    pub description: Cow<'static, str>,
+}
+
+impl CustomHazardData {
+    /// Creates a [`CustomHazardData`].
+    #[must_use]
+    pub fn new(
+        hazard: CustomHazard,
+        name: impl Into<Cow<'static, str>>,
+        description: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            hazard,
+            name: name.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// The severity of the consequences of an [`Hazard`].
+///
+/// Severities are ordered from the least to the most severe one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    /// The consequences are negligible.
+    Negligible,
+    /// The consequences are minor and easily recoverable.
+    Minor,
+    /// The consequences are moderate.
+    Moderate,
+    /// The consequences are serious.
+    High,
+    /// The consequences are catastrophic and irreversible.
+    Catastrophic,
+}
+
+impl core::fmt::Display for Severity {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl Severity {
+    /// Returns a [`Severity`] name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Negligible => "Negligible",
+            Self::Minor => "Minor",
+            Self::Moderate => "Moderate",
+            Self::High => "High",
+            Self::Catastrophic => "Catastrophic",
+        }
+    }
+}
+
+/// The likelihood that an [`Hazard`] occurs.
+///
+/// Likelihoods are ordered from the least to the most likely one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Likelihood {
+    /// The hazard occurs only in exceptional circumstances.
+    Rare,
+    /// The hazard is not expected to occur.
+    Unlikely,
+    /// The hazard might occur.
+    Possible,
+    /// The hazard is expected to occur.
+    Likely,
+    /// The hazard occurs at each execution.
+    Certain,
+}
+
+impl core::fmt::Display for Likelihood {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl Likelihood {
+    /// Returns a [`Likelihood`] name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Rare => "Rare",
+            Self::Unlikely => "Unlikely",
+            Self::Possible => "Possible",
+            Self::Likely => "Likely",
+            Self::Certain => "Certain",
+        }
+    }
+}
+
+/// The level of an [`Hazard`] declared by a route.
+///
+/// The same hazard might be harmless on a device and dangerous on another
+/// one, such as a fire hazard on a night-light and on a space heater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HazardLevel {
+    /// Severity of the consequences.
+    pub severity: Severity,
+    /// Likelihood of the occurrence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub likelihood: Option<Likelihood>,
+}
+
+impl HazardLevel {
+    /// Creates an [`HazardLevel`] with the given [`Severity`].
+    #[must_use]
+    pub const fn new(severity: Severity) -> Self {
+        Self {
+            severity,
+            likelihood: None,
+        }
+    }
+
+    /// Sets the [`Likelihood`] of an [`HazardLevel`].
+    #[must_use]
+    pub const fn likelihood(mut self, likelihood: Likelihood) -> Self {
+        self.likelihood = Some(likelihood);
+        self
+    }
+}
+
+// The serialized form of an hazard together with its level.
+//
+// Levels are serialized as a sequence of entries rather than as a map, since
+// custom hazards cannot be used as map keys.
+#[derive(Serialize, Deserialize)]
+struct HazardLevelEntry {
+    hazard: Hazard,
+    level: HazardLevel,
+}
+
+map! {
+  /// A map of [`Hazard`]s with their [`HazardLevel`]s.
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+  #[serde(from = "Vec<HazardLevelEntry>", into = "Vec<HazardLevelEntry>")]
+  pub struct HazardLevels(IndexMap<Hazard, HazardLevel, DefaultHashBuilder>);
+}
+
+impl From<Vec<HazardLevelEntry>> for HazardLevels {
+    fn from(entries: Vec<HazardLevelEntry>) -> Self {
+        let mut levels = Self::new();
+        for entry in entries {
+            levels.add(entry.hazard, entry.level);
+        }
+        levels
+    }
+}
+
+impl From<HazardLevels> for Vec<HazardLevelEntry> {
+    fn from(levels: HazardLevels) -> Self {
+        levels
+            .0
+            .into_iter()
+            .map(|(hazard, level)| HazardLevelEntry { hazard, level })
+            .collect()
+    }
 }
 
 /// All [`Hazard`] data.
@@ -406,7 +1001,45 @@ impl Category {
         }
     }
 
-    /// Returns all [`Hazard`]s associated with a [`Category`].
+    /// Returns a [`Category`] name in the given [`Locale`].
+    #[must_use]
+    pub const fn localized_name(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::English, _) => self.name(),
+            (Locale::Italian, Self::Financial) => "Finanziaria",
+            (Locale::Italian, Self::Privacy) => "Privacy",
+            (Locale::Italian, Self::Safety) => "Sicurezza",
+            (Locale::German, Self::Financial) => "Finanzen",
+            (Locale::German, Self::Privacy) => "Datenschutz",
+            (Locale::German, Self::Safety) => "Sicherheit",
+        }
+    }
+
+    /// Returns a [`Category`] description in the given [`Locale`].
+    #[must_use]
+    pub const fn localized_description(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::English, _) => self.description(),
+            (Locale::Italian, Self::Financial) => {
+                "Categoria che include tutti i pericoli di natura finanziaria."
+            }
+            (Locale::Italian, Self::Privacy) => {
+                "Categoria che include tutti i pericoli relativi alla privacy."
+            }
+            (Locale::Italian, Self::Safety) => {
+                "Categoria che include tutti i pericoli relativi alla sicurezza."
+            }
+            (Locale::German, Self::Financial) => {
+                "Kategorie, die alle finanziellen Gefahren umfasst."
+            }
+            (Locale::German, Self::Privacy) => {
+                "Kategorie, die alle Gefahren für den Datenschutz umfasst."
+            }
+            (Locale::German, Self::Safety) => "Kategorie, die alle Sicherheitsgefahren umfasst.",
+        }
+    }
+
+    /// Returns all built-in [`Hazard`]s associated with a [`Category`].
     #[must_use]
     pub const fn hazards(&self) -> &[Hazard] {
         match self {
@@ -448,7 +1081,12 @@ impl Category {
 mod tests {
     use crate::{deserialize, serialize};
 
-    use super::{ALL_CATEGORIES, ALL_HAZARDS, Category, Hazard};
+    use alloc::string::ToString;
+
+    use super::{
+        ALL_CATEGORIES, ALL_HAZARDS, ALL_LOCALES, CUSTOM_HAZARD_FIRST_ID, Category, CustomHazard,
+        CustomHazardData, Hazard, HazardLevel, HazardLevels, Hazards, Likelihood, Locale, Severity,
+    };
 
     #[test]
     fn test_hazard() {
@@ -475,11 +1113,239 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_locale() {
+        assert_eq!(Locale::default(), Locale::English);
+
+        for locale in ALL_LOCALES {
+            assert_eq!(Locale::from_code(locale.code()), Some(*locale));
+            assert_eq!(serialize(locale), serde_json::json!(locale.code()));
+            assert_eq!(deserialize::<Locale>(serialize(locale)), *locale);
+        }
+
+        // Only the language subtag is considered.
+        assert_eq!(Locale::from_code("IT"), Some(Locale::Italian));
+        assert_eq!(Locale::from_code("de-CH"), Some(Locale::German));
+        assert_eq!(Locale::from_code("en_GB"), Some(Locale::English));
+        assert_eq!(Locale::from_code("fr"), None);
+        assert_eq!(Locale::from_code(""), None);
+    }
+
+    #[test]
+    fn test_localized_hazard() {
+        let custom =
+            Hazard::Custom(CustomHazard::new(CUSTOM_HAZARD_FIRST_ID, Category::Safety).unwrap());
+
+        for hazard in ALL_HAZARDS.iter().chain([&custom]) {
+            // English is the default catalogue.
+            assert_eq!(hazard.localized_data(Locale::English), hazard.data());
+
+            for locale in &ALL_LOCALES[1..] {
+                let data = hazard.localized_data(*locale);
+                assert_eq!(data.id, hazard.id());
+                assert!(!data.name.is_empty());
+                assert_ne!(data.description, hazard.description());
+                assert_eq!(
+                    data.category_name,
+                    hazard.category().localized_name(*locale)
+                );
+            }
+        }
+
+        let data = Hazard::FireHazard.localized_data(Locale::Italian);
+        assert_eq!(data.name, "Pericolo di incendio");
+        assert_eq!(data.category_name, "Sicurezza");
+
+        let data = Hazard::FireHazard.localized_data(Locale::German);
+        assert_eq!(data.name, "Brandgefahr");
+        assert_eq!(data.category_name, "Sicherheit");
+    }
+
+    #[test]
+    fn test_hazard_implications() {
+        assert_eq!(
+            Hazard::VideoRecordAndStore.implies(),
+            &[Hazard::VideoDisplay]
+        );
+        assert!(Hazard::VideoDisplay.implies().is_empty());
+
+        // Implied hazards are expanded transitively.
+        assert_eq!(
+            Hazards::init(Hazard::AudioVideoRecordAndStore).closure(),
+            Hazards::new()
+                .insert(Hazard::AudioVideoRecordAndStore)
+                .insert(Hazard::AudioVideoDisplay)
+                .insert(Hazard::VideoRecordAndStore)
+                .insert(Hazard::VideoDisplay)
+        );
+
+        // A closure does not contain duplicates and is idempotent.
+        let hazards = Hazards::new()
+            .insert(Hazard::VideoDisplay)
+            .insert(Hazard::VideoRecordAndStore)
+            .insert(Hazard::PaySubscriptionFee);
+        let closure = hazards.closure();
+        assert_eq!(
+            closure,
+            Hazards::new()
+                .insert(Hazard::VideoDisplay)
+                .insert(Hazard::VideoRecordAndStore)
+                .insert(Hazard::PaySubscriptionFee)
+                .insert(Hazard::SpendMoney)
+        );
+        assert_eq!(closure.closure(), closure);
+
+        // An hazard never implies a hazard of a different category.
+        for hazard in ALL_HAZARDS {
+            assert!(
+                hazard
+                    .implies()
+                    .iter()
+                    .all(|implied| implied.category() == hazard.category())
+            );
+        }
+    }
+
+    #[test]
+    fn test_hazards_operations() {
+        let first = Hazards::new()
+            .insert(Hazard::FireHazard)
+            .insert(Hazard::TakePictures)
+            .insert(Hazard::SpendMoney);
+        let second = Hazards::new()
+            .insert(Hazard::SpendMoney)
+            .insert(Hazard::Explosion);
+
+        assert_eq!(
+            first.union(&second),
+            Hazards::new()
+                .insert(Hazard::FireHazard)
+                .insert(Hazard::TakePictures)
+                .insert(Hazard::SpendMoney)
+                .insert(Hazard::Explosion)
+        );
+        assert_eq!(
+            first.intersection(&second),
+            Hazards::init(Hazard::SpendMoney)
+        );
+        assert_eq!(
+            first.difference(&second),
+            Hazards::new()
+                .insert(Hazard::FireHazard)
+                .insert(Hazard::TakePictures)
+        );
+        assert_eq!(
+            first.union(&second).filter_by_category(Category::Safety),
+            Hazards::new()
+                .insert(Hazard::FireHazard)
+                .insert(Hazard::Explosion)
+        );
+        assert_eq!(
+            first.filter_by_category(Category::Privacy),
+            Hazards::init(Hazard::TakePictures)
+        );
+    }
+
+    #[test]
+    fn test_custom_hazard() {
+        // Identifiers of built-in hazards cannot be used.
+        assert_eq!(CustomHazard::new(6, Category::Safety), None);
+        assert!(
+            ALL_HAZARDS
+                .iter()
+                .all(|hazard| hazard.id() < CUSTOM_HAZARD_FIRST_ID)
+        );
+
+        let dosing = CustomHazard::new(1000, Category::Safety).unwrap();
+        let hazard = Hazard::Custom(dosing);
+
+        assert!(hazard.is_custom());
+        assert_eq!(hazard.id(), 1000);
+        assert_eq!(hazard.category(), Category::Safety);
+        assert_eq!(hazard.to_string(), "Custom Hazard 1000");
+        assert_eq!(Hazard::from_id(1000), None);
+
+        // Custom hazards are serialized alongside the built-in ones.
+        let hazards = Hazards::new().insert(Hazard::FireHazard).insert(hazard);
+        let value = serialize(&hazards);
+        assert_eq!(
+            value,
+            serde_json::json!([
+                "FireHazard",
+                { "Custom": { "id": 1000, "category": "Safety" } },
+            ])
+        );
+        assert_eq!(deserialize::<Hazards>(value), hazards);
+
+        // Reserved identifiers are rejected.
+        assert!(
+            serde_json::from_value::<Hazard>(serde_json::json!({
+                "Custom": { "id": 6, "category": "Safety" }
+            }))
+            .is_err()
+        );
+
+        let data = CustomHazardData::new(dosing, "Chemical Dosing", "Doses pool chemicals.");
+        let value = serialize(&data);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "id": 1000,
+                "category": "Safety",
+                "name": "Chemical Dosing",
+                "description": "Doses pool chemicals.",
+            })
+        );
+        assert_eq!(deserialize::<CustomHazardData>(value), data);
+    }
+
+    #[test]
+    fn test_hazard_levels() {
+        assert!(Severity::Negligible < Severity::Catastrophic);
+        assert!(Likelihood::Rare < Likelihood::Certain);
+
+        let levels = HazardLevels::new()
+            .insert(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .insert(
+                Hazard::SpoiledFood,
+                HazardLevel::new(Severity::Minor).likelihood(Likelihood::Unlikely),
+            );
+
+        let value = serialize(&levels);
+        assert_eq!(
+            value,
+            serde_json::json!([
+                { "hazard": "FireHazard", "level": { "severity": "High" } },
+                {
+                    "hazard": "SpoiledFood",
+                    "level": { "severity": "Minor", "likelihood": "Unlikely" },
+                },
+            ])
+        );
+        assert_eq!(deserialize::<HazardLevels>(value), levels);
+
+        // Levels of custom hazards are serialized too.
+        let custom =
+            Hazard::Custom(CustomHazard::new(CUSTOM_HAZARD_FIRST_ID, Category::Safety).unwrap());
+        let levels = HazardLevels::init(custom, HazardLevel::new(Severity::Catastrophic));
+        assert_eq!(deserialize::<HazardLevels>(serialize(&levels)), levels);
+    }
+
     #[test]
     fn test_category() {
         // Compare all categories.
         for category in ALL_CATEGORIES {
             assert_eq!(deserialize::<Category>(serialize(category)), *category);
+
+            for locale in ALL_LOCALES {
+                assert!(!category.localized_name(*locale).is_empty());
+                assert!(!category.localized_description(*locale).is_empty());
+            }
+            assert_eq!(category.localized_name(Locale::English), category.name());
+            assert_eq!(
+                category.localized_description(Locale::English),
+                category.description()
+            );
         }
     }
 }
@@ -24,6 +24,12 @@
 //!
 //! This crate can be used both on `std` and `no_std` environments. The `alloc`
 //! feature allows heap-allocations and it is enabled by default.
+//!
+//! The `schema` feature allows to describe route input parameters through
+//! `JSON Schema` documents, and to declare them from external ones.
+//!
+//! The `openapi` feature allows to describe the routes of a device through
+//! an `OpenAPI` document, so that generic HTTP tools can interact with it.
 
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
@@ -41,12 +47,22 @@ pub mod economy;
 pub mod energy;
 /// Hazards descriptions and methods.
 pub mod hazards;
+/// `OpenAPI` documents describing device routes.
+#[cfg(feature = "openapi")]
+pub mod openapi;
 /// Route input parameters.
 pub mod parameters;
+/// Protocol versioning among devices and controllers.
+pub mod protocol;
 /// All supported responses returned by a device action.
 pub mod response;
 /// Definition of device routes.
 pub mod route;
+/// `JSON Schema` descriptions of route input parameters.
+#[cfg(feature = "schema")]
+pub mod schema;
+/// Measurement units of route input parameters.
+pub mod units;
 
 #[cfg(test)]
 pub(crate) fn serialize<T: serde::Serialize>(value: T) -> serde_json::Value {
@@ -11,6 +11,14 @@ macro_rules! mandatory_route {
         }
 
         impl $name {
+            #[doc = "The route path."]
+            pub const PATH: &'static str = $path;
+
+            #[doc = "The allowed route `REST` methods."]
+            pub const METHODS: &'static [RestKind] = &[
+                $(mandatory_route!(@rest_kind $method)),*
+            ];
+
             $(
                 mandatory_route!(@method_fn $method, $name, $path);
             )*
@@ -53,6 +61,30 @@ macro_rules! mandatory_route {
                 self
             }
 
+            #[doc = concat!("Sets the [`HazardLevel`] of an [`Hazard`] of a [`", stringify!($name), "`].")]
+            #[must_use]
+            #[inline]
+            pub fn with_hazard_level(mut self, hazard: Hazard, level: HazardLevel) -> Self {
+                self.route = self.route.with_hazard_level(hazard, level);
+                self
+            }
+
+            #[doc = concat!("Adds an [`Hazard`] to a [`", stringify!($name), "`] which occurs only when the given [`Condition`] on an input parameter is met.")]
+            #[must_use]
+            #[inline]
+            pub fn with_conditional_hazard(mut self, hazard: Hazard, condition: Condition) -> Self {
+                self.route = self.route.with_conditional_hazard(hazard, condition);
+                self
+            }
+
+            #[doc = concat!("Adds a [`RouteTranslation`] for the given [`Locale`] to a [`", stringify!($name), "`].")]
+            #[must_use]
+            #[inline]
+            pub fn with_translation(mut self, locale: Locale, translation: RouteTranslation) -> Self {
+                self.route = self.route.with_translation(locale, translation);
+                self
+            }
+
             #[doc = concat!("Adds [`Parameters`] to a [`", stringify!($name), "`].")]
             #[must_use]
             #[inline]
@@ -93,6 +125,11 @@ macro_rules! mandatory_route {
         }
     };
 
+    (@rest_kind get) => { RestKind::Get };
+    (@rest_kind put) => { RestKind::Put };
+    (@rest_kind post) => { RestKind::Post };
+    (@rest_kind delete) => { RestKind::Delete };
+
     (@method_fn get, $name:ident, $path:expr) => {
         #[doc = concat!("Creates a new [`", stringify!($name), "`] through a REST `GET` API.")]
         #[must_use]
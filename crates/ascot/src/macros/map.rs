@@ -10,7 +10,7 @@ macro_rules! map {
 
         impl<'a> IntoIterator for &'a $name {
             type Item = (&'a $key, &'a $value);
-            type IntoIter = Iter<'a, $key, $value>;
+            type IntoIter = indexmap::map::Iter<'a, $key, $value>;
 
             fn into_iter(self) -> Self::IntoIter {
                 self.iter()
@@ -34,15 +34,15 @@ macro_rules! map {
             #[doc = concat!("Initializes [`", stringify!($name), "`] with a specific element.")]
             #[must_use]
             #[inline]
-            pub fn init(key: $key, value: $value) -> Self {
+            pub fn init(key: $key, value: impl Into<$value>) -> Self {
                 Self::new().insert(key, value)
             }
 
             #[doc = concat!("Inserts a new element into [`", stringify!($name), "`].")]
             #[must_use]
             #[inline]
-            pub fn insert(mut self, key: $key, value: $value) -> Self {
-                self.0.insert(key, value);
+            pub fn insert(mut self, key: $key, value: impl Into<$value>) -> Self {
+                self.0.insert(key, value.into());
                 self
             }
 
@@ -50,8 +50,8 @@ macro_rules! map {
             #[doc = ""]
             #[doc = concat!("Unlike [`Self::insert`], this method does not return a modified [`", stringify!($name), "`].")]
             #[inline]
-            pub fn add(&mut self, key: $key, value: $value) {
-                self.0.insert(key, value);
+            pub fn add(&mut self, key: $key, value: impl Into<$value>) {
+                self.0.insert(key, value.into());
             }
 
             #[doc = concat!("Checks if [`", stringify!($name), "`] is empty.")]
@@ -82,7 +82,7 @@ macro_rules! map {
             #[doc = "**Iterates over the elements in the order they were inserted.**"]
             #[must_use]
             #[inline]
-            pub fn iter(&self) -> Iter<'_, $key, $value> {
+            pub fn iter(&self) -> indexmap::map::Iter<'_, $key, $value> {
                 self.0.iter()
             }
         }
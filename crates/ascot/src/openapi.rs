@@ -0,0 +1,348 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use serde_json::{Map, Value, json};
+
+use crate::device::DeviceData;
+use crate::parameters::ParametersData;
+use crate::response::ResponseKind;
+use crate::route::{RestKind, RouteConfig};
+use crate::schema::{object_schema, parameter_schema};
+
+/// The `OpenAPI` specification version of the generated documents.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+// Builds the path of a route.
+//
+// `GET` routes receive their scalar parameters as path segments, as the
+// routes built by a device do, while lists and objects are passed through
+// the query string.
+fn route_path(main_route: &str, route_config: &RouteConfig) -> String {
+    let mut path = format!("{main_route}{}", route_config.data.path);
+
+    if matches!(route_config.rest_kind, RestKind::Get) {
+        for (name, _) in route_config
+            .data
+            .parameters
+            .iter()
+            .filter(|(_, parameter)| !parameter.kind.is_structured())
+        {
+            path.push_str("/{");
+            path.push_str(name);
+            path.push('}');
+        }
+    }
+
+    path
+}
+
+fn get_parameters(parameters: &ParametersData) -> Value {
+    parameters
+        .iter()
+        .map(|(name, parameter)| {
+            if parameter.kind.is_structured() {
+                // Structured parameters are encoded as JSON text.
+                json!({
+                    "name": name,
+                    "in": "query",
+                    "required": parameter.required,
+                    "content": {
+                        "application/json": { "schema": parameter_schema(parameter) }
+                    },
+                })
+            } else {
+                json!({
+                    "name": name,
+                    "in": "path",
+                    "required": true,
+                    "schema": parameter_schema(parameter),
+                })
+            }
+        })
+        .collect()
+}
+
+fn request_body(parameters: &ParametersData) -> Value {
+    json!({
+        "required": parameters.iter().any(|(_, parameter)| parameter.required),
+        "content": {
+            "application/json": { "schema": object_schema(parameters) }
+        }
+    })
+}
+
+fn success_response(response_kind: ResponseKind) -> Value {
+    match response_kind {
+        ResponseKind::Ok => json!({
+            "description": "The action terminated correctly.",
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "action_terminated_correctly": { "type": "boolean" }
+                        },
+                    }
+                }
+            }
+        }),
+        ResponseKind::Serial => json!({
+            "description": "The data produced by the action.",
+            "content": { "application/json": { "schema": {} } }
+        }),
+        ResponseKind::Info => json!({
+            "description": "The device energy and economy information.",
+            "content": { "application/json": { "schema": { "type": "object" } } }
+        }),
+        #[cfg(feature = "stream")]
+        ResponseKind::Stream => json!({
+            "description": "A stream of bytes.",
+            "content": {
+                "application/octet-stream": {
+                    "schema": { "type": "string", "contentMediaType": "application/octet-stream" }
+                }
+            }
+        }),
+    }
+}
+
+fn error_response() -> Value {
+    json!({
+        "description": "An error occurred during the action execution.",
+        "content": {
+            "application/json": {
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "error": { "type": "string", "enum": ["InvalidData", "Internal"] },
+                        "description": { "type": "string" },
+                        "info": { "type": ["string", "null"] },
+                    },
+                    "required": ["error", "description"],
+                }
+            }
+        }
+    })
+}
+
+fn operation(
+    device_data: &DeviceData,
+    route_config: &RouteConfig,
+) -> Result<Value, serde_json::Error> {
+    let mut operation = Map::new();
+
+    operation.insert("summary".into(), route_config.data.name.as_ref().into());
+    if let Some(description) = &route_config.data.description {
+        operation.insert("description".into(), description.as_ref().into());
+    }
+
+    if !route_config.data.parameters.is_empty() {
+        if matches!(route_config.rest_kind, RestKind::Get) {
+            operation.insert(
+                "parameters".into(),
+                get_parameters(&route_config.data.parameters),
+            );
+        } else {
+            operation.insert(
+                "requestBody".into(),
+                request_body(&route_config.data.parameters),
+            );
+        }
+    }
+
+    operation.insert(
+        "responses".into(),
+        json!({
+            "200": success_response(route_config.response_kind),
+            "default": error_response(),
+        }),
+    );
+
+    if !route_config.data.hazards.is_empty() {
+        operation.insert(
+            "x-ascot-hazards".into(),
+            serde_json::to_value(&route_config.data.hazards)?,
+        );
+    }
+    if !route_config.data.conditional_hazards.is_empty() {
+        operation.insert(
+            "x-ascot-conditional-hazards".into(),
+            serde_json::to_value(&route_config.data.conditional_hazards)?,
+        );
+    }
+    if !route_config.data.hazard_levels.is_empty() {
+        operation.insert(
+            "x-ascot-hazard-levels".into(),
+            serde_json::to_value(&route_config.data.hazard_levels)?,
+        );
+    }
+    if device_data
+        .kind
+        .requires_confirmation(&route_config.data.path)
+    {
+        operation.insert("x-ascot-confirmation".into(), true.into());
+    }
+
+    Ok(Value::Object(operation))
+}
+
+/// Builds an `OpenAPI` document describing all routes of a device.
+///
+/// Route hazards are described through the `x-ascot-hazards` extension,
+/// hazards occurring only under a condition on the route parameters through
+/// the `x-ascot-conditional-hazards` extension, and hazard levels through the
+/// `x-ascot-hazard-levels` extension, while
+/// routes requiring an explicit confirmation are marked through the
+/// `x-ascot-confirmation` extension.
+///
+/// # Errors
+///
+/// It returns an error when the hazards of a route cannot be serialized.
+pub fn openapi_document(device_data: &DeviceData) -> Result<Value, serde_json::Error> {
+    let mut paths = Map::new();
+
+    for route_config in &device_data.route_configs {
+        let path = route_path(&device_data.main_route, route_config);
+
+        let method = match route_config.rest_kind {
+            RestKind::Get => "get",
+            RestKind::Put => "put",
+            RestKind::Post => "post",
+            RestKind::Delete => "delete",
+        };
+
+        if let Value::Object(path_item) = paths
+            .entry(path)
+            .or_insert_with(|| Value::Object(Map::new()))
+        {
+            path_item.insert(method.into(), operation(device_data, route_config)?);
+        }
+    }
+
+    let mut info = Map::new();
+    info.insert(
+        "title".into(),
+        format!("{} device", device_data.kind).into(),
+    );
+    if let Some(description) = &device_data.description {
+        info.insert("description".into(), description.as_ref().into());
+    }
+    info.insert(
+        "version".into(),
+        device_data
+            .identity
+            .firmware_version
+            .as_deref()
+            .unwrap_or("unknown")
+            .into(),
+    );
+    info.insert(
+        "x-ascot-protocol-version".into(),
+        device_data.protocol_version.to_string().into(),
+    );
+
+    Ok(json!({
+        "openapi": OPENAPI_VERSION,
+        "info": info,
+        "paths": paths,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::device::{DeviceData, DeviceEnvironment, DeviceKind};
+    use crate::hazards::{Hazard, HazardLevel, Severity};
+    use crate::parameters::{Comparison, Condition, ParameterKind, Parameters};
+    use crate::route::{LightOffRoute, LightOnRoute, Route, RouteConfigs};
+
+    use super::{OPENAPI_VERSION, openapi_document};
+
+    fn light() -> DeviceData {
+        let route_configs = RouteConfigs::new()
+            .insert(
+                LightOnRoute::put("On")
+                    .description("Turn light on.")
+                    .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::Moderate))
+                    .with_conditional_hazard(
+                        Hazard::ElectricEnergyConsumption,
+                        Condition::new("brightness", Comparison::GreaterOrEqual, 10u64),
+                    )
+                    .with_parameters(Parameters::new().rangeu64("brightness", (0, 20, 1)))
+                    .into_route()
+                    .serialize_data(),
+            )
+            .insert(LightOffRoute::put("Off").into_route().serialize_data())
+            .insert(
+                Route::get("Toggle", "/toggle")
+                    .with_parameters(Parameters::new().u8("level", 3).list(
+                        "schedule",
+                        ParameterKind::U8 {
+                            default: 0,
+                            min: 0,
+                            max: u8::MAX,
+                        },
+                    ))
+                    .serialize_data(),
+            );
+
+        DeviceData::new(
+            DeviceKind::Light,
+            DeviceEnvironment::Os,
+            "/light",
+            route_configs,
+            None,
+            None,
+            2,
+        )
+        .description("A light device.")
+    }
+
+    #[test]
+    fn test_openapi_document() {
+        let document = openapi_document(&light()).unwrap();
+
+        assert_eq!(document["openapi"], OPENAPI_VERSION);
+        assert_eq!(document["info"]["title"], "Light device");
+        assert_eq!(document["info"]["description"], "A light device.");
+
+        // Hazards are exposed through an extension.
+        let on = &document["paths"]["/light/on"]["put"];
+        assert_eq!(on["summary"], "On");
+        assert_eq!(on["x-ascot-hazards"], json!(["FireHazard"]));
+        assert_eq!(on["x-ascot-hazard-levels"][0]["hazard"], "FireHazard");
+        assert_eq!(
+            on["x-ascot-hazard-levels"][0]["level"]["severity"],
+            "Moderate"
+        );
+        assert_eq!(
+            on["x-ascot-conditional-hazards"][0]["hazard"],
+            "ElectricEnergyConsumption"
+        );
+
+        // Parameters of non-`GET` routes are passed in the request body.
+        assert_eq!(
+            on["requestBody"]["content"]["application/json"]["schema"]["properties"]["brightness"]
+                ["maximum"],
+            20
+        );
+
+        // Scalar parameters of `GET` routes are path segments, while
+        // structured ones are passed through the query string.
+        let toggle = &document["paths"]["/light/toggle/{level}"]["get"];
+        assert_eq!(toggle["parameters"][0]["name"], "level");
+        assert_eq!(toggle["parameters"][0]["in"], "path");
+        assert_eq!(toggle["parameters"][1]["name"], "schedule");
+        assert_eq!(toggle["parameters"][1]["in"], "query");
+        assert_eq!(
+            toggle["parameters"][1]["content"]["application/json"]["schema"]["type"],
+            "array"
+        );
+
+        // Routes without parameters have neither parameters nor a body.
+        let off = &document["paths"]["/light/off"]["put"];
+        assert!(off.get("requestBody").is_none());
+        assert!(off.get("x-ascot-hazards").is_none());
+    }
+}
@@ -2,15 +2,20 @@
 #![allow(clippy::trivially_copy_pass_by_ref)]
 
 use alloc::borrow::Cow;
-use alloc::string::String;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use hashbrown::DefaultHashBuilder;
 
-use indexmap::map::{IndexMap, Iter};
+use indexmap::map::IndexMap;
+
+use log::error;
 
 use serde::{Deserialize, Serialize};
 
 use crate::macros::map;
+use crate::units::Unit;
 
 fn is_u8_max(value: &u8) -> bool {
     *value == u8::MAX
@@ -60,6 +65,70 @@ fn u64_max() -> u64 {
     u64::MAX
 }
 
+fn is_i8_max(value: &i8) -> bool {
+    *value == i8::MAX
+}
+
+fn is_i8_min(value: &i8) -> bool {
+    *value == i8::MIN
+}
+
+fn i8_min() -> i8 {
+    i8::MIN
+}
+
+fn i8_max() -> i8 {
+    i8::MAX
+}
+
+fn is_i16_max(value: &i16) -> bool {
+    *value == i16::MAX
+}
+
+fn is_i16_min(value: &i16) -> bool {
+    *value == i16::MIN
+}
+
+fn i16_min() -> i16 {
+    i16::MIN
+}
+
+fn i16_max() -> i16 {
+    i16::MAX
+}
+
+fn is_i32_max(value: &i32) -> bool {
+    *value == i32::MAX
+}
+
+fn is_i32_min(value: &i32) -> bool {
+    *value == i32::MIN
+}
+
+fn i32_min() -> i32 {
+    i32::MIN
+}
+
+fn i32_max() -> i32 {
+    i32::MAX
+}
+
+fn is_i64_max(value: &i64) -> bool {
+    *value == i64::MAX
+}
+
+fn is_i64_min(value: &i64) -> bool {
+    *value == i64::MIN
+}
+
+fn i64_min() -> i64 {
+    i64::MIN
+}
+
+fn i64_max() -> i64 {
+    i64::MAX
+}
+
 fn is_f32_max(value: &f32) -> bool {
     *value == f32::MAX
 }
@@ -92,6 +161,22 @@ fn f64_max() -> f64 {
     f64::MAX
 }
 
+fn is_usize_max(value: &usize) -> bool {
+    *value == usize::MAX
+}
+
+fn is_usize_min(value: &usize) -> bool {
+    *value == usize::MIN
+}
+
+fn usize_max() -> usize {
+    usize::MAX
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 /// All route input parameters identifiers.
 pub enum ParameterId {
@@ -107,6 +192,16 @@ pub enum ParameterId {
     U64,
     /// A range of [`u64`].
     RangeU64,
+    /// An [`i8`] value.
+    I8,
+    /// An [`i16`] value.
+    I16,
+    /// An [`i32`] value.
+    I32,
+    /// An [`i64`] value.
+    I64,
+    /// A range of [`i64`].
+    RangeI64,
     /// A [`f32`] value.
     F32,
     /// A [`f64`] value.
@@ -115,6 +210,14 @@ pub enum ParameterId {
     RangeF64,
     /// A characters sequence.
     CharsSequence,
+    /// A choice among a set of labels.
+    Choice,
+    /// A [`Color`] value.
+    Color,
+    /// A list of values.
+    List,
+    /// An object composed of named fields.
+    Object,
 }
 
 impl ParameterId {
@@ -128,10 +231,19 @@ impl ParameterId {
             ParameterKind::U32 { .. } => Self::U32,
             ParameterKind::U64 { .. } => Self::U64,
             ParameterKind::RangeU64 { .. } => Self::RangeU64,
+            ParameterKind::I8 { .. } => Self::I8,
+            ParameterKind::I16 { .. } => Self::I16,
+            ParameterKind::I32 { .. } => Self::I32,
+            ParameterKind::I64 { .. } => Self::I64,
+            ParameterKind::RangeI64 { .. } => Self::RangeI64,
             ParameterKind::F32 { .. } => Self::F32,
             ParameterKind::F64 { .. } => Self::F64,
             ParameterKind::RangeF64 { .. } => Self::RangeF64,
             ParameterKind::CharsSequence { .. } => Self::CharsSequence,
+            ParameterKind::Choice { .. } => Self::Choice,
+            ParameterKind::Color { .. } => Self::Color,
+            ParameterKind::List { .. } => Self::List,
+            ParameterKind::Object { .. } => Self::Object,
         }
     }
 
@@ -145,10 +257,19 @@ impl ParameterId {
             Self::U32 => "U32",
             Self::U64 => "U64",
             Self::RangeU64 => "RangeU64",
+            Self::I8 => "I8",
+            Self::I16 => "I16",
+            Self::I32 => "I32",
+            Self::I64 => "I64",
+            Self::RangeI64 => "RangeI64",
             Self::F32 => "F32",
             Self::F64 => "F64",
             Self::RangeF64 => "RangeF64",
             Self::CharsSequence => "String",
+            Self::Choice => "Choice",
+            Self::Color => "Color",
+            Self::List => "List",
+            Self::Object => "Object",
         }
     }
 
@@ -162,13 +283,43 @@ impl ParameterId {
             Self::U16 => "u16",
             Self::U32 => "u32",
             Self::U64 | Self::RangeU64 => "u64",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 | Self::RangeI64 => "i64",
             Self::F32 => "f32",
             Self::F64 | Self::RangeF64 => "f64",
-            Self::CharsSequence => "String",
+            Self::CharsSequence | Self::Choice => "String",
+            Self::Color => "Color",
+            Self::List => "List",
+            Self::Object => "Object",
         }
     }
 }
 
+// The serialized form of a choice, before checking its default value.
+#[derive(Deserialize)]
+struct UncheckedChoice {
+    default: Cow<'static, str>,
+    choices: Vec<Cow<'static, str>>,
+}
+
+type ChoiceFields = (Cow<'static, str>, Vec<Cow<'static, str>>);
+
+fn deserialize_choice<'de, D>(deserializer: D) -> Result<ChoiceFields, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let choice = UncheckedChoice::deserialize(deserializer)?;
+    if choice.choices.contains(&choice.default) {
+        Ok((choice.default, choice.choices))
+    } else {
+        Err(serde::de::Error::custom(
+            "the default value of a choice must be one of its labels",
+        ))
+    }
+}
+
 /// All supported kinds of route input parameters.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ParameterKind {
@@ -234,6 +385,62 @@ pub enum ParameterKind {
         #[serde(default = "u64_max")]
         max: u64,
     },
+    /// An [`i8`] value.
+    I8 {
+        /// The initial [`i8`] value, but also the default one
+        /// in case of a missing input parameter.
+        default: i8,
+        /// The minimum [`i8`] value allowed.
+        #[serde(skip_serializing_if = "is_i8_max")]
+        #[serde(default = "i8_min")]
+        min: i8,
+        /// The maximum [`i8`] value allowed.
+        #[serde(skip_serializing_if = "is_i8_min")]
+        #[serde(default = "i8_max")]
+        max: i8,
+    },
+    /// An [`i16`] value.
+    I16 {
+        /// The initial [`i16`] value, but also the default one
+        /// in case of a missing input parameter.
+        default: i16,
+        /// The minimum [`i16`] value allowed.
+        #[serde(skip_serializing_if = "is_i16_max")]
+        #[serde(default = "i16_min")]
+        min: i16,
+        /// The maximum [`i16`] value allowed.
+        #[serde(skip_serializing_if = "is_i16_min")]
+        #[serde(default = "i16_max")]
+        max: i16,
+    },
+    /// An [`i32`] value.
+    I32 {
+        /// The initial [`i32`] value, but also the default one
+        /// in case of a missing input parameter.
+        default: i32,
+        /// The minimum [`i32`] value allowed.
+        #[serde(skip_serializing_if = "is_i32_max")]
+        #[serde(default = "i32_min")]
+        min: i32,
+        /// The maximum [`i32`] value allowed.
+        #[serde(skip_serializing_if = "is_i32_min")]
+        #[serde(default = "i32_max")]
+        max: i32,
+    },
+    /// An [`i64`] value.
+    I64 {
+        /// The initial [`i64`] value, but also the default one
+        /// in case of a missing input parameter.
+        default: i64,
+        /// The minimum [`i64`] value allowed.
+        #[serde(skip_serializing_if = "is_i64_max")]
+        #[serde(default = "i64_min")]
+        min: i64,
+        /// The maximum [`i64`] value allowed.
+        #[serde(skip_serializing_if = "is_i64_min")]
+        #[serde(default = "i64_max")]
+        max: i64,
+    },
     /// A [`f32`] value.
     F32 {
         /// The initial [`f32`] value, but also the default one
@@ -282,6 +489,18 @@ pub enum ParameterKind {
         /// Initial [`u64`] range value.
         default: u64,
     },
+    /// A range of [`i64`] values.
+    RangeI64 {
+        /// Minimum [`i64`] value allowed.
+        min: i64,
+        /// Maximum [`i64`] value allowed.
+        max: i64,
+        /// The [`i64`] step necessary to pass from one allowed value
+        /// to another one in the range. It is always a positive value.
+        step: i64,
+        /// Initial [`i64`] range value.
+        default: i64,
+    },
     /// A range of [`f64`] values.
     RangeF64 {
         /// The minimum [`f64`] value allowed.
@@ -302,9 +521,61 @@ pub enum ParameterKind {
         /// Initial characters sequence, which also represents the default
         /// value.
         default: Cow<'static, str>,
-        /// The character sequence length.
+        /// The maximum number of characters of a sequence.
+        ///
+        /// A length of `0` admits sequences of any length.
         length: usize,
     },
+    /// A choice among a set of labels.
+    ///
+    /// A choice whose default value is not among its labels is rejected
+    /// when deserialized.
+    #[serde(deserialize_with = "deserialize_choice")]
+    Choice {
+        /// Initial label, which also represents the default value.
+        default: Cow<'static, str>,
+        /// All allowed labels.
+        choices: Vec<Cow<'static, str>>,
+    },
+    /// A [`Color`] value.
+    ///
+    /// All values must adopt the same [`ColorModel`] of the default value.
+    Color {
+        /// The initial [`Color`] value, but also the default one
+        /// in case of a missing input parameter.
+        default: Color,
+        /// The minimum color temperature allowed, expressed in Kelvin.
+        #[serde(skip_serializing_if = "is_u16_max")]
+        #[serde(default)]
+        min_kelvin: u16,
+        /// The maximum color temperature allowed, expressed in Kelvin.
+        #[serde(skip_serializing_if = "is_u16_min")]
+        #[serde(default = "u16_max")]
+        max_kelvin: u16,
+    },
+    /// A list of values of the same kind.
+    ///
+    /// Its default value is composed of `min_len` default items.
+    List {
+        /// The kind of each item.
+        item: Box<ParameterKind>,
+        /// Minimum number of items.
+        #[serde(skip_serializing_if = "is_usize_min")]
+        #[serde(default)]
+        min_len: usize,
+        /// Maximum number of items.
+        #[serde(skip_serializing_if = "is_usize_max")]
+        #[serde(default = "usize_max")]
+        max_len: usize,
+    },
+    /// An object composed of named fields.
+    ///
+    /// All fields must be present in a value. Its default value is
+    /// composed of the default values of its fields.
+    Object {
+        /// Object fields.
+        fields: ParametersData,
+    },
 }
 
 /// Floating point decimal precision.
@@ -340,203 +611,1521 @@ impl DecimalPrecision {
     }
 }
 
-map! {
-  /// A map of serializable and deserializable [`Parameters`] data.
-  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-  pub struct ParametersData(IndexMap<String, ParameterKind, DefaultHashBuilder>);
+/// All supported color models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorModel {
+    /// Red, green, and blue components.
+    Rgb,
+    /// Hue, saturation, and value components.
+    Hsv,
+    /// Color temperature.
+    Kelvin,
 }
 
-/// Route input parameters.
-#[derive(Debug, Clone)]
-pub struct Parameters(IndexMap<&'static str, ParameterKind, DefaultHashBuilder>);
-
-impl Default for Parameters {
-    fn default() -> Self {
-        Self::new()
+impl ColorModel {
+    /// Shows a [`ColorModel`] as a [`&str`].
+    #[must_use]
+    pub const fn to_str(&self) -> &'static str {
+        match self {
+            Self::Rgb => "rgb",
+            Self::Hsv => "hsv",
+            Self::Kelvin => "kelvin",
+        }
     }
 }
 
-impl Parameters {
-    /// Creates a [`Parameters`].
-    #[must_use]
-    #[inline]
-    pub fn new() -> Self {
-        Self(IndexMap::with_hasher(DefaultHashBuilder::default()))
-    }
+/// An error in parsing a [`Color`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseColorError(String);
 
-    /// Adds a [`bool`] parameter.
-    #[must_use]
-    #[inline]
-    pub fn bool(self, name: &'static str, default: bool) -> Self {
-        self.create_parameter(name, ParameterKind::Bool { default })
+impl core::fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "`{}` is not a valid color", self.0)
     }
+}
 
-    /// Adds an [`u8`] parameter.
+/// A color.
+///
+/// On the wire, a color is encoded as a characters sequence composed of the
+/// [`ColorModel`] name followed by its components, such as `rgb:255,0,0`,
+/// `hsv:120,100,50`, or `kelvin:2700`. The hexadecimal `#rrggbb` notation
+/// is accepted as input for RGB colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Color {
+    /// An RGB color.
+    Rgb {
+        /// Red component.
+        red: u8,
+        /// Green component.
+        green: u8,
+        /// Blue component.
+        blue: u8,
+    },
+    /// An HSV color.
+    Hsv {
+        /// Hue component, in degrees from 0 to 359.
+        hue: u16,
+        /// Saturation component, in percentage.
+        saturation: u8,
+        /// Value component, in percentage.
+        value: u8,
+    },
+    /// A color temperature, expressed in Kelvin.
+    Kelvin(u16),
+}
+
+impl Color {
+    /// Creates an RGB [`Color`].
     #[must_use]
-    #[inline]
-    pub fn u8(self, name: &'static str, default: u8) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::U8 {
-                default,
-                min: u8::MAX,
-                max: u8::MIN,
-            },
-        )
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::Rgb { red, green, blue }
     }
 
-    /// Adds an [`u8`] parameter with limits.
+    /// Creates an HSV [`Color`].
+    ///
+    /// If [`None`], the components are out of their ranges.
     #[must_use]
-    #[inline]
-    pub fn u8_with_limits(self, name: &'static str, default: u8, min: u8, max: u8) -> Self {
-        self.create_parameter(name, ParameterKind::U8 { default, min, max })
+    pub const fn hsv(hue: u16, saturation: u8, value: u8) -> Option<Self> {
+        let color = Self::Hsv {
+            hue,
+            saturation,
+            value,
+        };
+        if color.is_valid() { Some(color) } else { None }
     }
 
-    /// Adds an [`u16`] parameter.
+    /// Creates a color temperature [`Color`].
     #[must_use]
-    #[inline]
-    pub fn u16(self, name: &'static str, default: u16) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::U16 {
-                default,
-                min: u16::MAX,
-                max: u16::MIN,
-            },
-        )
+    pub const fn kelvin(kelvin: u16) -> Self {
+        Self::Kelvin(kelvin)
     }
 
-    /// Adds an [`u16`] parameter with limits.
+    /// Returns the [`ColorModel`].
     #[must_use]
-    #[inline]
-    pub fn u16_with_limits(self, name: &'static str, default: u16, min: u16, max: u16) -> Self {
-        self.create_parameter(name, ParameterKind::U16 { default, min, max })
+    pub const fn model(&self) -> ColorModel {
+        match self {
+            Self::Rgb { .. } => ColorModel::Rgb,
+            Self::Hsv { .. } => ColorModel::Hsv,
+            Self::Kelvin(_) => ColorModel::Kelvin,
+        }
     }
 
-    /// Adds an [`u32`] parameter.
+    /// Checks whether all color components are within their ranges.
     #[must_use]
-    #[inline]
-    pub fn u32(self, name: &'static str, default: u32) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::U32 {
-                default,
-                min: u32::MAX,
-                max: u32::MIN,
-            },
-        )
+    pub const fn is_valid(&self) -> bool {
+        match self {
+            Self::Hsv {
+                hue,
+                saturation,
+                value,
+            } => *hue < 360 && *saturation <= 100 && *value <= 100,
+            Self::Rgb { .. } | Self::Kelvin(_) => true,
+        }
     }
 
-    /// Adds an [`u32`] parameter with limits.
-    #[must_use]
-    #[inline]
-    pub fn u32_with_limits(self, name: &'static str, default: u32, min: u32, max: u32) -> Self {
-        self.create_parameter(name, ParameterKind::U32 { default, min, max })
+    fn parse_components<const N: usize>(components: &str) -> Option<[u16; N]> {
+        let mut values = [0; N];
+        let mut components = components.split(',');
+        for value in &mut values {
+            *value = components.next()?.trim().parse().ok()?;
+        }
+        components.next().is_none().then_some(values)
     }
 
-    /// Adds an [`u64`] parameter.
-    #[must_use]
-    #[inline]
-    pub fn u64(self, name: &'static str, default: u64) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::U64 {
-                default,
-                min: u64::MAX,
-                max: u64::MIN,
-            },
-        )
+    fn parse_hex(hex: &str) -> Option<Self> {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let component = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+        Some(Self::rgb(component(0)?, component(2)?, component(4)?))
     }
+}
 
-    /// Adds an [`u64`] parameter with limits.
-    #[must_use]
-    #[inline]
-    pub fn u64_with_limits(self, name: &'static str, default: u64, min: u64, max: u64) -> Self {
-        self.create_parameter(name, ParameterKind::U64 { default, min, max })
+impl core::fmt::Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Rgb { red, green, blue } => write!(f, "rgb:{red},{green},{blue}"),
+            Self::Hsv {
+                hue,
+                saturation,
+                value,
+            } => write!(f, "hsv:{hue},{saturation},{value}"),
+            Self::Kelvin(kelvin) => write!(f, "kelvin:{kelvin}"),
+        }
     }
+}
 
-    /// Adds a [`f32`] parameter.
-    #[must_use]
-    #[inline]
-    pub fn f32(self, name: &'static str, default: f32) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::F32 {
-                default,
-                min: f32::MAX,
-                max: f32::MIN,
-                step: 0.,
-            },
-        )
+impl core::str::FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let color = if let Some(hex) = value.strip_prefix('#') {
+            Self::parse_hex(hex)
+        } else {
+            value
+                .split_once(':')
+                .and_then(|(model, components)| match model {
+                    "rgb" => Self::parse_components::<3>(components).and_then(|[r, g, b]| {
+                        Some(Self::rgb(
+                            u8::try_from(r).ok()?,
+                            u8::try_from(g).ok()?,
+                            u8::try_from(b).ok()?,
+                        ))
+                    }),
+                    "hsv" => Self::parse_components::<3>(components).and_then(|[h, s, v]| {
+                        Self::hsv(h, u8::try_from(s).ok()?, u8::try_from(v).ok()?)
+                    }),
+                    "kelvin" => Self::parse_components::<1>(components).map(|[k]| Self::kelvin(k)),
+                    _ => None,
+                })
+        };
+
+        color.ok_or_else(|| ParseColorError(value.into()))
     }
+}
 
-    /// Adds a [`f32`] parameter with limits.
-    #[must_use]
-    #[inline]
-    pub fn f32_with_limits(
-        self,
-        name: &'static str,
-        default: f32,
-        min: f32,
-        max: f32,
-        decimal_precision: DecimalPrecision,
-    ) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::F32 {
-                default,
-                min,
-                max,
-                step: decimal_precision.to_f32(),
-            },
-        )
+impl From<Color> for String {
+    fn from(color: Color) -> Self {
+        color.to_string()
     }
+}
 
-    /// Adds a [`f64`] parameter.
-    #[must_use]
-    #[inline]
-    pub fn f64(self, name: &'static str, default: f64) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::F64 {
-                default,
-                min: f64::MAX,
-                max: f64::MIN,
-                step: 0.,
-            },
-        )
+impl TryFrom<String> for Color {
+    type Error = ParseColorError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
     }
+}
 
-    /// Adds a [`f64`] parameter with limits.
-    #[must_use]
-    #[inline]
-    pub fn f64_with_limits(
-        self,
-        name: &'static str,
-        default: f64,
-        min: f64,
-        max: f64,
-        decimal_precision: DecimalPrecision,
-    ) -> Self {
-        self.create_parameter(
-            name,
-            ParameterKind::F64 {
-                default,
-                min,
-                max,
-                step: decimal_precision.to_f64(),
-            },
-        )
+// Checks whether a value is contained in the closed interval [min, max].
+//
+// When `min` is greater than `max`, the parameter has been declared without
+// limits, so all values are admitted.
+pub(crate) fn in_interval<T: PartialOrd + Copy>(value: T, min: T, max: T) -> bool {
+    min > max || (min <= value && value <= max)
+}
+
+// Checks whether a floating point value can be reached from `origin`
+// adding `step` an integer number of times.
+//
+// The comparison tolerates the rounding errors introduced by the
+// floating point representation.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn is_aligned(value: f64, origin: f64, step: f64, epsilon: f64) -> bool {
+    let steps = (value - origin) / step;
+    let steps = if steps < 0. { -steps } else { steps };
+    // Rounding is not available in `core`, hence the nearest integer is
+    // obtained through a cast.
+    let nearest = (steps + 0.5) as u64 as f64;
+    let difference = if steps > nearest {
+        steps - nearest
+    } else {
+        nearest - steps
+    };
+    difference <= epsilon * if steps > 1. { steps } else { 1. }
+}
+
+// Checks whether a value is contained in the interval declared by a
+// parameter.
+fn bounded<T>(value: T, min: T, max: T) -> Result<(), Violation>
+where
+    T: PartialOrd + Copy + Into<ParameterValue>,
+{
+    if in_interval(value, min, max) {
+        Ok(())
+    } else {
+        Err(Violation::OutOfRange {
+            min: min.into(),
+            max: max.into(),
+        })
     }
+}
 
-    /// Adds an [`u64`] range without a default value.
-    #[must_use]
-    #[inline]
-    pub fn rangeu64(self, name: &'static str, range: (u64, u64, u64)) -> Self {
-        self.rangeu64_with_default(name, range, 0)
+fn validate_color(
+    value: Color,
+    default: Color,
+    min_kelvin: u16,
+    max_kelvin: u16,
+) -> Result<(), Violation> {
+    if value.model() != default.model() {
+        return Err(Violation::WrongColorModel {
+            expected: default.model(),
+        });
     }
 
-    /// Adds an [`u64`] range with a default value.
+    match value {
+        Color::Kelvin(kelvin) if !in_interval(kelvin, min_kelvin, max_kelvin) => {
+            Err(Violation::OutOfRange {
+                min: Color::Kelvin(min_kelvin).into(),
+                max: Color::Kelvin(max_kelvin).into(),
+            })
+        }
+        _ if !value.is_valid() => Err(Violation::InvalidColor),
+        _ => Ok(()),
+    }
+}
+
+/// A route input parameter value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterValue {
+    /// A [`bool`] value.
+    Bool(bool),
+    /// An [`u8`] value.
+    U8(u8),
+    /// An [`u16`] value.
+    U16(u16),
+    /// An [`u32`] value.
+    U32(u32),
+    /// An [`u64`] value.
+    U64(u64),
+    /// An [`i8`] value.
+    I8(i8),
+    /// An [`i16`] value.
+    I16(i16),
+    /// An [`i32`] value.
+    I32(i32),
+    /// An [`i64`] value.
+    I64(i64),
+    /// A [`f32`] value.
+    F32(f32),
+    /// A [`f64`] value.
+    F64(f64),
+    /// A characters sequence.
+    CharsSequence(String),
+    /// A [`Color`] value.
+    Color(Color),
+    /// A list of values.
+    List(Box<[ParameterValue]>),
+    /// An object composed of named values, in order.
+    Object(Box<[(String, ParameterValue)]>),
+}
+
+impl core::fmt::Display for ParameterValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Bool(v) => v.fmt(f),
+            Self::U8(v) => v.fmt(f),
+            Self::U16(v) => v.fmt(f),
+            Self::U32(v) => v.fmt(f),
+            Self::U64(v) => v.fmt(f),
+            Self::I8(v) => v.fmt(f),
+            Self::I16(v) => v.fmt(f),
+            Self::I32(v) => v.fmt(f),
+            Self::I64(v) => v.fmt(f),
+            Self::F32(v) => v.fmt(f),
+            Self::F64(v) => v.fmt(f),
+            Self::CharsSequence(v) => v.fmt(f),
+            Self::Color(v) => v.fmt(f),
+            Self::List(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    value.fmt(f)?;
+                }
+                write!(f, "]")
+            }
+            Self::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (name, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name}: {value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+macro_rules! parameter_value_from {
+    ($($ty:ty => $variant:ident),+) => {
+        $(
+            impl From<$ty> for ParameterValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )+
+    };
+}
+
+parameter_value_from!(
+    bool => Bool,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    f32 => F32,
+    f64 => F64,
+    String => CharsSequence,
+    Color => Color,
+    &str => CharsSequence
+);
+
+impl ParameterValue {
+    /// Returns the default [`ParameterValue`] of a [`ParameterKind`].
+    #[must_use]
+    pub fn from_parameter_kind(parameter_kind: &ParameterKind) -> Self {
+        match parameter_kind {
+            ParameterKind::Bool { default } => Self::Bool(*default),
+            ParameterKind::U8 { default, .. } => Self::U8(*default),
+            ParameterKind::U16 { default, .. } => Self::U16(*default),
+            ParameterKind::U32 { default, .. } => Self::U32(*default),
+            ParameterKind::U64 { default, .. } | ParameterKind::RangeU64 { default, .. } => {
+                Self::U64(*default)
+            }
+            ParameterKind::I8 { default, .. } => Self::I8(*default),
+            ParameterKind::I16 { default, .. } => Self::I16(*default),
+            ParameterKind::I32 { default, .. } => Self::I32(*default),
+            ParameterKind::I64 { default, .. } | ParameterKind::RangeI64 { default, .. } => {
+                Self::I64(*default)
+            }
+            ParameterKind::F32 { default, .. } => Self::F32(*default),
+            ParameterKind::F64 { default, .. } | ParameterKind::RangeF64 { default, .. } => {
+                Self::F64(*default)
+            }
+            ParameterKind::CharsSequence { default, .. }
+            | ParameterKind::Choice { default, .. } => Self::CharsSequence(default.as_ref().into()),
+            ParameterKind::Color { default, .. } => Self::Color(*default),
+            ParameterKind::List { item, min_len, .. } => Self::List(
+                core::iter::repeat_n(Self::from_parameter_kind(item), *min_len).collect(),
+            ),
+            ParameterKind::Object { fields } => Self::Object(
+                fields
+                    .iter()
+                    .map(|(name, field)| (name.clone(), Self::from_parameter_kind(&field.kind)))
+                    .collect(),
+            ),
+        }
+    }
+
+    // Returns the value of a number.
+    //
+    // If [`None`], the value is not a number.
+    #[allow(clippy::cast_precision_loss)]
+    fn number(&self) -> Option<f64> {
+        match self {
+            Self::U8(v) => Some(f64::from(*v)),
+            Self::U16(v) => Some(f64::from(*v)),
+            Self::U32(v) => Some(f64::from(*v)),
+            Self::U64(v) => Some(*v as f64),
+            Self::I8(v) => Some(f64::from(*v)),
+            Self::I16(v) => Some(f64::from(*v)),
+            Self::I32(v) => Some(f64::from(*v)),
+            Self::I64(v) => Some(*v as f64),
+            Self::F32(v) => Some(f64::from(*v)),
+            Self::F64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+/// A violation of the rules declared by a [`ParameterKind`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// The parameter is not declared.
+    Undeclared,
+    /// The value type does not match the parameter type.
+    WrongType {
+        /// Expected parameter.
+        expected: ParameterId,
+    },
+    /// The value is outside of the closed interval `[min, max]`.
+    OutOfRange {
+        /// Minimum value allowed.
+        min: ParameterValue,
+        /// Maximum value allowed.
+        max: ParameterValue,
+    },
+    /// The value cannot be reached adding `step` to `origin` an integer
+    /// number of times.
+    Misaligned {
+        /// Initial value.
+        origin: ParameterValue,
+        /// Step value.
+        step: ParameterValue,
+    },
+    /// The characters sequence exceeds the maximum length.
+    TooLong {
+        /// Maximum number of characters.
+        length: usize,
+    },
+    /// The label is not among the allowed ones.
+    InvalidChoice {
+        /// All allowed labels.
+        choices: Vec<Cow<'static, str>>,
+    },
+    /// The color adopts a different color model.
+    WrongColorModel {
+        /// Expected color model.
+        expected: ColorModel,
+    },
+    /// The color components are outside of their ranges.
+    InvalidColor,
+    /// The number of items of a list is outside of `[min_len, max_len]`.
+    WrongLength {
+        /// Minimum number of items.
+        min_len: usize,
+        /// Maximum number of items.
+        max_len: usize,
+    },
+    /// An object field is missing.
+    MissingField {
+        /// Field name.
+        field: String,
+    },
+    /// The parameter must be supplied.
+    Required,
+    /// The parameter must be supplied together with another parameter.
+    Requires {
+        /// Name of the other parameter.
+        parameter: Cow<'static, str>,
+    },
+    /// The parameter cannot be supplied together with another parameter.
+    Excludes {
+        /// Name of the other parameter.
+        parameter: Cow<'static, str>,
+    },
+    /// The value is expressed in a unit which cannot be converted into the
+    /// unit of the parameter.
+    IncompatibleUnit {
+        /// Unit of the value.
+        unit: Unit,
+        /// Unit of the parameter.
+        ///
+        /// If [`None`], the parameter has no unit.
+        expected: Option<Unit>,
+    },
+    /// The parameter is supplied while its enabling condition is not met.
+    Disabled {
+        /// Enabling condition.
+        condition: Condition,
+    },
+    /// An item of a list or a field of an object is invalid.
+    ///
+    /// The name of the inner [`ValidationError`] is the path of the
+    /// invalid value, relative to the parameter, such as `[2]` or `.width`.
+    Nested(Box<ValidationError>),
+}
+
+/// A parameter validation error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// Parameter name.
+    pub name: String,
+    /// Offending value.
+    pub value: ParameterValue,
+    /// Violated rule.
+    pub violation: Violation,
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let Self {
+            name,
+            value,
+            violation,
+        } = self;
+        match violation {
+            Violation::Undeclared => write!(f, "`{name}` does not exist"),
+            Violation::WrongType { expected } => {
+                write!(f, "`{name}` must be of type `{}`", expected.as_type())
+            }
+            Violation::OutOfRange { min, max } => {
+                write!(f, "`{name}` value `{value}` is outside of [{min}, {max}]")
+            }
+            Violation::Misaligned { origin, step } => write!(
+                f,
+                "`{name}` value `{value}` is not reachable from `{origin}` with step `{step}`"
+            ),
+            Violation::TooLong { length } => write!(
+                f,
+                "`{name}` value `{value}` is longer than {length} characters"
+            ),
+            Violation::InvalidChoice { choices } => {
+                write!(f, "`{name}` value `{value}` is not one of [")?;
+                for (index, choice) in choices.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{choice}")?;
+                }
+                write!(f, "]")
+            }
+            Violation::WrongColorModel { expected } => write!(
+                f,
+                "`{name}` value `{value}` must adopt the `{}` color model",
+                expected.to_str()
+            ),
+            Violation::InvalidColor => {
+                write!(f, "`{name}` value `{value}` has components out of range")
+            }
+            Violation::WrongLength { min_len, max_len } => write!(
+                f,
+                "`{name}` value `{value}` has a length outside of [{min_len}, {max_len}]"
+            ),
+            Violation::MissingField { field } => {
+                write!(f, "`{name}` value `{value}` misses the `{field}` field")
+            }
+            Violation::IncompatibleUnit { unit, expected } => match expected {
+                Some(expected) => write!(
+                    f,
+                    "`{name}` value `{value}` cannot be converted from `{unit}` into `{expected}`"
+                ),
+                None => write!(
+                    f,
+                    "`{name}` value `{value}` cannot be converted from `{unit}`, since `{name}` has no unit"
+                ),
+            },
+            Violation::Required => write!(f, "`{name}` is required"),
+            Violation::Requires { parameter } => {
+                write!(f, "`{name}` requires `{parameter}`")
+            }
+            Violation::Excludes { parameter } => {
+                write!(f, "`{name}` cannot be supplied together with `{parameter}`")
+            }
+            Violation::Disabled { condition } => {
+                write!(f, "`{name}` is only allowed when {condition}")
+            }
+            Violation::Nested(error) => error.prefixed(name).fmt(f),
+        }
+    }
+}
+
+impl ValidationError {
+    // Prepends a path segment to the name of the error.
+    fn prefixed(&self, segment: &str) -> Self {
+        let mut error = self.clone();
+        error.name.insert_str(0, segment);
+        error
+    }
+}
+
+// Wraps a violation of an item or a field, found at the given path segment.
+fn nested(segment: &str, value: &ParameterValue, violation: Violation) -> Violation {
+    Violation::Nested(Box::new(match violation {
+        Violation::Nested(error) => error.prefixed(segment),
+        violation => ValidationError {
+            name: segment.into(),
+            value: value.clone(),
+            violation,
+        },
+    }))
+}
+
+fn validate_choice(value: &str, choices: &[Cow<'static, str>]) -> Result<(), Violation> {
+    if choices.iter().any(|choice| choice == value) {
+        Ok(())
+    } else {
+        Err(Violation::InvalidChoice {
+            choices: choices.to_vec(),
+        })
+    }
+}
+
+fn validate_list(
+    values: &[ParameterValue],
+    item: &ParameterKind,
+    min_len: usize,
+    max_len: usize,
+) -> Result<(), Violation> {
+    if !in_interval(values.len(), min_len, max_len) {
+        return Err(Violation::WrongLength { min_len, max_len });
+    }
+
+    for (index, value) in values.iter().enumerate() {
+        item.validate(value)
+            .map_err(|violation| nested(&alloc::format!("[{index}]"), value, violation))?;
+    }
+    Ok(())
+}
+
+fn validate_object(
+    values: &[(String, ParameterValue)],
+    fields: &ParametersData,
+) -> Result<(), Violation> {
+    fields
+        .validate(values.iter().map(|(name, value)| (name, value)))
+        .map_err(|error| Violation::Nested(Box::new(error.prefixed("."))))?;
+
+    match fields
+        .iter()
+        .find(|(field, _)| !values.iter().any(|(name, _)| name == *field))
+    {
+        Some((field, _)) => Err(Violation::MissingField {
+            field: field.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+impl ParameterKind {
+    /// Checks whether a [`ParameterKind`] is structured, hence whether it
+    /// is a list or an object.
+    #[must_use]
+    pub const fn is_structured(&self) -> bool {
+        matches!(self, Self::List { .. } | Self::Object { .. })
+    }
+
+    /// Validates a [`ParameterValue`] against the rules declared by
+    /// a [`ParameterKind`].
+    ///
+    /// A parameter declared without limits admits all values of its type,
+    /// while a characters sequence with a length of `0` admits sequences
+    /// of any length.
+    ///
+    /// Lists and objects are validated recursively, reporting the first
+    /// invalid item or field through a [`Violation::Nested`].
+    ///
+    /// # Errors
+    ///
+    /// It returns the first [`Violation`] found.
+    pub fn validate(&self, value: &ParameterValue) -> Result<(), Violation> {
+        let out_of_range =
+            |min: ParameterValue, max: ParameterValue| Err(Violation::OutOfRange { min, max });
+        let misaligned = |origin: ParameterValue, step: ParameterValue| {
+            Err(Violation::Misaligned { origin, step })
+        };
+
+        match (value, self) {
+            (ParameterValue::Bool(_), Self::Bool { .. }) => Ok(()),
+            (ParameterValue::U8(v), Self::U8 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::U16(v), Self::U16 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::U32(v), Self::U32 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::U64(v), Self::U64 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::I8(v), Self::I8 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::I16(v), Self::I16 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::I32(v), Self::I32 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::I64(v), Self::I64 { min, max, .. }) => bounded(*v, *min, *max),
+            (ParameterValue::F32(v), Self::F32 { min, max, step, .. }) => {
+                if !in_interval(*v, *min, *max) {
+                    out_of_range((*min).into(), (*max).into())
+                } else if *step > 0.
+                    && !is_aligned(
+                        f64::from(*v),
+                        0.,
+                        f64::from(*step),
+                        f64::from(f32::EPSILON) * 4.,
+                    )
+                {
+                    misaligned(0f32.into(), (*step).into())
+                } else {
+                    Ok(())
+                }
+            }
+            (ParameterValue::F64(v), Self::F64 { min, max, step, .. }) => {
+                if !in_interval(*v, *min, *max) {
+                    out_of_range((*min).into(), (*max).into())
+                } else if *step > 0. && !is_aligned(*v, 0., *step, f64::EPSILON * 16.) {
+                    misaligned(0f64.into(), (*step).into())
+                } else {
+                    Ok(())
+                }
+            }
+            (ParameterValue::U64(v), Self::RangeU64 { min, max, step, .. }) => {
+                if !in_interval(*v, *min, *max) {
+                    out_of_range((*min).into(), (*max).into())
+                } else if *step > 0 && (i128::from(*v) - i128::from(*min)) % i128::from(*step) != 0
+                {
+                    misaligned((*min).into(), (*step).into())
+                } else {
+                    Ok(())
+                }
+            }
+            (ParameterValue::I64(v), Self::RangeI64 { min, max, step, .. }) => {
+                if !in_interval(*v, *min, *max) {
+                    out_of_range((*min).into(), (*max).into())
+                } else if *step > 0 && (i128::from(*v) - i128::from(*min)) % i128::from(*step) != 0
+                {
+                    misaligned((*min).into(), (*step).into())
+                } else {
+                    Ok(())
+                }
+            }
+            (ParameterValue::F64(v), Self::RangeF64 { min, max, step, .. }) => {
+                if !in_interval(*v, *min, *max) {
+                    out_of_range((*min).into(), (*max).into())
+                } else if *step > 0. && !is_aligned(*v, *min, *step, f64::EPSILON * 16.) {
+                    misaligned((*min).into(), (*step).into())
+                } else {
+                    Ok(())
+                }
+            }
+            (ParameterValue::CharsSequence(v), Self::CharsSequence { length, .. }) => {
+                if *length > 0 && v.chars().count() > *length {
+                    Err(Violation::TooLong { length: *length })
+                } else {
+                    Ok(())
+                }
+            }
+            (
+                ParameterValue::Color(v),
+                Self::Color {
+                    default,
+                    min_kelvin,
+                    max_kelvin,
+                },
+            ) => validate_color(*v, *default, *min_kelvin, *max_kelvin),
+            (
+                ParameterValue::List(v),
+                Self::List {
+                    item,
+                    min_len,
+                    max_len,
+                },
+            ) => validate_list(v, item, *min_len, *max_len),
+            (ParameterValue::Object(v), Self::Object { fields }) => validate_object(v, fields),
+            (ParameterValue::CharsSequence(v), Self::Choice { choices, .. }) => {
+                validate_choice(v, choices)
+            }
+            _ => Err(Violation::WrongType {
+                expected: ParameterId::from_parameter_kind(self),
+            }),
+        }
+    }
+}
+
+/// A comparison between the value of a parameter and the value of a
+/// [`Condition`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Comparison {
+    /// The values are equal.
+    #[default]
+    Equal,
+    /// The values are different.
+    NotEqual,
+    /// The parameter value is less than the condition value.
+    Less,
+    /// The parameter value is less than or equal to the condition value.
+    LessOrEqual,
+    /// The parameter value is greater than the condition value.
+    Greater,
+    /// The parameter value is greater than or equal to the condition value.
+    GreaterOrEqual,
+}
+
+impl core::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Equal => "is",
+            Self::NotEqual => "is not",
+            Self::Less => "is less than",
+            Self::LessOrEqual => "is at most",
+            Self::Greater => "is greater than",
+            Self::GreaterOrEqual => "is at least",
+        }
+        .fmt(f)
+    }
+}
+
+impl Comparison {
+    const fn is_equal(&self) -> bool {
+        matches!(self, Self::Equal)
+    }
+
+    const fn is_ordering(self) -> bool {
+        !matches!(self, Self::Equal | Self::NotEqual)
+    }
+}
+
+/// A condition on the value of a parameter.
+///
+/// Numbers are compared by value, while the other values are compared
+/// through their textual form. Ordering comparisons are met only by numbers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    /// Parameter name.
+    pub parameter: Cow<'static, str>,
+    /// Comparison between the parameter value and the expected value.
+    #[serde(skip_serializing_if = "Comparison::is_equal")]
+    #[serde(default)]
+    pub comparison: Comparison,
+    /// Expected value, expressed in its textual form.
+    pub value: Cow<'static, str>,
+}
+
+impl core::fmt::Display for Condition {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "`{}` {} `{}`",
+            self.parameter, self.comparison, self.value
+        )
+    }
+}
+
+impl Condition {
+    /// Creates a [`Condition`] comparing the value of a parameter with the
+    /// given value.
+    #[must_use]
+    pub fn new(
+        parameter: impl Into<Cow<'static, str>>,
+        comparison: Comparison,
+        value: impl Into<ParameterValue>,
+    ) -> Self {
+        Self {
+            parameter: parameter.into(),
+            comparison,
+            value: value.into().to_string().into(),
+        }
+    }
+
+    /// Checks whether a parameter value meets a [`Condition`].
+    #[must_use]
+    pub fn is_met(&self, value: &ParameterValue) -> bool {
+        let ordering = || {
+            value
+                .number()
+                .zip(self.value.parse::<f64>().ok())
+                .and_then(|(value, expected)| value.partial_cmp(&expected))
+        };
+
+        // Numbers are compared by value, so that `25.0` is equal to `25`.
+        let is_equal = || match value.number() {
+            Some(_) => ordering().is_some_and(core::cmp::Ordering::is_eq),
+            None => value.to_string() == self.value,
+        };
+
+        match self.comparison {
+            Comparison::Equal => is_equal(),
+            Comparison::NotEqual => !is_equal(),
+            Comparison::Less => ordering().is_some_and(core::cmp::Ordering::is_lt),
+            Comparison::LessOrEqual => ordering().is_some_and(core::cmp::Ordering::is_le),
+            Comparison::Greater => ordering().is_some_and(core::cmp::Ordering::is_gt),
+            Comparison::GreaterOrEqual => ordering().is_some_and(core::cmp::Ordering::is_ge),
+        }
+    }
+
+    // Checks whether a condition can be met by the values of a parameter
+    // kind.
+    //
+    // The value of a condition on a number must be a number, while ordering
+    // comparisons can only be applied to numbers.
+    pub(crate) fn is_valid_for(&self, kind: &ParameterKind) -> bool {
+        if ParameterValue::from_parameter_kind(kind).number().is_some() {
+            return self.value.parse::<f64>().is_ok_and(|value| !value.is_nan());
+        }
+
+        if self.comparison.is_ordering() {
+            return false;
+        }
+
+        match kind {
+            ParameterKind::Bool { .. } => self.value == "true" || self.value == "false",
+            ParameterKind::Choice { choices, .. } => choices.contains(&self.value),
+            ParameterKind::Color { .. } => self.value.parse::<Color>().is_ok(),
+            _ => true,
+        }
+    }
+}
+
+/// Data of a route input parameter.
+///
+/// It contains the [`ParameterKind`] together with the constraints which
+/// regulate the presence of a parameter in a request, and some metadata
+/// which describe the parameter to a user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParameterData {
+    /// Parameter kind.
+    #[serde(flatten)]
+    pub kind: ParameterKind,
+    /// Whether the parameter must be supplied, since its default value
+    /// cannot be used in its place.
+    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default)]
+    pub required: bool,
+    /// Parameters which must be supplied together with this parameter.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub requires: Vec<Cow<'static, str>>,
+    /// Parameters which cannot be supplied together with this parameter.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub excludes: Vec<Cow<'static, str>>,
+    /// The condition which must be met to supply this parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub enabled_when: Option<Condition>,
+    /// Measurement unit of the parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub unit: Option<Unit>,
+    /// A human-readable parameter name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub label: Option<Cow<'static, str>>,
+    /// Parameter description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub description: Option<Cow<'static, str>>,
+}
+
+impl ParameterData {
+    /// Converts a value expressed in the given [`Unit`] into a
+    /// [`ParameterValue`] expressed in the unit of the parameter.
+    ///
+    /// The converted value is rounded to the nearest value admitted by the
+    /// step, or by the decimal precision, of the parameter. Integer values
+    /// are rounded to the nearest integer.
+    ///
+    /// # Errors
+    ///
+    /// It returns a [`Violation`] when the units cannot be converted, or
+    /// when the converted value cannot be represented by the parameter kind.
+    pub fn convert(&self, value: f64, unit: Unit) -> Result<ParameterValue, Violation> {
+        let value = self
+            .unit
+            .and_then(|expected| unit.convert(value, expected))
+            .ok_or(Violation::IncompatibleUnit {
+                unit,
+                expected: self.unit,
+            })?;
+
+        number_value(&self.kind, value).ok_or(Violation::WrongType {
+            expected: ParameterId::from_parameter_kind(&self.kind),
+        })
+    }
+}
+
+// Rounds a number to the nearest integer.
+//
+// Rounding is not available in `core`, hence it is obtained through a cast.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn round(value: f64) -> f64 {
+    (if value < 0. { value - 0.5 } else { value + 0.5 }) as i128 as f64
+}
+
+// Snaps a number to the nearest value reachable from `origin` adding `step`
+// an integer number of times.
+//
+// Decimal steps, such as `0.1`, are not exactly represented, so when their
+// reciprocal is an integer the number of steps is divided by it, in order not
+// to introduce further rounding errors.
+fn snap(value: f64, origin: f64, step: f64) -> f64 {
+    if step <= 0. {
+        return value;
+    }
+    let steps = round((value - origin) / step);
+    let reciprocal = 1. / step;
+    if is_aligned(reciprocal, 0., 1., f64::EPSILON * 16.) {
+        origin + steps / round(reciprocal)
+    } else {
+        origin + steps * step
+    }
+}
+
+// Expresses a number with the type of a parameter kind, snapping it to the
+// step of the parameter.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn number_value(parameter_kind: &ParameterKind, value: f64) -> Option<ParameterValue> {
+    let value = match parameter_kind {
+        ParameterKind::F32 { step, .. } => snap(value, 0., f64::from(*step)),
+        ParameterKind::F64 { step, .. } => snap(value, 0., *step),
+        ParameterKind::RangeU64 { min, step, .. } => snap(value, *min as f64, *step as f64),
+        ParameterKind::RangeI64 { min, step, .. } => snap(value, *min as f64, *step as f64),
+        ParameterKind::RangeF64 { min, step, .. } => snap(value, *min, *step),
+        _ => value,
+    };
+    let integer = round(value) as i128;
+    match parameter_kind {
+        ParameterKind::U8 { .. } => u8::try_from(integer).ok().map(Into::into),
+        ParameterKind::U16 { .. } => u16::try_from(integer).ok().map(Into::into),
+        ParameterKind::U32 { .. } => u32::try_from(integer).ok().map(Into::into),
+        ParameterKind::U64 { .. } | ParameterKind::RangeU64 { .. } => {
+            u64::try_from(integer).ok().map(Into::into)
+        }
+        ParameterKind::I8 { .. } => i8::try_from(integer).ok().map(Into::into),
+        ParameterKind::I16 { .. } => i16::try_from(integer).ok().map(Into::into),
+        ParameterKind::I32 { .. } => i32::try_from(integer).ok().map(Into::into),
+        ParameterKind::I64 { .. } | ParameterKind::RangeI64 { .. } => {
+            i64::try_from(integer).ok().map(Into::into)
+        }
+        ParameterKind::F32 { .. } => Some((value as f32).into()),
+        ParameterKind::F64 { .. } | ParameterKind::RangeF64 { .. } => Some(value.into()),
+        _ => None,
+    }
+}
+
+impl From<ParameterKind> for ParameterData {
+    fn from(kind: ParameterKind) -> Self {
+        Self {
+            kind,
+            required: false,
+            requires: Vec::new(),
+            excludes: Vec::new(),
+            enabled_when: None,
+            unit: None,
+            label: None,
+            description: None,
+        }
+    }
+}
+
+map! {
+  /// A map of serializable and deserializable [`Parameters`] data.
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+  pub struct ParametersData(IndexMap<String, ParameterData, DefaultHashBuilder>);
+}
+
+impl ParametersData {
+    /// Validates a sequence of named [`ParameterValue`]s against the
+    /// declared parameters.
+    ///
+    /// Parameters which are declared but not present in the sequence are
+    /// not considered, since their default value is used.
+    ///
+    /// # Errors
+    ///
+    /// It returns a [`ValidationError`] for the first invalid value.
+    pub fn validate<'a, N>(
+        &self,
+        values: impl IntoIterator<Item = (N, &'a ParameterValue)>,
+    ) -> Result<(), ValidationError>
+    where
+        N: AsRef<str>,
+    {
+        for (name, value) in values {
+            let name = name.as_ref();
+            let violation = match self.get(name) {
+                Some(parameter) => parameter.kind.validate(value),
+                None => Err(Violation::Undeclared),
+            };
+
+            match violation {
+                Ok(()) => {}
+                Err(Violation::Nested(error)) => return Err(error.prefixed(name)),
+                Err(violation) => {
+                    return Err(ValidationError {
+                        name: name.into(),
+                        value: value.clone(),
+                        violation,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the constraints among the parameters supplied in a
+    /// request.
+    ///
+    /// Required parameters must be present in the sequence, and each
+    /// supplied parameter must respect its dependencies. The enabling
+    /// condition of a parameter is evaluated on the default value of the
+    /// other parameter when the latter is not supplied.
+    ///
+    /// # Errors
+    ///
+    /// It returns a [`ValidationError`] for the first violated constraint.
+    pub fn validate_constraints<N>(
+        &self,
+        supplied: &[(N, &ParameterValue)],
+    ) -> Result<(), ValidationError>
+    where
+        N: AsRef<str>,
+    {
+        let supplied_value = |name: &str| {
+            supplied
+                .iter()
+                .find(|(supplied_name, _)| supplied_name.as_ref() == name)
+                .map(|(_, value)| *value)
+        };
+
+        for (name, parameter) in self {
+            let Some(value) = supplied_value(name) else {
+                if parameter.required {
+                    return Err(ValidationError {
+                        name: name.clone(),
+                        value: ParameterValue::from_parameter_kind(&parameter.kind),
+                        violation: Violation::Required,
+                    });
+                }
+                continue;
+            };
+
+            let violation = if let Some(other) = parameter
+                .requires
+                .iter()
+                .find(|other| supplied_value(other).is_none())
+            {
+                Violation::Requires {
+                    parameter: other.clone(),
+                }
+            } else if let Some(other) = parameter
+                .excludes
+                .iter()
+                .find(|other| supplied_value(other).is_some())
+            {
+                Violation::Excludes {
+                    parameter: other.clone(),
+                }
+            } else if let Some(condition) = parameter
+                .enabled_when
+                .as_ref()
+                .filter(|condition| !self.is_met(condition, supplied))
+            {
+                Violation::Disabled {
+                    condition: condition.clone(),
+                }
+            } else {
+                continue;
+            };
+
+            return Err(ValidationError {
+                name: name.clone(),
+                value: value.clone(),
+                violation,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks whether a [`Condition`] is met by the parameters supplied in a
+    /// request.
+    ///
+    /// The condition is evaluated on the default value of its parameter when
+    /// the latter is not supplied, and it is never met when its parameter is
+    /// not declared.
+    #[must_use]
+    pub fn is_met<N>(&self, condition: &Condition, supplied: &[(N, &ParameterValue)]) -> bool
+    where
+        N: AsRef<str>,
+    {
+        supplied
+            .iter()
+            .find(|(name, _)| name.as_ref() == condition.parameter)
+            .map_or_else(
+                || {
+                    self.get(condition.parameter.as_ref())
+                        .is_some_and(|parameter| {
+                            condition.is_met(&ParameterValue::from_parameter_kind(&parameter.kind))
+                        })
+                },
+                |(_, value)| condition.is_met(value),
+            )
+    }
+
+    /// Checks whether a parameter is required or whether its presence is
+    /// checked by a dependency, hence whether its default value cannot be
+    /// sent in its place when the parameter is not supplied.
+    #[must_use]
+    pub fn is_constrained(&self, name: &str) -> bool {
+        self.iter().any(|(other, parameter)| {
+            (other == name
+                && (parameter.required
+                    || !parameter.requires.is_empty()
+                    || !parameter.excludes.is_empty()
+                    || parameter.enabled_when.is_some()))
+                || parameter.requires.iter().any(|target| target == name)
+                || parameter.excludes.iter().any(|target| target == name)
+        })
+    }
+}
+
+/// Route input parameters.
+#[derive(Debug, Clone)]
+pub struct Parameters(IndexMap<Cow<'static, str>, ParameterData, DefaultHashBuilder>);
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ParametersData> for Parameters {
+    fn from(parameters: ParametersData) -> Self {
+        Self(
+            parameters
+                .0
+                .into_iter()
+                .map(|(name, parameter)| (name.into(), parameter))
+                .collect(),
+        )
+    }
+}
+
+impl Parameters {
+    /// Creates a [`Parameters`].
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self(IndexMap::with_hasher(DefaultHashBuilder::default()))
+    }
+
+    /// Adds a [`bool`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn bool(self, name: &'static str, default: bool) -> Self {
+        self.create_parameter(name, ParameterKind::Bool { default })
+    }
+
+    /// Adds an [`u8`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn u8(self, name: &'static str, default: u8) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::U8 {
+                default,
+                min: u8::MAX,
+                max: u8::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`u8`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn u8_with_limits(self, name: &'static str, default: u8, min: u8, max: u8) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::U8 { default, min, max })
+    }
+
+    /// Adds an [`u16`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn u16(self, name: &'static str, default: u16) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::U16 {
+                default,
+                min: u16::MAX,
+                max: u16::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`u16`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn u16_with_limits(self, name: &'static str, default: u16, min: u16, max: u16) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::U16 { default, min, max })
+    }
+
+    /// Adds an [`u32`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn u32(self, name: &'static str, default: u32) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::U32 {
+                default,
+                min: u32::MAX,
+                max: u32::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`u32`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn u32_with_limits(self, name: &'static str, default: u32, min: u32, max: u32) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::U32 { default, min, max })
+    }
+
+    /// Adds an [`u64`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn u64(self, name: &'static str, default: u64) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::U64 {
+                default,
+                min: u64::MAX,
+                max: u64::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`u64`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn u64_with_limits(self, name: &'static str, default: u64, min: u64, max: u64) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::U64 { default, min, max })
+    }
+
+    /// Adds an [`i8`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn i8(self, name: &'static str, default: i8) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I8 {
+                default,
+                min: i8::MAX,
+                max: i8::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`i8`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn i8_with_limits(self, name: &'static str, default: i8, min: i8, max: i8) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::I8 { default, min, max })
+    }
+
+    /// Adds an [`i16`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn i16(self, name: &'static str, default: i16) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I16 {
+                default,
+                min: i16::MAX,
+                max: i16::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`i16`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn i16_with_limits(self, name: &'static str, default: i16, min: i16, max: i16) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::I16 { default, min, max })
+    }
+
+    /// Adds an [`i32`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn i32(self, name: &'static str, default: i32) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I32 {
+                default,
+                min: i32::MAX,
+                max: i32::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`i32`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn i32_with_limits(self, name: &'static str, default: i32, min: i32, max: i32) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::I32 { default, min, max })
+    }
+
+    /// Adds an [`i64`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn i64(self, name: &'static str, default: i64) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::I64 {
+                default,
+                min: i64::MAX,
+                max: i64::MIN,
+            },
+        )
+    }
+
+    /// Adds an [`i64`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn i64_with_limits(self, name: &'static str, default: i64, min: i64, max: i64) -> Self {
+        self.create_bounded_parameter(name, min, max, ParameterKind::I64 { default, min, max })
+    }
+
+    /// Adds a [`f32`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn f32(self, name: &'static str, default: f32) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::F32 {
+                default,
+                min: f32::MAX,
+                max: f32::MIN,
+                step: 0.,
+            },
+        )
+    }
+
+    /// Adds a [`f32`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn f32_with_limits(
+        self,
+        name: &'static str,
+        default: f32,
+        min: f32,
+        max: f32,
+        decimal_precision: DecimalPrecision,
+    ) -> Self {
+        self.create_bounded_parameter(
+            name,
+            min,
+            max,
+            ParameterKind::F32 {
+                default,
+                min,
+                max,
+                step: decimal_precision.to_f32(),
+            },
+        )
+    }
+
+    /// Adds a [`f64`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn f64(self, name: &'static str, default: f64) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::F64 {
+                default,
+                min: f64::MAX,
+                max: f64::MIN,
+                step: 0.,
+            },
+        )
+    }
+
+    /// Adds a [`f64`] parameter with limits.
+    #[must_use]
+    #[inline]
+    pub fn f64_with_limits(
+        self,
+        name: &'static str,
+        default: f64,
+        min: f64,
+        max: f64,
+        decimal_precision: DecimalPrecision,
+    ) -> Self {
+        self.create_bounded_parameter(
+            name,
+            min,
+            max,
+            ParameterKind::F64 {
+                default,
+                min,
+                max,
+                step: decimal_precision.to_f64(),
+            },
+        )
+    }
+
+    /// Adds an [`u64`] range without a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangeu64(self, name: &'static str, range: (u64, u64, u64)) -> Self {
+        self.rangeu64_with_default(name, range, 0)
+    }
+
+    /// Adds an [`u64`] range with a default value.
     #[must_use]
     #[inline]
     pub fn rangeu64_with_default(
@@ -545,8 +2134,10 @@ impl Parameters {
         range: (u64, u64, u64),
         default: u64,
     ) -> Self {
-        self.create_parameter(
+        self.create_bounded_parameter(
             name,
+            range.0,
+            range.1,
             ParameterKind::RangeU64 {
                 min: range.0,
                 max: range.1,
@@ -556,6 +2147,35 @@ impl Parameters {
         )
     }
 
+    /// Adds an [`i64`] range without a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangei64(self, name: &'static str, range: (i64, i64, i64)) -> Self {
+        self.rangei64_with_default(name, range, 0)
+    }
+
+    /// Adds an [`i64`] range with a default value.
+    #[must_use]
+    #[inline]
+    pub fn rangei64_with_default(
+        self,
+        name: &'static str,
+        range: (i64, i64, i64),
+        default: i64,
+    ) -> Self {
+        self.create_bounded_parameter(
+            name,
+            range.0,
+            range.1,
+            ParameterKind::RangeI64 {
+                min: range.0,
+                max: range.1,
+                step: range.2.saturating_abs(),
+                default,
+            },
+        )
+    }
+
     /// Adds a [`f64`] range without a default value.
     #[must_use]
     #[inline]
@@ -572,8 +2192,10 @@ impl Parameters {
         range: (f64, f64, f64),
         default: f64,
     ) -> Self {
-        self.create_parameter(
+        self.create_bounded_parameter(
             name,
+            range.0,
+            range.1,
             ParameterKind::RangeF64 {
                 min: range.0,
                 max: range.1,
@@ -583,22 +2205,241 @@ impl Parameters {
         )
     }
 
-    /// Adds a characters sequence with a determined length.
+    /// Adds a characters sequence of any length.
+    #[must_use]
+    #[inline]
+    pub fn characters_sequence(
+        self,
+        name: &'static str,
+        default: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.characters_sequence_with_length(name, default, 0)
+    }
+
+    /// Adds a characters sequence containing at most `length` characters.
+    ///
+    /// A `length` of `0` admits sequences of any length, while a default
+    /// value longer than `length` causes the parameter not to be added.
+    #[must_use]
+    #[inline]
+    pub fn characters_sequence_with_length(
+        self,
+        name: &'static str,
+        default: impl Into<Cow<'static, str>>,
+        length: usize,
+    ) -> Self {
+        let default = default.into();
+        if length > 0 && default.chars().count() > length {
+            error!("Default value of `{name}` longer than {length} characters, removed");
+            return self;
+        }
+        self.create_parameter(name, ParameterKind::CharsSequence { default, length })
+    }
+
+    /// Adds a [`Color`] parameter.
+    #[must_use]
+    #[inline]
+    pub fn color(self, name: &'static str, default: Color) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::Color {
+                default,
+                min_kelvin: u16::MAX,
+                max_kelvin: u16::MIN,
+            },
+        )
+    }
+
+    /// Adds a color temperature [`Color`] parameter with limits,
+    /// expressed in Kelvin.
+    #[must_use]
+    #[inline]
+    pub fn color_temperature(
+        self,
+        name: &'static str,
+        default: u16,
+        min_kelvin: u16,
+        max_kelvin: u16,
+    ) -> Self {
+        self.create_bounded_parameter(
+            name,
+            min_kelvin,
+            max_kelvin,
+            ParameterKind::Color {
+                default: Color::Kelvin(default),
+                min_kelvin,
+                max_kelvin,
+            },
+        )
+    }
+
+    /// Adds a list of items of the same kind, without any length limit.
+    #[must_use]
+    #[inline]
+    pub fn list(self, name: &'static str, item: ParameterKind) -> Self {
+        self.list_with_length(name, item, usize::MIN, usize::MAX)
+    }
+
+    /// Adds a list of items of the same kind, containing from `min_len`
+    /// up to `max_len` items.
+    #[must_use]
+    #[inline]
+    pub fn list_with_length(
+        self,
+        name: &'static str,
+        item: ParameterKind,
+        min_len: usize,
+        max_len: usize,
+    ) -> Self {
+        self.create_bounded_parameter(
+            name,
+            min_len,
+            max_len,
+            ParameterKind::List {
+                item: Box::new(item),
+                min_len,
+                max_len,
+            },
+        )
+    }
+
+    /// Adds an object composed of the given fields.
+    #[must_use]
+    #[inline]
+    pub fn object(self, name: &'static str, fields: Self) -> Self {
+        self.create_parameter(
+            name,
+            ParameterKind::Object {
+                fields: fields.serialize_data(),
+            },
+        )
+    }
+
+    /// Adds a choice among a set of labels.
+    ///
+    /// The first label represents the default value.
+    #[must_use]
+    #[inline]
+    pub fn choice(self, name: &'static str, choices: &[&'static str]) -> Self {
+        self.choice_with_default(name, choices, choices.first().copied().unwrap_or_default())
+    }
+
+    /// Adds a choice among a set of labels with a default value.
+    ///
+    /// A choice without labels is not added, while a default value which is
+    /// not among the labels is replaced with the first label.
+    #[must_use]
+    #[inline]
+    pub fn choice_with_default(
+        self,
+        name: &'static str,
+        choices: &[&'static str],
+        default: &'static str,
+    ) -> Self {
+        let Some(first) = choices.first() else {
+            error!("Choice `{name}` without any label, removed");
+            return self;
+        };
+        let default = if choices.contains(&default) {
+            default
+        } else {
+            error!(
+                "Default value `{default}` of choice `{name}` is not a label, replaced with `{first}`"
+            );
+            first
+        };
+
+        self.create_parameter(
+            name,
+            ParameterKind::Choice {
+                default: default.into(),
+                choices: choices
+                    .iter()
+                    .map(|choice| Cow::Borrowed(*choice))
+                    .collect(),
+            },
+        )
+    }
+
+    /// Marks a parameter as required.
+    ///
+    /// A required parameter must be supplied in each request, since its
+    /// default value cannot be used in its place.
+    ///
+    /// It has no effect when the parameter has not been added.
+    #[must_use]
+    #[inline]
+    pub fn required(self, name: &'static str) -> Self {
+        self.update(name, |parameter| parameter.required = true)
+    }
+
+    /// Requires the `other` parameter to be supplied together with the
+    /// `name` parameter.
+    ///
+    /// It has no effect when the `name` parameter has not been added.
+    #[must_use]
+    #[inline]
+    pub fn requires(self, name: &'static str, other: &'static str) -> Self {
+        self.update(name, |parameter| parameter.requires.push(other.into()))
+    }
+
+    /// Forbids the `other` parameter to be supplied together with the
+    /// `name` parameter.
+    ///
+    /// It has no effect when the `name` parameter has not been added.
+    #[must_use]
+    #[inline]
+    pub fn excludes(self, name: &'static str, other: &'static str) -> Self {
+        self.update(name, |parameter| parameter.excludes.push(other.into()))
+    }
+
+    /// Allows the `name` parameter to be supplied only when the `other`
+    /// parameter has the given value.
+    ///
+    /// It has no effect when the `name` parameter has not been added.
+    #[must_use]
+    #[inline]
+    pub fn enabled_when(
+        self,
+        name: &'static str,
+        other: &'static str,
+        value: impl Into<ParameterValue>,
+    ) -> Self {
+        let condition = Condition::new(other, Comparison::Equal, value);
+        self.update(name, |parameter| parameter.enabled_when = Some(condition))
+    }
+
+    /// Sets the measurement [`Unit`] of a parameter.
+    ///
+    /// It has no effect when the parameter has not been added.
     #[must_use]
     #[inline]
-    pub fn characters_sequence(
+    pub fn unit(self, name: &'static str, unit: Unit) -> Self {
+        self.update(name, |parameter| parameter.unit = Some(unit))
+    }
+
+    /// Sets a human-readable name of a parameter.
+    ///
+    /// It has no effect when the parameter has not been added.
+    #[must_use]
+    #[inline]
+    pub fn label(self, name: &'static str, label: impl Into<Cow<'static, str>>) -> Self {
+        self.update(name, |parameter| parameter.label = Some(label.into()))
+    }
+
+    /// Sets the description of a parameter.
+    ///
+    /// It has no effect when the parameter has not been added.
+    #[must_use]
+    #[inline]
+    pub fn description(
         self,
         name: &'static str,
-        default: impl Into<Cow<'static, str>>,
+        description: impl Into<Cow<'static, str>>,
     ) -> Self {
-        let default = default.into();
-        self.create_parameter(
-            name,
-            ParameterKind::CharsSequence {
-                length: default.len(),
-                default,
-            },
-        )
+        self.update(name, |parameter| {
+            parameter.description = Some(description.into());
+        })
     }
 
     /// Serializes [`Parameters`] data.
@@ -609,24 +2450,58 @@ impl Parameters {
     pub fn serialize_data(self) -> ParametersData {
         let mut data = ParametersData::new();
         for (key, value) in self.0 {
-            data.add(key.into(), value);
+            data.add(key.into_owned(), value);
         }
         data
     }
 
     fn create_parameter(mut self, name: &'static str, parameter_kind: ParameterKind) -> Self {
-        self.0.insert(name, parameter_kind);
+        self.0.insert(name.into(), parameter_kind.into());
+        self
+    }
+
+    // Adds a parameter declared with explicit bounds.
+    //
+    // Since inverted bounds denote a parameter without limits, a parameter
+    // whose minimum value is greater than the maximum one is not added.
+    fn create_bounded_parameter<T: PartialOrd + Copy + core::fmt::Display>(
+        self,
+        name: &'static str,
+        min: T,
+        max: T,
+        parameter_kind: ParameterKind,
+    ) -> Self {
+        if min > max {
+            error!(
+                "Parameter `{name}` with a minimum `{min}` greater than its maximum `{max}`, removed"
+            );
+            return self;
+        }
+        self.create_parameter(name, parameter_kind)
+    }
+
+    fn update(mut self, name: &'static str, update: impl FnOnce(&mut ParameterData)) -> Self {
+        if let Some(parameter) = self.0.get_mut(name) {
+            update(parameter);
+        }
         self
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use alloc::string::String;
+    use alloc::boxed::Box;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
 
+    use crate::units::Unit;
     use crate::{deserialize, serialize};
 
-    use super::{ParameterKind, Parameters, ParametersData};
+    use super::{
+        Color, ColorModel, Comparison, Condition, DecimalPrecision, ParameterId, ParameterKind,
+        ParameterValue, Parameters, ParametersData, ValidationError, Violation,
+    };
 
     fn expected_parameters_data() -> ParametersData {
         ParametersData::new()
@@ -710,7 +2585,14 @@ mod tests {
                 "greeting2".into(),
                 ParameterKind::CharsSequence {
                     default: "hello".into(),
-                    length: 5,
+                    length: 0,
+                },
+            )
+            .insert(
+                "format".into(),
+                ParameterKind::Choice {
+                    default: "closest".into(),
+                    choices: vec!["random".into(), "closest".into(), "exact".into()],
                 },
             )
     }
@@ -727,8 +2609,9 @@ mod tests {
             .f64("f64", 0.)
             .rangeu64_with_default("rangeu64", (0, 20, 1), 5)
             .rangef64_with_default("rangef64", (0., 20., 0.1), 5.)
-            .characters_sequence("greeting", "hello")
+            .characters_sequence_with_length("greeting", "hello", 5)
             .characters_sequence("greeting2", String::from("hello"))
+            .choice_with_default("format", &["random", "closest", "exact"], "closest")
             // Adds a duplicate to see whether that value is maintained or
             // removed.
             .u16("u16", 0);
@@ -738,4 +2621,704 @@ mod tests {
             expected_parameters_data(),
         );
     }
+
+    #[test]
+    fn test_signed_parameters() {
+        let parameters = Parameters::new()
+            .i8("i8", -1)
+            .i16("i16", -1)
+            .i32("i32", -1)
+            .i64("i64", -1)
+            .i64_with_limits("offset", 0, -10, 10)
+            .rangei64("rangei64", (-20, 20, -2));
+
+        let expected = ParametersData::new()
+            .insert(
+                "i8".into(),
+                ParameterKind::I8 {
+                    default: -1,
+                    min: i8::MIN,
+                    max: i8::MAX,
+                },
+            )
+            .insert(
+                "i16".into(),
+                ParameterKind::I16 {
+                    default: -1,
+                    min: i16::MIN,
+                    max: i16::MAX,
+                },
+            )
+            .insert(
+                "i32".into(),
+                ParameterKind::I32 {
+                    default: -1,
+                    min: i32::MIN,
+                    max: i32::MAX,
+                },
+            )
+            .insert(
+                "i64".into(),
+                ParameterKind::I64 {
+                    default: -1,
+                    min: i64::MIN,
+                    max: i64::MAX,
+                },
+            )
+            .insert(
+                "offset".into(),
+                ParameterKind::I64 {
+                    default: 0,
+                    min: -10,
+                    max: 10,
+                },
+            )
+            .insert(
+                "rangei64".into(),
+                ParameterKind::RangeI64 {
+                    min: -20,
+                    max: 20,
+                    step: 2,
+                    default: 0,
+                },
+            );
+
+        assert_eq!(
+            deserialize::<ParametersData>(serialize(parameters.serialize_data())),
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_color_parameters() {
+        let parameters = Parameters::new()
+            .color("tint", Color::rgb(255, 255, 255))
+            .color_temperature("temperature", 4000, 2700, 6500);
+
+        let expected = ParametersData::new()
+            .insert(
+                "tint".into(),
+                ParameterKind::Color {
+                    default: Color::rgb(255, 255, 255),
+                    min_kelvin: 0,
+                    max_kelvin: u16::MAX,
+                },
+            )
+            .insert(
+                "temperature".into(),
+                ParameterKind::Color {
+                    default: Color::kelvin(4000),
+                    min_kelvin: 2700,
+                    max_kelvin: 6500,
+                },
+            );
+
+        assert_eq!(
+            deserialize::<ParametersData>(serialize(parameters.serialize_data())),
+            expected,
+        );
+
+        // Textual representation.
+        for color in [
+            Color::rgb(255, 128, 0),
+            Color::hsv(359, 100, 0).unwrap(),
+            Color::kelvin(2700),
+        ] {
+            assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+            assert_eq!(deserialize::<Color>(serialize(color)), color);
+        }
+        assert_eq!("#FF8000".parse::<Color>(), Ok(Color::rgb(255, 128, 0)));
+        assert!("rgb:256,0,0".parse::<Color>().is_err());
+        assert!("hsv:360,0,0".parse::<Color>().is_err());
+        assert!("kelvin:2700,1".parse::<Color>().is_err());
+        assert!("cmyk:0,0,0,0".parse::<Color>().is_err());
+        assert_eq!(Color::hsv(0, 101, 0), None);
+
+        // Validation.
+        let temperature = ParameterKind::Color {
+            default: Color::kelvin(4000),
+            min_kelvin: 2700,
+            max_kelvin: 6500,
+        };
+        assert_eq!(temperature.validate(&Color::kelvin(6500).into()), Ok(()));
+        assert_eq!(
+            temperature.validate(&Color::kelvin(2000).into()),
+            Err(Violation::OutOfRange {
+                min: Color::kelvin(2700).into(),
+                max: Color::kelvin(6500).into(),
+            })
+        );
+        assert_eq!(
+            temperature.validate(&Color::rgb(0, 0, 0).into()),
+            Err(Violation::WrongColorModel {
+                expected: ColorModel::Kelvin
+            })
+        );
+        assert_eq!(
+            ParameterKind::Color {
+                default: Color::hsv(0, 0, 0).unwrap(),
+                min_kelvin: u16::MAX,
+                max_kelvin: u16::MIN,
+            }
+            .validate(&ParameterValue::Color(Color::Hsv {
+                hue: 400,
+                saturation: 0,
+                value: 0,
+            })),
+            Err(Violation::InvalidColor)
+        );
+    }
+
+    fn structured_parameters() -> ParametersData {
+        Parameters::new()
+            .list_with_length(
+                "schedule",
+                ParameterKind::U8 {
+                    default: 0,
+                    min: 0,
+                    max: 100,
+                },
+                1,
+                4,
+            )
+            .object(
+                "resolution",
+                Parameters::new()
+                    .u16_with_limits("width", 640, 0, 1920)
+                    .u16_with_limits("height", 480, 0, 1080),
+            )
+            .serialize_data()
+    }
+
+    #[test]
+    fn test_structured_parameters() {
+        let parameters = structured_parameters();
+
+        assert_eq!(
+            deserialize::<ParametersData>(serialize(&parameters)),
+            parameters
+        );
+
+        // Default values.
+        let schedule = &parameters.get("schedule").unwrap().kind;
+        let resolution = &parameters.get("resolution").unwrap().kind;
+        assert!(schedule.is_structured() && resolution.is_structured());
+        assert_eq!(
+            ParameterValue::from_parameter_kind(schedule),
+            ParameterValue::List(Box::new([ParameterValue::U8(0)]))
+        );
+        assert_eq!(
+            ParameterValue::from_parameter_kind(resolution).to_string(),
+            "{width: 640, height: 480}"
+        );
+    }
+
+    #[test]
+    fn test_validate_structured_parameters() {
+        let parameters = structured_parameters();
+        let list = |values: &[u8]| {
+            ParameterValue::List(values.iter().map(|v| ParameterValue::U8(*v)).collect())
+        };
+        let object = |fields: &[(&str, ParameterValue)]| {
+            ParameterValue::Object(
+                fields
+                    .iter()
+                    .map(|(name, value)| ((*name).into(), value.clone()))
+                    .collect(),
+            )
+        };
+        let validate =
+            |name: &str, value: &ParameterValue| parameters.validate([(name, value)]).unwrap_err();
+
+        assert!(
+            parameters
+                .validate([
+                    ("schedule", &list(&[10, 50, 100])),
+                    (
+                        "resolution",
+                        &object(&[("width", 1920u16.into()), ("height", 1080u16.into())])
+                    ),
+                ])
+                .is_ok()
+        );
+
+        assert_eq!(
+            validate("schedule", &list(&[])).to_string(),
+            "`schedule` value `[]` has a length outside of [1, 4]"
+        );
+        assert_eq!(
+            validate("schedule", &list(&[10, 101])),
+            ValidationError {
+                name: "schedule[1]".into(),
+                value: ParameterValue::U8(101),
+                violation: Violation::OutOfRange {
+                    min: ParameterValue::U8(0),
+                    max: ParameterValue::U8(100),
+                },
+            }
+        );
+        assert_eq!(
+            validate(
+                "resolution",
+                &object(&[("width", 1920u16.into()), ("height", 1440u16.into())])
+            )
+            .to_string(),
+            "`resolution.height` value `1440` is outside of [0, 1080]"
+        );
+        assert_eq!(
+            validate("resolution", &object(&[("depth", 8u8.into())])).to_string(),
+            "`resolution.depth` does not exist"
+        );
+        assert_eq!(
+            validate("resolution", &object(&[("width", 1920u16.into())])).to_string(),
+            "`resolution` value `{width: 1920}` misses the `height` field"
+        );
+
+        // Nested violations are also reported by parameter kinds.
+        assert_eq!(
+            parameters
+                .get("schedule")
+                .unwrap()
+                .kind
+                .validate(&list(&[101])),
+            Err(Violation::Nested(Box::new(ValidationError {
+                name: "[0]".into(),
+                value: ParameterValue::U8(101),
+                violation: Violation::OutOfRange {
+                    min: ParameterValue::U8(0),
+                    max: ParameterValue::U8(100),
+                },
+            })))
+        );
+    }
+
+    #[test]
+    fn test_parameter_constraints() {
+        let parameters = Parameters::new()
+            .u8("brightness", 0)
+            .required("brightness")
+            .bool("timer", false)
+            .u64("duration", 0)
+            .enabled_when("duration", "timer", true)
+            .choice("effect", &["none", "fade", "blink"])
+            .u16("period", 500)
+            .requires("period", "effect")
+            .excludes("period", "duration")
+            .required("missing")
+            .serialize_data();
+
+        // Constraints are serialized together with the parameter kind.
+        assert_eq!(
+            serialize(&parameters)["period"],
+            serde_json::json!({
+                "U16": { "default": 500 },
+                "requires": ["effect"],
+                "excludes": ["duration"],
+            })
+        );
+        let deserialized = deserialize::<ParametersData>(serialize(&parameters));
+        for name in ["brightness", "duration", "period"] {
+            let (parameter, expected) = (deserialized.get(name), parameters.get(name));
+            assert_eq!(
+                parameter.map(|p| (p.required, &p.requires, &p.excludes, &p.enabled_when)),
+                expected.map(|p| (p.required, &p.requires, &p.excludes, &p.enabled_when)),
+            );
+        }
+
+        assert!(parameters.is_constrained("brightness"));
+        assert!(parameters.is_constrained("duration"));
+        assert!(parameters.is_constrained("effect"));
+        assert!(!parameters.is_constrained("timer"));
+
+        let validate = |supplied: &[(&str, ParameterValue)]| {
+            let supplied: Vec<(&str, &ParameterValue)> = supplied
+                .iter()
+                .map(|(name, value)| (*name, value))
+                .collect();
+            parameters
+                .validate_constraints(&supplied)
+                .map_err(|e| e.to_string())
+        };
+
+        assert_eq!(
+            validate(&[("brightness", 5u8.into()), ("timer", true.into())]),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&[("timer", true.into())]),
+            Err("`brightness` is required".into())
+        );
+        assert_eq!(
+            validate(&[("brightness", 5u8.into()), ("duration", 10u64.into())]),
+            Err("`duration` is only allowed when `timer` is `true`".into())
+        );
+        assert_eq!(
+            validate(&[
+                ("brightness", 5u8.into()),
+                ("timer", true.into()),
+                ("duration", 10u64.into()),
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&[("brightness", 5u8.into()), ("period", 100u16.into())]),
+            Err("`period` requires `effect`".into())
+        );
+        assert_eq!(
+            validate(&[
+                ("brightness", 5u8.into()),
+                ("timer", true.into()),
+                ("duration", 10u64.into()),
+                ("effect", "blink".into()),
+                ("period", 100u16.into()),
+            ]),
+            Err("`period` cannot be supplied together with `duration`".into())
+        );
+    }
+
+    #[test]
+    fn test_conditions() {
+        let greater = Condition::new("brightness", Comparison::Greater, 80u8);
+
+        assert!(greater.is_met(&ParameterValue::U8(81)));
+        assert!(!greater.is_met(&ParameterValue::U8(80)));
+        // Numbers of different types are compared by value.
+        assert!(greater.is_met(&ParameterValue::F64(80.5)));
+        // Ordering comparisons are never met by non-numeric values.
+        assert!(!greater.is_met(&ParameterValue::CharsSequence("90".into())));
+        assert!(!greater.is_met(&ParameterValue::Bool(true)));
+
+        assert!(Condition::new("timer", Comparison::Equal, true).is_met(&true.into()));
+        assert!(Condition::new("effect", Comparison::NotEqual, "none").is_met(&"fade".into()));
+        assert!(Condition::new("offset", Comparison::LessOrEqual, -2i8).is_met(&(-2i8).into()));
+        assert!(!Condition::new("offset", Comparison::Less, -2i8).is_met(&(-2i8).into()));
+
+        // Equality comparisons on numbers are performed by value too.
+        let equal = Condition::new("temperature", Comparison::Equal, "25.0");
+        assert!(equal.is_met(&ParameterValue::F64(25.)));
+        assert!(equal.is_met(&ParameterValue::U8(25)));
+        assert!(!equal.is_met(&ParameterValue::F64(25.5)));
+        let not_equal = Condition::new("temperature", Comparison::NotEqual, "25.0");
+        assert!(!not_equal.is_met(&ParameterValue::F64(25.)));
+        assert!(not_equal.is_met(&ParameterValue::F64(25.5)));
+
+        assert_eq!(greater.to_string(), "`brightness` is greater than `80`");
+
+        // Equality comparisons are not serialized.
+        assert_eq!(
+            serialize(Condition::new("timer", Comparison::Equal, true)),
+            serde_json::json!({ "parameter": "timer", "value": "true" })
+        );
+        assert_eq!(
+            serialize(&greater),
+            serde_json::json!({
+                "parameter": "brightness",
+                "comparison": "Greater",
+                "value": "80",
+            })
+        );
+        assert_eq!(deserialize::<Condition>(serialize(&greater)), greater);
+
+        // Conditions on parameters which are not supplied are evaluated on
+        // their default value.
+        let parameters = Parameters::new().u8("brightness", 90).serialize_data();
+        let supplied: [(&str, &ParameterValue); 0] = [];
+        assert!(parameters.is_met(&greater, &supplied));
+        assert!(!parameters.is_met(&greater, &[("brightness", &ParameterValue::U8(50))]));
+        assert!(!parameters.is_met(
+            &Condition::new("missing", Comparison::NotEqual, 0u8),
+            &supplied
+        ));
+    }
+
+    #[test]
+    fn test_parameter_metadata() {
+        let parameters = Parameters::new()
+            .f64_with_limits("temperature", 20., 5., 30., DecimalPrecision::OneDigit)
+            .unit("temperature", Unit::Celsius)
+            .label("temperature", "Target temperature")
+            .description("temperature", "Temperature to be reached by the room.")
+            .u8("brightness", 100)
+            .unit("brightness", Unit::Percent)
+            .rangeu64("volume", (2, 20, 3))
+            .unit("volume", Unit::Percent)
+            .serialize_data();
+
+        assert_eq!(
+            serialize(&parameters)["temperature"],
+            serde_json::json!({
+                "F64": { "default": 20., "min": 5., "max": 30., "step": 0.1 },
+                "unit": "Celsius",
+                "label": "Target temperature",
+                "description": "Temperature to be reached by the room.",
+            })
+        );
+
+        let temperature = parameters.get("temperature").unwrap();
+        assert_eq!(
+            temperature.convert(212., Unit::Fahrenheit),
+            Ok(ParameterValue::F64(100.))
+        );
+        assert_eq!(
+            temperature.convert(1., Unit::Watt),
+            Err(Violation::IncompatibleUnit {
+                unit: Unit::Watt,
+                expected: Some(Unit::Celsius),
+            })
+        );
+
+        let brightness = parameters.get("brightness").unwrap();
+        assert_eq!(
+            brightness.convert(49.6, Unit::Percent),
+            Ok(ParameterValue::U8(50))
+        );
+        assert!(brightness.convert(300., Unit::Percent).is_err());
+
+        // Converted values are snapped to the parameter step.
+        assert_eq!(
+            temperature.convert(70., Unit::Fahrenheit),
+            Ok(ParameterValue::F64(21.1))
+        );
+        let volume = parameters.get("volume").unwrap();
+        assert_eq!(
+            volume.convert(6.2, Unit::Percent),
+            Ok(ParameterValue::U64(5))
+        );
+        assert_eq!(volume.kind.validate(&ParameterValue::U64(5)), Ok(()));
+    }
+
+    #[test]
+    fn test_inverted_bounds() {
+        let parameters = Parameters::new()
+            .u8_with_limits("brightness", 5, 20, 0)
+            .f64_with_limits("level", 0., 1., -1., DecimalPrecision::OneDigit)
+            .rangeu64_with_default("volume", (20, 2, 3), 2)
+            .rangei64_with_default("tilt", (5, -5, 2), 1)
+            .rangef64_with_default("ratio", (1., 0., 0.1), 0.5)
+            .color_temperature("white", 3000, 6500, 2700)
+            .list_with_length("schedule", ParameterKind::Bool { default: false }, 8, 1)
+            .list("tags", ParameterKind::Bool { default: false })
+            .u8("count", 0)
+            .serialize_data();
+
+        // Parameters declared with inverted bounds are discarded, since they
+        // would admit all values.
+        assert_eq!(
+            parameters
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            ["tags", "count"]
+        );
+    }
+
+    #[test]
+    fn test_choice_parameters() {
+        let parameters = Parameters::new()
+            .choice("empty", &[])
+            .choice_with_default("format", &["random", "exact"], "exact")
+            .choice_with_default("mode", &["eco", "boost"], "turbo")
+            .serialize_data();
+
+        // Choices without labels are discarded.
+        assert!(parameters.get("empty").is_none());
+
+        assert_eq!(
+            parameters.get("format").unwrap().kind,
+            ParameterKind::Choice {
+                default: "exact".into(),
+                choices: vec!["random".into(), "exact".into()],
+            }
+        );
+
+        // A default value which is not a label is replaced with the first one.
+        let kind = &parameters.get("mode").unwrap().kind;
+        assert_eq!(
+            *kind,
+            ParameterKind::Choice {
+                default: "eco".into(),
+                choices: vec!["eco".into(), "boost".into()],
+            }
+        );
+        assert_eq!(
+            kind.validate(&ParameterValue::from_parameter_kind(kind)),
+            Ok(())
+        );
+
+        // Choices are checked when deserialized.
+        assert_eq!(deserialize::<ParameterKind>(serialize(kind.clone())), *kind);
+        assert!(
+            serde_json::from_value::<ParameterKind>(serde_json::json!({
+                "Choice": { "default": "eco", "choices": ["eco"] }
+            }))
+            .is_ok()
+        );
+        for choice in [
+            serde_json::json!({ "Choice": { "default": "turbo", "choices": ["eco"] } }),
+            serde_json::json!({ "Choice": { "default": "", "choices": [] } }),
+        ] {
+            assert!(serde_json::from_value::<ParameterKind>(choice).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_parameter_kind() {
+        let parameters = Parameters::new()
+            .u8("u8", 0)
+            .u16_with_limits("u16", 5, 0, 20)
+            .f64_with_limits("f64", 0., -1., 1., DecimalPrecision::TwoDigits)
+            .rangeu64_with_default("rangeu64", (2, 20, 3), 5)
+            .rangef64("rangef64", (0.5, 2., 0.1))
+            .i8_with_limits("offset", 0, -5, 5)
+            .rangei64("temperature", (-25, -15, 5))
+            .characters_sequence_with_length("greeting", "hello", 5)
+            .characters_sequence_with_length("ssid", "home", 32)
+            .characters_sequence_with_length("invalid", "too long", 3)
+            .characters_sequence("empty", "")
+            .choice("format", &["random", "closest", "exact"])
+            .serialize_data();
+        let kind = |name: &str| &parameters.get(name).unwrap().kind;
+
+        // Parameters without limits.
+        assert_eq!(kind("u8").validate(&u8::MAX.into()), Ok(()));
+        assert_eq!(kind("empty").validate(&"any length".into()), Ok(()));
+        // Lengths are expressed in characters.
+        assert_eq!(kind("ssid").validate(&"ufficio è qui".into()), Ok(()));
+        assert_eq!(kind("greeting").validate(&"ciaoè".into()), Ok(()));
+        // Default values longer than the length are discarded.
+        assert!(parameters.get("invalid").is_none());
+
+        // Wrong type.
+        assert_eq!(
+            kind("u16").validate(&5u8.into()),
+            Err(Violation::WrongType {
+                expected: ParameterId::U16
+            })
+        );
+
+        // Limits.
+        assert_eq!(kind("u16").validate(&20u16.into()), Ok(()));
+        assert_eq!(
+            kind("u16").validate(&21u16.into()),
+            Err(Violation::OutOfRange {
+                min: ParameterValue::U16(0),
+                max: ParameterValue::U16(20),
+            })
+        );
+
+        // Decimal precision.
+        assert_eq!(kind("f64").validate(&(-0.37).into()), Ok(()));
+        assert_eq!(
+            kind("f64").validate(&0.375.into()),
+            Err(Violation::Misaligned {
+                origin: ParameterValue::F64(0.),
+                step: ParameterValue::F64(0.01),
+            })
+        );
+
+        // Ranges steps.
+        assert_eq!(kind("rangeu64").validate(&20u64.into()), Ok(()));
+        assert_eq!(
+            kind("rangeu64").validate(&6u64.into()),
+            Err(Violation::Misaligned {
+                origin: ParameterValue::U64(2),
+                step: ParameterValue::U64(3),
+            })
+        );
+
+        // Inverted bounds mean no limits, so values below the minimum must
+        // not overflow when checking the step.
+        let inverted = ParameterKind::RangeU64 {
+            min: 10,
+            max: 5,
+            step: 2,
+            default: 10,
+        };
+        assert_eq!(inverted.validate(&4u64.into()), Ok(()));
+        assert_eq!(
+            inverted.validate(&3u64.into()),
+            Err(Violation::Misaligned {
+                origin: ParameterValue::U64(10),
+                step: ParameterValue::U64(2),
+            })
+        );
+
+        assert_eq!(kind("rangef64").validate(&1.7.into()), Ok(()));
+        assert!(kind("rangef64").validate(&1.75.into()).is_err());
+        assert!(kind("rangef64").validate(&0.4.into()).is_err());
+
+        // Characters sequence length.
+        assert_eq!(kind("greeting").validate(&"hi".into()), Ok(()));
+        assert_eq!(
+            kind("greeting").validate(&"hello world".into()),
+            Err(Violation::TooLong { length: 5 })
+        );
+
+        // Signed values.
+        assert_eq!(kind("offset").validate(&(-5i8).into()), Ok(()));
+        assert_eq!(
+            kind("offset").validate(&(-6i8).into()),
+            Err(Violation::OutOfRange {
+                min: ParameterValue::I8(-5),
+                max: ParameterValue::I8(5),
+            })
+        );
+        assert_eq!(kind("temperature").validate(&(-20i64).into()), Ok(()));
+        assert_eq!(
+            kind("temperature").validate(&(-18i64).into()),
+            Err(Violation::Misaligned {
+                origin: ParameterValue::I64(-25),
+                step: ParameterValue::I64(5),
+            })
+        );
+
+        // Choices.
+        assert_eq!(kind("format").validate(&"exact".into()), Ok(()));
+        assert_eq!(
+            kind("format").validate(&"highest".into()),
+            Err(Violation::InvalidChoice {
+                choices: vec!["random".into(), "closest".into(), "exact".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_parameters_data() {
+        let parameters = Parameters::new()
+            .bool("bool", true)
+            .u8_with_limits("brightness", 5, 0, 20)
+            .serialize_data();
+
+        let valid = [
+            ("bool", ParameterValue::Bool(false)),
+            ("brightness", ParameterValue::U8(10)),
+        ];
+        assert_eq!(
+            parameters.validate(valid.iter().map(|(n, v)| (n, v))),
+            Ok(())
+        );
+
+        let undeclared = [("wrong", ParameterValue::Bool(false))];
+        let error = parameters
+            .validate(undeclared.iter().map(|(n, v)| (n, v)))
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ValidationError {
+                name: "wrong".into(),
+                value: ParameterValue::Bool(false),
+                violation: Violation::Undeclared,
+            }
+        );
+        assert_eq!(error.to_string(), "`wrong` does not exist");
+
+        let out_of_range = [("brightness", ParameterValue::U8(21))];
+        assert_eq!(
+            parameters
+                .validate(out_of_range.iter().map(|(n, v)| (n, v)))
+                .unwrap_err()
+                .to_string(),
+            "`brightness` value `21` is outside of [0, 20]"
+        );
+    }
 }
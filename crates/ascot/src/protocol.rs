@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+/// The protocol version implemented by this crate.
+///
+/// It **MUST** be bumped whenever the data exchanged among a device and a
+/// controller changes:
+///
+/// - The major number when a change breaks the existing data layout, such as
+///   renaming or removing a field, or when it adds data which affects the
+///   policy decisions of a controller, such as new kinds of hazards, since a
+///   controller ignoring that data would allow requests it should block
+/// - The minor number when a change only adds data which a controller can
+///   safely ignore, such as descriptions and translations
+/// - The patch number when a change does not touch the data layout at all
+pub const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::new(2, 0, 0);
+
+/// The name of the discovery service property advertising the
+/// [`ProtocolVersion`] of a device.
+pub const PROTOCOL_VERSION_PROPERTY: &str = "protocol-version";
+
+/// The name of the discovery service property advertising the unique
+/// identifier of a device.
+pub const UUID_PROPERTY: &str = "uuid";
+
+/// A semantic protocol version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProtocolVersion {
+    /// Major number.
+    pub major: u16,
+    /// Minor number.
+    pub minor: u16,
+    /// Patch number.
+    pub patch: u16,
+}
+
+impl core::fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl core::str::FromStr for ProtocolVersion {
+    type Err = ProtocolVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = s.trim().split('.').map(str::parse::<u16>);
+
+        match (
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+        ) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => {
+                Ok(Self::new(major, minor, patch))
+            }
+            _ => Err(ProtocolVersionError),
+        }
+    }
+}
+
+impl ProtocolVersion {
+    /// Creates a [`ProtocolVersion`].
+    #[must_use]
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Checks whether a [`ProtocolVersion`] is compatible with the `min`
+    /// version, hence whether it has the same major number and it is not
+    /// older.
+    ///
+    /// Minor releases only add data, which older readers ignore, so newer
+    /// minor versions are compatible.
+    #[must_use]
+    pub const fn is_compatible_with(&self, min: &Self) -> bool {
+        self.major == min.major
+            && (self.minor > min.minor || (self.minor == min.minor && self.patch >= min.patch))
+    }
+
+    /// Checks whether a [`ProtocolVersion`] falls within the inclusive
+    /// range delimited by the `min` and `max` versions.
+    ///
+    /// Patch releases never change the data layout, so the patch number of
+    /// the upper bound is not considered.
+    #[must_use]
+    pub const fn is_within(&self, min: &Self, max: &Self) -> bool {
+        let above_min = self.major > min.major
+            || (self.major == min.major
+                && (self.minor > min.minor
+                    || (self.minor == min.minor && self.patch >= min.patch)));
+
+        let below_max =
+            self.major < max.major || (self.major == max.major && self.minor <= max.minor);
+
+        above_min && below_max
+    }
+}
+
+/// An error raised when a [`ProtocolVersion`] cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProtocolVersionError;
+
+impl core::fmt::Display for ProtocolVersionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        "a protocol version must be in the `major.minor.patch` format".fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{deserialize, serialize};
+
+    use alloc::string::ToString;
+
+    use super::{PROTOCOL_VERSION, ProtocolVersion, ProtocolVersionError};
+
+    #[test]
+    fn test_protocol_version() {
+        let version = ProtocolVersion::new(1, 2, 3);
+
+        assert_eq!(version.to_string(), "1.2.3");
+        assert_eq!("1.2.3".parse(), Ok(version));
+        assert_eq!(deserialize::<ProtocolVersion>(serialize(version)), version);
+
+        assert_eq!("1.2".parse::<ProtocolVersion>(), Err(ProtocolVersionError));
+        assert_eq!(
+            "1.2.3.4".parse::<ProtocolVersion>(),
+            Err(ProtocolVersionError)
+        );
+        assert_eq!(
+            "1.a.3".parse::<ProtocolVersion>(),
+            Err(ProtocolVersionError)
+        );
+    }
+
+    #[test]
+    fn test_protocol_version_range() {
+        let min = ProtocolVersion::new(1, 0, 0);
+        let max = ProtocolVersion::new(1, 2, 0);
+
+        assert!(PROTOCOL_VERSION.is_within(&PROTOCOL_VERSION, &PROTOCOL_VERSION));
+
+        assert!(ProtocolVersion::new(1, 0, 0).is_within(&min, &max));
+        assert!(ProtocolVersion::new(1, 1, 7).is_within(&min, &max));
+        // Patch releases of the upper bound are accepted.
+        assert!(ProtocolVersion::new(1, 2, 4).is_within(&min, &max));
+
+        assert!(!ProtocolVersion::new(0, 9, 0).is_within(&min, &max));
+        assert!(!ProtocolVersion::new(1, 3, 0).is_within(&min, &max));
+        assert!(!ProtocolVersion::new(2, 0, 0).is_within(&min, &max));
+    }
+
+    #[test]
+    fn test_protocol_version_compatibility() {
+        let min = ProtocolVersion::new(1, 1, 2);
+
+        assert!(PROTOCOL_VERSION.is_compatible_with(&PROTOCOL_VERSION));
+
+        assert!(ProtocolVersion::new(1, 1, 2).is_compatible_with(&min));
+        // Newer minor versions only add data.
+        assert!(ProtocolVersion::new(1, 9, 0).is_compatible_with(&min));
+
+        assert!(!ProtocolVersion::new(1, 1, 1).is_compatible_with(&min));
+        assert!(!ProtocolVersion::new(1, 0, 5).is_compatible_with(&min));
+        assert!(!ProtocolVersion::new(2, 0, 0).is_compatible_with(&min));
+        assert!(!ProtocolVersion::new(0, 9, 0).is_compatible_with(&min));
+    }
+}
@@ -1,18 +1,21 @@
 use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 use hashbrown::DefaultHashBuilder;
 
+use indexmap::map::IndexMap;
 use indexmap::set::{IndexSet, IntoIter, Iter};
 
 use log::error;
 
 use serde::{Deserialize, Serialize};
 
-use crate::hazards::{Hazard, Hazards};
-use crate::parameters::{Parameters, ParametersData};
+use crate::hazards::{Hazard, HazardLevel, HazardLevels, Hazards, Locale};
+use crate::parameters::{Condition, ParameterValue, Parameters, ParametersData};
 use crate::response::ResponseKind;
 
-use crate::macros::{mandatory_route, set};
+use crate::macros::{mandatory_route, map, set};
 
 /// `REST` requests kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -39,6 +42,83 @@ impl core::fmt::Display for RestKind {
     }
 }
 
+/// An [`Hazard`] which occurs only when a [`Condition`] on a route input
+/// parameter is met.
+///
+/// For example, a lamp might become a fire hazard only above a certain
+/// brightness.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionalHazard {
+    /// Hazard.
+    pub hazard: Hazard,
+    /// The condition which activates the hazard.
+    pub condition: Condition,
+}
+
+impl ConditionalHazard {
+    /// Creates a [`ConditionalHazard`].
+    #[must_use]
+    pub const fn new(hazard: Hazard, condition: Condition) -> Self {
+        Self { hazard, condition }
+    }
+}
+
+/// The name and description of a route in a specific [`Locale`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteTranslation {
+    /// Translated name.
+    pub name: Cow<'static, str>,
+    /// Translated description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub description: Option<Cow<'static, str>>,
+}
+
+impl RouteTranslation {
+    /// Creates a [`RouteTranslation`] with the given translated name.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+        }
+    }
+
+    /// Sets the translated description.
+    #[must_use]
+    pub fn description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+// The serialized form of route translations, keyed by any language tag.
+#[derive(Deserialize)]
+struct UncheckedRouteTranslations(IndexMap<String, RouteTranslation, DefaultHashBuilder>);
+
+map! {
+  /// A map of [`Locale`]s with the [`RouteTranslation`]s of a route.
+  ///
+  /// Translations in unsupported locales are ignored when deserialized.
+  #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+  #[serde(from = "UncheckedRouteTranslations")]
+  pub struct RouteTranslations(IndexMap<Locale, RouteTranslation, DefaultHashBuilder>);
+}
+
+impl From<UncheckedRouteTranslations> for RouteTranslations {
+    fn from(translations: UncheckedRouteTranslations) -> Self {
+        let mut checked = Self::new();
+        for (code, translation) in translations.0 {
+            // The first translation of a locale wins over the ones of its
+            // regional variants.
+            if let Some(locale) = Locale::from_code(&code) {
+                checked.0.entry(locale).or_insert(translation);
+            }
+        }
+        checked
+    }
+}
+
 /// Route data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RouteData {
@@ -52,6 +132,27 @@ pub struct RouteData {
     #[serde(skip_serializing_if = "Hazards::is_empty")]
     #[serde(default = "Hazards::new")]
     pub hazards: Hazards,
+    /// Levels of the route hazards.
+    ///
+    /// Hazards without a level are described only through their identity.
+    #[serde(rename = "hazard levels")]
+    #[serde(skip_serializing_if = "HazardLevels::is_empty")]
+    #[serde(default = "HazardLevels::new")]
+    pub hazard_levels: HazardLevels,
+    /// Hazards which occur only when a condition on the route input
+    /// parameters is met.
+    ///
+    /// They affect the policy decisions of a controller, so they are only
+    /// exchanged starting from the `2.0.0` protocol version, which controllers
+    /// ignoring them do not accept.
+    #[serde(rename = "conditional hazards")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default = "Vec::new")]
+    pub conditional_hazards: Vec<ConditionalHazard>,
+    /// Translations of the route name and description.
+    #[serde(skip_serializing_if = "RouteTranslations::is_empty")]
+    #[serde(default = "RouteTranslations::new")]
+    pub translations: RouteTranslations,
     /// Input parameters associated with a route.
     #[serde(skip_serializing_if = "ParametersData::is_empty")]
     #[serde(default = "ParametersData::new")]
@@ -65,15 +166,129 @@ impl PartialEq for RouteData {
 }
 
 impl RouteData {
+    /// Returns the route name in the given [`Locale`].
+    ///
+    /// When no translation is declared for the locale, the route name is
+    /// returned as is.
+    #[must_use]
+    pub fn localized_name(&self, locale: Locale) -> &str {
+        self.translations
+            .get(&locale)
+            .map_or(self.name.as_ref(), |translation| translation.name.as_ref())
+    }
+
+    /// Returns the route description in the given [`Locale`].
+    ///
+    /// When no translated description is declared for the locale, the route
+    /// description is returned as is.
+    #[must_use]
+    pub fn localized_description(&self, locale: Locale) -> Option<&str> {
+        self.translations
+            .get(&locale)
+            .and_then(|translation| translation.description.as_deref())
+            .or(self.description.as_deref())
+    }
+
+    /// Returns the [`Hazards`] of a route when it is requested with the
+    /// given input parameter values.
+    ///
+    /// Conditional hazards are evaluated on the default value of the
+    /// parameters which are not supplied.
+    #[must_use]
+    pub fn effective_hazards<N>(&self, supplied: &[(N, &ParameterValue)]) -> Hazards
+    where
+        N: AsRef<str>,
+    {
+        effective_hazards(
+            &self.hazards,
+            &self.conditional_hazards,
+            &self.parameters,
+            supplied,
+        )
+    }
+
     fn new(route: Route) -> Self {
+        let parameters = route.parameters.serialize_data();
+
+        let mut declared_conditional_hazards = Hazards::new();
+        for conditional_hazard in &route.conditional_hazards {
+            declared_conditional_hazards.add(conditional_hazard.hazard);
+        }
+
+        // Only the hazards conditioned on declared parameters, through a
+        // value admitted by their kind, are retained.
+        let mut conditional_hazards = Vec::new();
+        for conditional_hazard in route.conditional_hazards {
+            match parameters.get(conditional_hazard.condition.parameter.as_ref()) {
+                Some(parameter) if conditional_hazard.condition.is_valid_for(&parameter.kind) => {
+                    conditional_hazards.push(conditional_hazard);
+                }
+                Some(_) => error!(
+                    "Hazard conditioned on an invalid value, removed: {} when {}",
+                    conditional_hazard.hazard, conditional_hazard.condition
+                ),
+                None => error!(
+                    "Hazard conditioned on the undeclared parameter `{}`, removed: {}",
+                    conditional_hazard.condition.parameter, conditional_hazard.hazard
+                ),
+            }
+        }
+
+        // A level adds its hazard to the route hazards when the hazard has
+        // been declared neither as a route hazard nor as a conditional
+        // hazard, while the levels of discarded conditional hazards are
+        // discarded too.
+        let mut hazards = route.hazards;
+        let mut hazard_levels = HazardLevels::new();
+        for (hazard, level) in &route.hazard_levels {
+            if hazards.contains(hazard)
+                || conditional_hazards
+                    .iter()
+                    .any(|conditional_hazard| conditional_hazard.hazard == *hazard)
+            {
+                hazard_levels.add(*hazard, *level);
+            } else if !declared_conditional_hazards.contains(hazard) {
+                hazards.add(*hazard);
+                hazard_levels.add(*hazard, *level);
+            }
+        }
+
         Self {
             name: route.name.into(),
             path: route.path.into(),
             description: route.description.map(core::convert::Into::into),
-            hazards: route.hazards,
-            parameters: route.parameters.serialize_data(),
+            hazards,
+            hazard_levels,
+            conditional_hazards,
+            translations: route.translations,
+            parameters,
+        }
+    }
+}
+
+/// Computes the [`Hazards`] of a route when it is requested with the given
+/// input parameter values.
+///
+/// The result contains the unconditional hazards together with the
+/// [`ConditionalHazard`]s whose condition is met. Conditions are evaluated on
+/// the default value of the parameters which are not supplied.
+#[must_use]
+pub fn effective_hazards<N>(
+    hazards: &Hazards,
+    conditional_hazards: &[ConditionalHazard],
+    parameters: &ParametersData,
+    supplied: &[(N, &ParameterValue)],
+) -> Hazards
+where
+    N: AsRef<str>,
+{
+    let mut effective_hazards = hazards.clone();
+    for conditional_hazard in conditional_hazards {
+        if parameters.is_met(&conditional_hazard.condition, supplied) {
+            effective_hazards.add(conditional_hazard.hazard);
         }
     }
+    effective_hazards
 }
 
 /// A server route configuration.
@@ -149,6 +364,12 @@ pub struct Route {
     parameters: Parameters,
     // Hazards.
     hazards: Hazards,
+    // Hazard levels.
+    hazard_levels: HazardLevels,
+    // Hazards conditioned on the input parameters.
+    conditional_hazards: Vec<ConditionalHazard>,
+    // Translations of name and description.
+    translations: RouteTranslations,
 }
 
 impl PartialEq for Route {
@@ -240,6 +461,43 @@ impl Route {
         self
     }
 
+    /// Sets the [`HazardLevel`] of an [`Hazard`] of a [`Route`].
+    ///
+    /// If the hazard has been declared neither as a route hazard nor as a
+    /// conditional hazard, it is added to the route hazards when the route is
+    /// serialized, regardless of the order in which they are declared.
+    #[must_use]
+    #[inline]
+    pub fn with_hazard_level(mut self, hazard: Hazard, level: HazardLevel) -> Self {
+        self.hazard_levels.add(hazard, level);
+        self
+    }
+
+    /// Adds an [`Hazard`] to a [`Route`] which occurs only when the given
+    /// [`Condition`] on an input parameter is met.
+    ///
+    /// The level of the hazard can then be set through
+    /// [`Route::with_hazard_level`]. The hazard is discarded when the route
+    /// is serialized if the condition refers to an undeclared parameter, or
+    /// to a value which cannot be assumed by the parameter.
+    #[must_use]
+    #[inline]
+    pub fn with_conditional_hazard(mut self, hazard: Hazard, condition: Condition) -> Self {
+        self.conditional_hazards
+            .push(ConditionalHazard::new(hazard, condition));
+        self
+    }
+
+    /// Adds a [`RouteTranslation`] for the given [`Locale`] to a [`Route`].
+    ///
+    /// A translation replaces any previous one for the same locale.
+    #[must_use]
+    #[inline]
+    pub fn with_translation(mut self, locale: Locale, translation: RouteTranslation) -> Self {
+        self.translations.add(locale, translation);
+        self
+    }
+
     /// Adds [`Parameters`] to a [`Route`].
     #[must_use]
     #[inline]
@@ -266,6 +524,24 @@ impl Route {
         &self.hazards
     }
 
+    /// Returns [`HazardLevels`].
+    #[must_use]
+    pub const fn hazard_levels(&self) -> &HazardLevels {
+        &self.hazard_levels
+    }
+
+    /// Returns the [`ConditionalHazard`]s.
+    #[must_use]
+    pub fn conditional_hazards(&self) -> &[ConditionalHazard] {
+        &self.conditional_hazards
+    }
+
+    /// Returns [`RouteTranslations`].
+    #[must_use]
+    pub const fn translations(&self) -> &RouteTranslations {
+        &self.translations
+    }
+
     /// Returns [`Parameters`].
     #[must_use]
     pub const fn parameters(&self) -> &Parameters {
@@ -274,18 +550,48 @@ impl Route {
 
     /// Removes any prohibited [`Hazard`]s and returns an updated version of
     /// the [`Route`].
+    ///
+    /// Custom hazards are never removed, since they cannot be part of the
+    /// allowed hazards of a device profile.
     #[must_use]
     #[inline]
     pub fn remove_prohibited_hazards(mut self, allowed_hazards: &[Hazard]) -> Self {
+        let mut hazard_levels = HazardLevels::new();
+        for (hazard, level) in &self.hazard_levels {
+            if hazard.is_custom() || allowed_hazards.contains(hazard) {
+                hazard_levels.add(*hazard, *level);
+            } else if !self.hazards.contains(hazard)
+                && !self
+                    .conditional_hazards
+                    .iter()
+                    .any(|conditional_hazard| conditional_hazard.hazard == *hazard)
+            {
+                // Hazards declared only through their level.
+                error!("Hazards not allowed, removed: {hazard}");
+            }
+        }
+        self.hazard_levels = hazard_levels;
+
         let mut hazards = Hazards::new();
         for hazard in self.hazards {
-            if allowed_hazards.contains(&hazard) {
+            if hazard.is_custom() || allowed_hazards.contains(&hazard) {
                 hazards.add(hazard);
             } else {
                 error!("Hazards not allowed, removed: {hazard}");
             }
         }
         self.hazards = hazards;
+        self.conditional_hazards.retain(|conditional_hazard| {
+            let allowed = conditional_hazard.hazard.is_custom()
+                || allowed_hazards.contains(&conditional_hazard.hazard);
+            if !allowed {
+                error!(
+                    "Conditional hazard not allowed, removed: {}",
+                    conditional_hazard.hazard
+                );
+            }
+            allowed
+        });
         self
     }
 
@@ -305,6 +611,9 @@ impl Route {
             rest_kind,
             description: None,
             hazards: Hazards::new(),
+            hazard_levels: HazardLevels::new(),
+            conditional_hazards: Vec::new(),
+            translations: RouteTranslations::new(),
             parameters: Parameters::new(),
         }
     }
@@ -318,15 +627,41 @@ set! {
 
 mandatory_route!(LightOnRoute, "/on", methods: [post, put]);
 mandatory_route!(LightOffRoute, "/off", methods: [post, put]);
+mandatory_route!(
+    ThermostatTargetTemperatureRoute,
+    "/target-temperature",
+    methods: [post, put]
+);
+mandatory_route!(
+    ThermostatCurrentTemperatureRoute,
+    "/current-temperature",
+    methods: [get]
+);
+mandatory_route!(CameraStreamRoute, "/stream", methods: [get]);
+mandatory_route!(CameraSnapshotRoute, "/snapshot", methods: [get]);
+mandatory_route!(SmartPlugOnRoute, "/on", methods: [post, put]);
+mandatory_route!(SmartPlugOffRoute, "/off", methods: [post, put]);
+mandatory_route!(SmartPlugMeasurementRoute, "/measurement", methods: [get]);
+mandatory_route!(DoorLockLockRoute, "/lock", methods: [post, put]);
+mandatory_route!(DoorLockUnlockRoute, "/unlock", methods: [post, put]);
+mandatory_route!(DoorLockStatusRoute, "/status", methods: [get]);
 
 #[cfg(test)]
 mod tests {
-    use crate::hazards::{Hazard, Hazards};
-    use crate::parameters::{ParameterKind, Parameters, ParametersData};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::hazards::{Hazard, HazardLevel, HazardLevels, Hazards, Locale, Severity};
+    use crate::parameters::{
+        Comparison, Condition, ParameterKind, ParameterValue, Parameters, ParametersData,
+    };
     use crate::response::ResponseKind;
     use crate::{deserialize, serialize};
 
-    use super::{RestKind, Route, RouteConfig, RouteData};
+    use super::{
+        ConditionalHazard, RestKind, Route, RouteConfig, RouteData, RouteTranslation,
+        RouteTranslations,
+    };
 
     fn route_config_empty(rest_kind: RestKind, desc: &'static str) -> RouteConfig {
         route_config_hazards(rest_kind, Hazards::new(), desc)
@@ -354,6 +689,9 @@ mod tests {
                 path: "/route".into(),
                 description: Some(desc.into()),
                 hazards,
+                hazard_levels: HazardLevels::new(),
+                conditional_hazards: Vec::new(),
+                translations: RouteTranslations::new(),
                 parameters,
             },
         }
@@ -451,6 +789,232 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hazard_levels() {
+        let route = Route::put("Route", "/route")
+            .with_hazard(Hazard::ElectricEnergyConsumption)
+            .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .serialize_data();
+
+        // The hazard is declared together with its level.
+        assert_eq!(
+            route.data.hazards,
+            Hazards::new()
+                .insert(Hazard::ElectricEnergyConsumption)
+                .insert(Hazard::FireHazard)
+        );
+        assert_eq!(
+            route.data.hazard_levels,
+            HazardLevels::init(Hazard::FireHazard, HazardLevel::new(Severity::High))
+        );
+
+        let value = serialize(&route);
+        assert_eq!(
+            value["hazard levels"],
+            serde_json::json!([{ "hazard": "FireHazard", "level": { "severity": "High" } }])
+        );
+        assert_eq!(
+            deserialize::<RouteConfig>(value).data.hazard_levels,
+            route.data.hazard_levels
+        );
+
+        // The hazard is declared regardless of the order of the calls.
+        let level_first = Route::put("Route", "/route")
+            .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .with_hazard(Hazard::ElectricEnergyConsumption)
+            .serialize_data();
+        assert_eq!(level_first.data.hazards, route.data.hazards);
+        assert_eq!(level_first.data.hazard_levels, route.data.hazard_levels);
+
+        // A level set before its conditional hazard does not make the hazard
+        // unconditional.
+        let route = Route::put("Route", "/route")
+            .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .with_conditional_hazard(
+                Hazard::FireHazard,
+                Condition::new("brightness", Comparison::Greater, 80u8),
+            )
+            .with_parameters(Parameters::new().u8("brightness", 50))
+            .serialize_data();
+        assert!(route.data.hazards.is_empty());
+        assert_eq!(
+            route.data.hazard_levels,
+            HazardLevels::init(Hazard::FireHazard, HazardLevel::new(Severity::High))
+        );
+
+        // Levels of discarded conditional hazards are discarded too.
+        let route = Route::put("Route", "/route")
+            .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .with_conditional_hazard(
+                Hazard::FireHazard,
+                Condition::new("missing", Comparison::Equal, true),
+            )
+            .serialize_data();
+        assert!(route.data.hazards.is_empty());
+        assert!(route.data.hazard_levels.is_empty());
+
+        // Levels of prohibited hazards are removed together with them.
+        let route = Route::put("Route", "/route")
+            .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .remove_prohibited_hazards(&[Hazard::ElectricEnergyConsumption])
+            .serialize_data();
+        assert!(route.data.hazards.is_empty());
+        assert!(route.data.hazard_levels.is_empty());
+
+        // Routes without levels are still accepted.
+        let route = deserialize::<RouteConfig>(serde_json::json!({
+            "name": "Route",
+            "path": "/route",
+            "description": null,
+            "hazards": ["FireHazard"],
+            "REST kind": "Put",
+            "response kind": "Ok",
+        }));
+        assert!(route.data.hazard_levels.is_empty());
+    }
+
+    #[test]
+    fn test_conditional_hazards() {
+        let route = Route::put("Brightness", "/brightness")
+            .with_hazard(Hazard::ElectricEnergyConsumption)
+            .with_conditional_hazard(
+                Hazard::FireHazard,
+                Condition::new("brightness", Comparison::Greater, 80u8),
+            )
+            .with_hazard_level(Hazard::FireHazard, HazardLevel::new(Severity::High))
+            .with_conditional_hazard(
+                Hazard::WaterFlooding,
+                Condition::new("missing", Comparison::Equal, true),
+            )
+            .with_conditional_hazard(
+                Hazard::SpendMoney,
+                Condition::new("brightness", Comparison::Greater, "eighty"),
+            )
+            .with_conditional_hazard(
+                Hazard::TakePictures,
+                Condition::new("mode", Comparison::Equal, "turbo"),
+            )
+            .with_conditional_hazard(
+                Hazard::VideoDisplay,
+                Condition::new("mode", Comparison::Greater, "eco"),
+            )
+            .with_parameters(
+                Parameters::new()
+                    .u8("brightness", 50)
+                    .choice("mode", &["eco", "boost"]),
+            )
+            .serialize_data();
+
+        // Hazards conditioned on undeclared parameters, or on values which
+        // cannot be assumed by a parameter, are discarded.
+        assert_eq!(
+            route.data.conditional_hazards,
+            vec![ConditionalHazard::new(
+                Hazard::FireHazard,
+                Condition::new("brightness", Comparison::Greater, 80u8),
+            )]
+        );
+
+        let value = serialize(&route);
+        assert_eq!(
+            value["conditional hazards"],
+            serde_json::json!([{
+                "hazard": "FireHazard",
+                "condition": {
+                    "parameter": "brightness",
+                    "comparison": "Greater",
+                    "value": "80",
+                },
+            }])
+        );
+        let deserialized = deserialize::<RouteConfig>(value);
+        assert_eq!(
+            deserialized.data.conditional_hazards,
+            route.data.conditional_hazards
+        );
+        assert_eq!(deserialized.data.hazard_levels, route.data.hazard_levels);
+
+        // Conditional hazards occur only when their condition is met.
+        let supplied: [(&str, &ParameterValue); 0] = [];
+        assert_eq!(
+            route.data.effective_hazards(&supplied),
+            Hazards::new().insert(Hazard::ElectricEnergyConsumption)
+        );
+        assert_eq!(
+            route
+                .data
+                .effective_hazards(&[("brightness", &ParameterValue::U8(90))]),
+            Hazards::new()
+                .insert(Hazard::ElectricEnergyConsumption)
+                .insert(Hazard::FireHazard)
+        );
+
+        // Prohibited conditional hazards are removed.
+        let route = Route::put("Brightness", "/brightness")
+            .with_conditional_hazard(
+                Hazard::TakePictures,
+                Condition::new("brightness", Comparison::Greater, 80u8),
+            )
+            .remove_prohibited_hazards(&[Hazard::FireHazard]);
+        assert!(route.conditional_hazards().is_empty());
+    }
+
+    #[test]
+    fn test_translations() {
+        let route = Route::put("Brightness", "/brightness")
+            .description("Sets the lamp brightness")
+            .with_translation(
+                Locale::Italian,
+                RouteTranslation::new("Luminosità")
+                    .description("Imposta la luminosità della lampada"),
+            )
+            .with_translation(Locale::German, RouteTranslation::new("Helligkeit"))
+            .serialize_data();
+
+        let value = serialize(&route);
+        assert_eq!(
+            value["translations"],
+            serde_json::json!({
+                "it": {
+                    "name": "Luminosità",
+                    "description": "Imposta la luminosità della lampada",
+                },
+                "de": {
+                    "name": "Helligkeit",
+                },
+            })
+        );
+        let deserialized = deserialize::<RouteConfig>(value.clone());
+        assert_eq!(deserialized.data.translations, route.data.translations);
+
+        // Translations in unsupported locales are ignored.
+        let mut value = value;
+        value["translations"]["fr"] = serde_json::json!({ "name": "Luminosité" });
+        assert_eq!(
+            deserialize::<RouteConfig>(value).data.translations,
+            route.data.translations
+        );
+
+        assert_eq!(route.data.localized_name(Locale::Italian), "Luminosità");
+        assert_eq!(
+            route.data.localized_description(Locale::Italian),
+            Some("Imposta la luminosità della lampada")
+        );
+
+        // Missing translations fall back to the declared strings.
+        assert_eq!(route.data.localized_name(Locale::German), "Helligkeit");
+        assert_eq!(
+            route.data.localized_description(Locale::German),
+            Some("Sets the lamp brightness")
+        );
+        assert_eq!(route.data.localized_name(Locale::English), "Brightness");
+
+        // Routes without translations do not serialize them.
+        let route = Route::get("Route", "/route").serialize_data();
+        assert!(serialize(&route).get("translations").is_none());
+        assert_eq!(route.data.translations, RouteTranslations::new());
+    }
+
     #[test]
     fn test_all_parameters() {
         let expected = route_config_parameters(
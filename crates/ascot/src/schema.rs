@@ -0,0 +1,1170 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde_json::{Map, Value, json};
+
+use crate::parameters::{
+    Color, ParameterData, ParameterKind, ParameterValue, Parameters, ParametersData, in_interval,
+    is_aligned,
+};
+
+/// The `JSON Schema` dialect of the generated schemas.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+// Keywords which change the meaning of a schema, but cannot be represented
+// by a parameter.
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "$ref",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+    "if",
+    "const",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "minLength",
+    "pattern",
+    "uniqueItems",
+];
+
+/// The cause of a [`SchemaError`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaErrorKind {
+    /// A schema is not a `JSON` object.
+    NotAnObject,
+    /// A schema declares a type which cannot be represented by a parameter.
+    UnsupportedType(String),
+    /// A schema contains a keyword which cannot be represented by a
+    /// parameter.
+    UnsupportedKeyword(String),
+    /// A mandatory keyword is missing.
+    MissingKeyword(&'static str),
+    /// A keyword has an invalid value.
+    InvalidKeyword(&'static str),
+}
+
+/// An error raised when a `JSON Schema` cannot be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaError {
+    /// The location of the offending schema, expressed as a `JSON Pointer`.
+    pub pointer: String,
+    /// Error cause.
+    pub kind: SchemaErrorKind,
+}
+
+impl core::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "`#{}`: ", self.pointer)?;
+        match &self.kind {
+            SchemaErrorKind::NotAnObject => "the schema must be an object".fmt(f),
+            SchemaErrorKind::UnsupportedType(kind) => {
+                write!(f, "the `{kind}` type is not supported")
+            }
+            SchemaErrorKind::UnsupportedKeyword(keyword) => {
+                write!(f, "the `{keyword}` keyword is not supported")
+            }
+            SchemaErrorKind::MissingKeyword(keyword) => {
+                write!(f, "the `{keyword}` keyword is missing")
+            }
+            SchemaErrorKind::InvalidKeyword(keyword) => {
+                write!(f, "the `{keyword}` keyword has an invalid value")
+            }
+        }
+    }
+}
+
+impl SchemaError {
+    fn new(pointer: &str, kind: SchemaErrorKind) -> Self {
+        Self {
+            pointer: pointer.into(),
+            kind,
+        }
+    }
+}
+
+// Inserts a bound into a schema only when it differs from the type limit,
+// since a missing bound already admits all values of a type.
+fn insert_bound<T: Copy + PartialEq + Into<Value>>(
+    schema: &mut Map<String, Value>,
+    key: &str,
+    bound: T,
+    limit: T,
+) {
+    if bound != limit {
+        schema.insert(key.into(), bound.into());
+    }
+}
+
+// Inserts the bounds of a value into a schema.
+//
+// A minimum value greater than the maximum one admits all values of a type,
+// hence no bound is inserted.
+fn insert_bounds<T: Copy + PartialOrd + Into<Value>>(
+    schema: &mut Map<String, Value>,
+    keys: (&str, &str),
+    bounds: (T, T),
+    limits: (T, T),
+) {
+    if bounds.0 <= bounds.1 {
+        insert_bound(schema, keys.0, bounds.0, limits.0);
+        insert_bound(schema, keys.1, bounds.1, limits.1);
+    }
+}
+
+fn integer_schema<T: Copy + PartialOrd + Into<Value>>(
+    format: &str,
+    default: T,
+    min: T,
+    max: T,
+    limits: (T, T),
+) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".into(), "integer".into());
+    schema.insert("format".into(), format.into());
+    insert_bounds(&mut schema, ("minimum", "maximum"), (min, max), limits);
+    schema.insert("default".into(), default.into());
+    schema
+}
+
+fn number_schema<T: Copy + PartialOrd + Into<Value>>(
+    format: &str,
+    default: T,
+    min: T,
+    max: T,
+    step: Option<T>,
+    limits: (T, T),
+) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".into(), "number".into());
+    schema.insert("format".into(), format.into());
+    insert_bounds(&mut schema, ("minimum", "maximum"), (min, max), limits);
+    // The step of a number is relative to zero, as `multipleOf` is.
+    if let Some(step) = step {
+        schema.insert("multipleOf".into(), step.into());
+    }
+    schema.insert("default".into(), default.into());
+    schema
+}
+
+// The step of a range is relative to its minimum value, hence it is
+// expressed through `multipleOf` only when the minimum value is a multiple
+// of the step. Otherwise, the `x-ascot-step` extension is adopted.
+fn range_schema<T: Into<Value>>(
+    kind: &str,
+    format: &str,
+    default: T,
+    min: T,
+    max: T,
+    step: Option<(T, bool)>,
+) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".into(), kind.into());
+    schema.insert("format".into(), format.into());
+    schema.insert("minimum".into(), min.into());
+    schema.insert("maximum".into(), max.into());
+    if let Some((step, aligned)) = step {
+        let keyword = if aligned {
+            "multipleOf"
+        } else {
+            "x-ascot-step"
+        };
+        schema.insert(keyword.into(), step.into());
+    }
+    schema.insert("default".into(), default.into());
+    schema
+}
+
+fn range_kind_schema(kind: &ParameterKind) -> Map<String, Value> {
+    match kind {
+        ParameterKind::RangeU64 {
+            min,
+            max,
+            step,
+            default,
+        } => range_schema(
+            "integer",
+            "uint64",
+            *default,
+            *min,
+            *max,
+            (*step > 0).then(|| (*step, *min % *step == 0)),
+        ),
+        ParameterKind::RangeI64 {
+            min,
+            max,
+            step,
+            default,
+        } => range_schema(
+            "integer",
+            "int64",
+            *default,
+            *min,
+            *max,
+            (*step > 0).then(|| (*step, *min % *step == 0)),
+        ),
+        ParameterKind::RangeF64 {
+            min,
+            max,
+            step,
+            default,
+        } => range_schema(
+            "number",
+            "double",
+            *default,
+            *min,
+            *max,
+            (*step > 0.).then(|| (*step, is_aligned(*min, 0., *step, f64::EPSILON * 16.))),
+        ),
+        _ => Map::new(),
+    }
+}
+
+fn string_schema(kind: &ParameterKind) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".into(), "string".into());
+
+    match kind {
+        ParameterKind::CharsSequence { default, length } => {
+            // A length of `0` admits sequences of any length.
+            if *length > 0 {
+                schema.insert("maxLength".into(), (*length).into());
+            }
+            schema.insert("default".into(), default.as_ref().into());
+        }
+        ParameterKind::Choice { default, choices } => {
+            schema.insert(
+                "enum".into(),
+                choices
+                    .iter()
+                    .map(|choice| Value::from(choice.as_ref()))
+                    .collect(),
+            );
+            schema.insert("default".into(), default.as_ref().into());
+        }
+        ParameterKind::Color {
+            default,
+            min_kelvin,
+            max_kelvin,
+        } => {
+            schema.insert("default".into(), default.to_string().into());
+            schema.insert(
+                "x-ascot-color-model".into(),
+                default.model().to_str().into(),
+            );
+            insert_bounds(
+                &mut schema,
+                ("x-ascot-min-kelvin", "x-ascot-max-kelvin"),
+                (*min_kelvin, *max_kelvin),
+                (u16::MIN, u16::MAX),
+            );
+        }
+        _ => {}
+    }
+
+    schema
+}
+
+/// Converts a [`ParameterKind`] into a `JSON Schema`.
+///
+/// Limits, steps, lengths, and default values are described through the
+/// standard `JSON Schema` keywords, while the `format` keyword retains the
+/// exact numeric type. The properties which have no standard counterpart,
+/// such as the color model, are described through `x-ascot-` extensions.
+#[must_use]
+pub fn parameter_kind_schema(kind: &ParameterKind) -> Value {
+    let schema = match kind {
+        ParameterKind::Bool { default } => {
+            let mut schema = Map::new();
+            schema.insert("type".into(), "boolean".into());
+            schema.insert("default".into(), (*default).into());
+            schema
+        }
+        ParameterKind::U8 { default, min, max } => {
+            integer_schema("uint8", *default, *min, *max, (u8::MIN, u8::MAX))
+        }
+        ParameterKind::U16 { default, min, max } => {
+            integer_schema("uint16", *default, *min, *max, (u16::MIN, u16::MAX))
+        }
+        ParameterKind::U32 { default, min, max } => {
+            integer_schema("uint32", *default, *min, *max, (u32::MIN, u32::MAX))
+        }
+        ParameterKind::U64 { default, min, max } => {
+            integer_schema("uint64", *default, *min, *max, (u64::MIN, u64::MAX))
+        }
+        ParameterKind::I8 { default, min, max } => {
+            integer_schema("int8", *default, *min, *max, (i8::MIN, i8::MAX))
+        }
+        ParameterKind::I16 { default, min, max } => {
+            integer_schema("int16", *default, *min, *max, (i16::MIN, i16::MAX))
+        }
+        ParameterKind::I32 { default, min, max } => {
+            integer_schema("int32", *default, *min, *max, (i32::MIN, i32::MAX))
+        }
+        ParameterKind::I64 { default, min, max } => {
+            integer_schema("int64", *default, *min, *max, (i64::MIN, i64::MAX))
+        }
+        ParameterKind::F32 {
+            default,
+            min,
+            max,
+            step,
+        } => number_schema(
+            "float",
+            *default,
+            *min,
+            *max,
+            (*step > 0.).then_some(*step),
+            (f32::MIN, f32::MAX),
+        ),
+        ParameterKind::F64 {
+            default,
+            min,
+            max,
+            step,
+        } => number_schema(
+            "double",
+            *default,
+            *min,
+            *max,
+            (*step > 0.).then_some(*step),
+            (f64::MIN, f64::MAX),
+        ),
+        ParameterKind::RangeU64 { .. }
+        | ParameterKind::RangeI64 { .. }
+        | ParameterKind::RangeF64 { .. } => range_kind_schema(kind),
+        ParameterKind::CharsSequence { .. }
+        | ParameterKind::Choice { .. }
+        | ParameterKind::Color { .. } => string_schema(kind),
+        ParameterKind::List {
+            item,
+            min_len,
+            max_len,
+        } => {
+            let mut schema = Map::new();
+            schema.insert("type".into(), "array".into());
+            schema.insert("items".into(), parameter_kind_schema(item));
+            insert_bound(&mut schema, "minItems", *min_len, usize::MIN);
+            insert_bound(&mut schema, "maxItems", *max_len, usize::MAX);
+            schema
+        }
+        ParameterKind::Object { fields } => {
+            let mut schema = object_schema(fields);
+            // All fields must be present in an object value.
+            schema.insert(
+                "required".into(),
+                fields
+                    .iter()
+                    .map(|(name, _)| Value::from(name.as_str()))
+                    .collect(),
+            );
+            schema
+        }
+    };
+
+    Value::Object(schema)
+}
+
+/// Converts a [`ParameterData`] into a `JSON Schema`.
+///
+/// The label and the description of a parameter become the `title` and the
+/// `description` of the schema, while its measurement unit is described
+/// through the `x-ascot-unit` extension.
+#[must_use]
+pub fn parameter_schema(parameter: &ParameterData) -> Value {
+    let mut schema = parameter_kind_schema(&parameter.kind);
+
+    if let Value::Object(schema) = &mut schema {
+        if let Some(label) = &parameter.label {
+            schema.insert("title".into(), label.as_ref().into());
+        }
+        if let Some(description) = &parameter.description {
+            schema.insert("description".into(), description.as_ref().into());
+        }
+        if let Some(unit) = &parameter.unit {
+            schema.insert("x-ascot-unit".into(), json!(unit));
+        }
+    }
+
+    schema
+}
+
+// Builds the schema of an object whose properties are the given parameters.
+//
+// Only the required parameters are listed as required properties, since
+// the others can be replaced by their default values.
+pub(crate) fn object_schema(parameters: &ParametersData) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".into(), "object".into());
+    schema.insert(
+        "properties".into(),
+        parameters
+            .iter()
+            .map(|(name, parameter)| (name.clone(), parameter_schema(parameter)))
+            .collect(),
+    );
+    schema.insert(
+        "required".into(),
+        parameters
+            .iter()
+            .filter(|(_, parameter)| parameter.required)
+            .map(|(name, _)| Value::from(name.as_str()))
+            .collect(),
+    );
+    // Undeclared parameters are rejected by a device.
+    schema.insert("additionalProperties".into(), false.into());
+    schema
+}
+
+/// Converts [`ParametersData`] into a `JSON Schema` describing an object
+/// which contains the input parameters of a route.
+#[must_use]
+pub fn parameters_schema(parameters: &ParametersData) -> Value {
+    let mut schema = object_schema(parameters);
+    schema.insert("$schema".into(), JSON_SCHEMA_DIALECT.into());
+    Value::Object(schema)
+}
+
+// Appends a segment to a `JSON Pointer`, escaping its special characters.
+fn child_pointer(pointer: &str, segment: &str) -> String {
+    format!(
+        "{pointer}/{}",
+        segment.replace('~', "~0").replace('/', "~1")
+    )
+}
+
+fn as_object<'a>(schema: &'a Value, pointer: &str) -> Result<&'a Map<String, Value>, SchemaError> {
+    let schema = schema
+        .as_object()
+        .ok_or_else(|| SchemaError::new(pointer, SchemaErrorKind::NotAnObject))?;
+
+    if let Some(keyword) = UNSUPPORTED_KEYWORDS
+        .iter()
+        .find(|keyword| schema.contains_key(**keyword))
+    {
+        return Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::UnsupportedKeyword((*keyword).into()),
+        ));
+    }
+
+    Ok(schema)
+}
+
+// Retrieves the value of a keyword, if present.
+fn keyword<'a, T>(
+    schema: &'a Map<String, Value>,
+    pointer: &str,
+    keyword: &'static str,
+    convert: impl FnOnce(&'a Value) -> Option<T>,
+) -> Result<Option<T>, SchemaError> {
+    schema
+        .get(keyword)
+        .map(|value| {
+            convert(value)
+                .ok_or_else(|| SchemaError::new(pointer, SchemaErrorKind::InvalidKeyword(keyword)))
+        })
+        .transpose()
+}
+
+fn as_integer<T: TryFrom<i128>>(value: &Value) -> Option<T> {
+    value
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| value.as_u64().map(i128::from))
+        .and_then(|value| T::try_from(value).ok())
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn as_f32(value: &Value) -> Option<f32> {
+    value.as_f64().map(|value| value as f32)
+}
+
+// Retrieves the bounds of a value.
+//
+// When both bounds are missing, the minimum value is greater than the
+// maximum one, so that all values of a type are admitted, as the
+// parameters declared without limits do.
+fn bounds<T: Copy + PartialOrd>(
+    schema: &Map<String, Value>,
+    pointer: &str,
+    keys: (&'static str, &'static str),
+    limits: (T, T),
+    convert: fn(&Value) -> Option<T>,
+) -> Result<(T, T), SchemaError> {
+    match (
+        keyword(schema, pointer, keys.0, convert)?,
+        keyword(schema, pointer, keys.1, convert)?,
+    ) {
+        (None, None) => Ok((limits.1, limits.0)),
+        (min, max) => ordered_bounds(
+            pointer,
+            keys.0,
+            min.unwrap_or(limits.0),
+            max.unwrap_or(limits.1),
+        ),
+    }
+}
+
+// Rejects a minimum value greater than the maximum one.
+fn ordered_bounds<T: PartialOrd>(
+    pointer: &str,
+    key: &'static str,
+    min: T,
+    max: T,
+) -> Result<(T, T), SchemaError> {
+    if min > max {
+        Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::InvalidKeyword(key),
+        ))
+    } else {
+        Ok((min, max))
+    }
+}
+
+// Rejects a kind whose default value does not satisfy its own constraints.
+fn valid_default(kind: ParameterKind, pointer: &str) -> Result<ParameterKind, SchemaError> {
+    match kind.validate(&ParameterValue::from_parameter_kind(&kind)) {
+        Ok(()) => Ok(kind),
+        Err(_) => Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::InvalidKeyword("default"),
+        )),
+    }
+}
+
+// Retrieves the bounds and the default value of a number.
+//
+// When a schema does not declare a default value, zero is adopted if it is
+// admitted, the minimum value otherwise.
+fn number_values<T: Default + PartialOrd + Copy>(
+    schema: &Map<String, Value>,
+    pointer: &str,
+    limits: (T, T),
+    convert: fn(&Value) -> Option<T>,
+) -> Result<(T, T, T), SchemaError> {
+    let (min, max) = bounds(schema, pointer, ("minimum", "maximum"), limits, convert)?;
+    let default = keyword(schema, pointer, "default", convert)?.unwrap_or_else(|| {
+        let zero = T::default();
+        if in_interval(zero, min, max) {
+            zero
+        } else {
+            min
+        }
+    });
+    Ok((min, max, default))
+}
+
+// Retrieves the bounds of a range, which are always declared.
+fn range_bounds<T: Copy + PartialOrd>(
+    schema: &Map<String, Value>,
+    pointer: &str,
+    limits: (T, T),
+    convert: fn(&Value) -> Option<T>,
+) -> Result<(T, T), SchemaError> {
+    ordered_bounds(
+        pointer,
+        "minimum",
+        keyword(schema, pointer, "minimum", convert)?.unwrap_or(limits.0),
+        keyword(schema, pointer, "maximum", convert)?.unwrap_or(limits.1),
+    )
+}
+
+fn integer_kind(schema: &Map<String, Value>, pointer: &str) -> Result<ParameterKind, SchemaError> {
+    let format = keyword(schema, pointer, "format", Value::as_str)?;
+
+    // A step relative to zero, or relative to the minimum value.
+    let step = match keyword(schema, pointer, "multipleOf", as_integer::<u64>)? {
+        Some(step) => Some((step, false)),
+        None => {
+            keyword(schema, pointer, "x-ascot-step", as_integer::<u64>)?.map(|step| (step, true))
+        }
+    };
+
+    if let Some((step, from_min)) = step.filter(|(step, _)| *step > 0) {
+        return match format {
+            Some("uint64") => {
+                let (min, max) = range_bounds(schema, pointer, (u64::MIN, u64::MAX), as_integer)?;
+                // A range starts from its first multiple of the step.
+                let min = if from_min || min % step == 0 {
+                    Some(min)
+                } else {
+                    min.checked_add(step - min % step)
+                }
+                .ok_or_else(|| {
+                    SchemaError::new(pointer, SchemaErrorKind::InvalidKeyword("minimum"))
+                })?;
+                let default = keyword(schema, pointer, "default", as_integer)?.unwrap_or(min);
+                Ok(ParameterKind::RangeU64 {
+                    min,
+                    max,
+                    step,
+                    default,
+                })
+            }
+            Some("int64") | None => {
+                let step = i64::try_from(step).map_err(|_| {
+                    SchemaError::new(pointer, SchemaErrorKind::InvalidKeyword("multipleOf"))
+                })?;
+                let (min, max) = range_bounds(schema, pointer, (i64::MIN, i64::MAX), as_integer)?;
+                let remainder = min.rem_euclid(step);
+                let min = if from_min || remainder == 0 {
+                    Some(min)
+                } else {
+                    min.checked_add(step - remainder)
+                }
+                .ok_or_else(|| {
+                    SchemaError::new(pointer, SchemaErrorKind::InvalidKeyword("minimum"))
+                })?;
+                let default = keyword(schema, pointer, "default", as_integer)?.unwrap_or(min);
+                Ok(ParameterKind::RangeI64 {
+                    min,
+                    max,
+                    step,
+                    default,
+                })
+            }
+            Some(_) => Err(SchemaError::new(
+                pointer,
+                SchemaErrorKind::InvalidKeyword("multipleOf"),
+            )),
+        };
+    }
+
+    macro_rules! integer {
+        ($kind:ident, $ty:ty) => {{
+            let (min, max, default) =
+                number_values(schema, pointer, (<$ty>::MIN, <$ty>::MAX), as_integer)?;
+            Ok(ParameterKind::$kind { default, min, max })
+        }};
+    }
+
+    match format {
+        Some("uint8") => integer!(U8, u8),
+        Some("uint16") => integer!(U16, u16),
+        Some("uint32") => integer!(U32, u32),
+        Some("uint64") => integer!(U64, u64),
+        Some("int8") => integer!(I8, i8),
+        Some("int16") => integer!(I16, i16),
+        Some("int32") => integer!(I32, i32),
+        Some("int64") | None => integer!(I64, i64),
+        Some(_) => Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::InvalidKeyword("format"),
+        )),
+    }
+}
+
+fn number_kind(schema: &Map<String, Value>, pointer: &str) -> Result<ParameterKind, SchemaError> {
+    match keyword(schema, pointer, "format", Value::as_str)? {
+        Some("float") => {
+            let (min, max, default) = number_values(schema, pointer, (f32::MIN, f32::MAX), as_f32)?;
+            let step = keyword(schema, pointer, "multipleOf", as_f32)?.unwrap_or(0.);
+            Ok(ParameterKind::F32 {
+                default,
+                min,
+                max,
+                step,
+            })
+        }
+        Some("double") | None => {
+            // A step relative to the minimum value denotes a range.
+            if let Some(step) = keyword(schema, pointer, "x-ascot-step", Value::as_f64)? {
+                let (min, max) =
+                    range_bounds(schema, pointer, (f64::MIN, f64::MAX), Value::as_f64)?;
+                let default = keyword(schema, pointer, "default", Value::as_f64)?.unwrap_or(min);
+                return Ok(ParameterKind::RangeF64 {
+                    min,
+                    max,
+                    step,
+                    default,
+                });
+            }
+            let (min, max, default) =
+                number_values(schema, pointer, (f64::MIN, f64::MAX), Value::as_f64)?;
+            let step = keyword(schema, pointer, "multipleOf", Value::as_f64)?.unwrap_or(0.);
+            Ok(ParameterKind::F64 {
+                default,
+                min,
+                max,
+                step,
+            })
+        }
+        Some(_) => Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::InvalidKeyword("format"),
+        )),
+    }
+}
+
+fn string_kind(schema: &Map<String, Value>, pointer: &str) -> Result<ParameterKind, SchemaError> {
+    let default = keyword(schema, pointer, "default", Value::as_str)?;
+
+    if let Some(model) = keyword(schema, pointer, "x-ascot-color-model", Value::as_str)? {
+        let default = default
+            .ok_or_else(|| SchemaError::new(pointer, SchemaErrorKind::MissingKeyword("default")))?
+            .parse::<Color>()
+            .map_err(|_| SchemaError::new(pointer, SchemaErrorKind::InvalidKeyword("default")))?;
+        if default.model().to_str() != model {
+            return Err(SchemaError::new(
+                pointer,
+                SchemaErrorKind::InvalidKeyword("x-ascot-color-model"),
+            ));
+        }
+        let (min_kelvin, max_kelvin) = bounds(
+            schema,
+            pointer,
+            ("x-ascot-min-kelvin", "x-ascot-max-kelvin"),
+            (u16::MIN, u16::MAX),
+            as_integer,
+        )?;
+        return Ok(ParameterKind::Color {
+            default,
+            min_kelvin,
+            max_kelvin,
+        });
+    }
+
+    if let Some(choices) = keyword(schema, pointer, "enum", |value| {
+        value
+            .as_array()
+            .filter(|choices| !choices.is_empty())?
+            .iter()
+            .map(|choice| choice.as_str().map(|choice| Cow::Owned(choice.into())))
+            .collect::<Option<Vec<_>>>()
+    })? {
+        let default = match default {
+            Some(default) if choices.iter().any(|choice| choice == default) => {
+                Cow::Owned(default.into())
+            }
+            Some(_) => {
+                return Err(SchemaError::new(
+                    pointer,
+                    SchemaErrorKind::InvalidKeyword("default"),
+                ));
+            }
+            None => choices[0].clone(),
+        };
+        return Ok(ParameterKind::Choice { default, choices });
+    }
+
+    Ok(ParameterKind::CharsSequence {
+        default: Cow::Owned(default.unwrap_or_default().into()),
+        length: keyword(schema, pointer, "maxLength", as_integer)?.unwrap_or(0),
+    })
+}
+
+fn kind_from_schema(schema: &Value, pointer: &str) -> Result<ParameterKind, SchemaError> {
+    let schema = as_object(schema, pointer)?;
+
+    match keyword(schema, pointer, "type", Value::as_str)? {
+        Some("boolean") => Ok(ParameterKind::Bool {
+            default: keyword(schema, pointer, "default", Value::as_bool)?.unwrap_or(false),
+        }),
+        Some("integer") => valid_default(integer_kind(schema, pointer)?, pointer),
+        Some("number") => valid_default(number_kind(schema, pointer)?, pointer),
+        Some("string") => valid_default(string_kind(schema, pointer)?, pointer),
+        Some("array") => {
+            let item = schema.get("items").ok_or_else(|| {
+                SchemaError::new(pointer, SchemaErrorKind::MissingKeyword("items"))
+            })?;
+            Ok(ParameterKind::List {
+                item: Box::new(kind_from_schema(item, &child_pointer(pointer, "items"))?),
+                min_len: keyword(schema, pointer, "minItems", as_integer)?.unwrap_or(usize::MIN),
+                max_len: keyword(schema, pointer, "maxItems", as_integer)?.unwrap_or(usize::MAX),
+            })
+        }
+        Some("object") => Ok(ParameterKind::Object {
+            // All fields of an object are always required.
+            fields: parameters_from_object(schema, pointer, &[])?,
+        }),
+        Some(kind) => Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::UnsupportedType(kind.into()),
+        )),
+        None => Err(SchemaError::new(
+            pointer,
+            SchemaErrorKind::MissingKeyword("type"),
+        )),
+    }
+}
+
+fn parameter_from_schema(schema: &Value, pointer: &str) -> Result<ParameterData, SchemaError> {
+    let mut parameter = ParameterData::from(kind_from_schema(schema, pointer)?);
+
+    // The schema is certainly an object once its kind has been retrieved.
+    if let Some(schema) = schema.as_object() {
+        parameter.label =
+            keyword(schema, pointer, "title", Value::as_str)?.map(|label| Cow::Owned(label.into()));
+        parameter.description = keyword(schema, pointer, "description", Value::as_str)?
+            .map(|description| Cow::Owned(description.into()));
+        parameter.unit = keyword(schema, pointer, "x-ascot-unit", |unit| {
+            serde_json::from_value(unit.clone()).ok()
+        })?;
+    }
+
+    Ok(parameter)
+}
+
+fn parameters_from_object(
+    schema: &Map<String, Value>,
+    pointer: &str,
+    required: &[&str],
+) -> Result<ParametersData, SchemaError> {
+    let mut parameters = ParametersData::new();
+
+    if let Some(properties) = keyword(schema, pointer, "properties", Value::as_object)? {
+        let pointer = child_pointer(pointer, "properties");
+        for (name, property) in properties {
+            let mut parameter = parameter_from_schema(property, &child_pointer(&pointer, name))?;
+            parameter.required = required.contains(&name.as_str());
+            parameters.add(name.clone(), parameter);
+        }
+    }
+
+    Ok(parameters)
+}
+
+/// Converts a `JSON Schema` into a [`ParameterKind`].
+///
+/// The supported subset of `JSON Schema` is the one produced by
+/// [`parameter_kind_schema`]. In particular, an integer with a step becomes
+/// a range, whose minimum value is rounded up to the first multiple of the
+/// step.
+///
+/// # Errors
+///
+/// It returns a [`SchemaError`] when the schema cannot be represented by a
+/// [`ParameterKind`], when its minimum value is greater than its maximum
+/// one, or when its default value violates its own constraints.
+pub fn parameter_kind_from_schema(schema: &Value) -> Result<ParameterKind, SchemaError> {
+    kind_from_schema(schema, "")
+}
+
+/// Converts a `JSON Schema` describing an object into [`Parameters`].
+///
+/// Each property of the object becomes a parameter, while the required
+/// properties become required parameters. This allows to declare the input
+/// parameters of a route through an external specification.
+///
+/// # Errors
+///
+/// It returns a [`SchemaError`] when the schema does not describe an
+/// object, or when one of its properties cannot be represented by a
+/// parameter.
+pub fn parameters_from_schema(schema: &Value) -> Result<Parameters, SchemaError> {
+    let object = as_object(schema, "")?;
+
+    match keyword(object, "", "type", Value::as_str)? {
+        Some("object") | None => {}
+        Some(kind) => {
+            return Err(SchemaError::new(
+                "",
+                SchemaErrorKind::UnsupportedType(kind.into()),
+            ));
+        }
+    }
+
+    let required = keyword(object, "", "required", |required| {
+        required
+            .as_array()?
+            .iter()
+            .map(Value::as_str)
+            .collect::<Option<Vec<_>>>()
+    })?
+    .unwrap_or_default();
+
+    let parameters = parameters_from_object(object, "", &required)?;
+
+    // Required properties must be declared.
+    if required.iter().any(|name| parameters.get(*name).is_none()) {
+        return Err(SchemaError::new(
+            "",
+            SchemaErrorKind::InvalidKeyword("required"),
+        ));
+    }
+
+    Ok(parameters.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::parameters::{Color, DecimalPrecision, ParameterKind, Parameters};
+    use crate::units::Unit;
+
+    use super::{
+        JSON_SCHEMA_DIALECT, SchemaError, SchemaErrorKind, parameter_kind_from_schema,
+        parameter_kind_schema, parameters_from_schema, parameters_schema,
+    };
+
+    fn parameters() -> Parameters {
+        Parameters::new()
+            .bool("on", true)
+            .u8_with_limits("level", 3, 1, 10)
+            .unit("level", Unit::Percent)
+            .i32("offset", -2)
+            .f32_with_limits("ratio", 0.5, 0., 1., DecimalPrecision::TwoDigits)
+            .f64("gain", 1.5)
+            .rangeu64_with_default("brightness", (0, 20, 2), 4)
+            .label("brightness", "Brightness")
+            .description("brightness", "Light brightness.")
+            .rangei64_with_default("tilt", (-5, 5, 2), -1)
+            .rangef64_with_default("volume", (0.25, 1.25, 0.5), 0.75)
+            .characters_sequence_with_length("greeting", "hello", 5)
+            .choice_with_default("mode", &["eco", "boost"], "boost")
+            .color_temperature("temperature", 2700, 2000, 6500)
+            .color("color", Color::rgb(255, 0, 0))
+            .list_with_length(
+                "schedule",
+                ParameterKind::U16 {
+                    default: 0,
+                    min: 0,
+                    max: 1440,
+                },
+                1,
+                8,
+            )
+            .object("position", Parameters::new().i16("x", 0).i16("y", 0))
+            .required("mode")
+    }
+
+    #[test]
+    fn test_parameter_kind_schema() {
+        assert_eq!(
+            parameter_kind_schema(&ParameterKind::U8 {
+                default: 3,
+                min: 0,
+                max: 10,
+            }),
+            json!({ "type": "integer", "format": "uint8", "maximum": 10, "default": 3 })
+        );
+
+        // The step of a range whose minimum is a multiple of the step is
+        // expressed through `multipleOf`.
+        assert_eq!(
+            parameter_kind_schema(&ParameterKind::RangeF64 {
+                min: 0.,
+                max: 1.,
+                step: 0.1,
+                default: 0.5,
+            }),
+            json!({
+                "type": "number",
+                "format": "double",
+                "minimum": 0.,
+                "maximum": 1.,
+                "multipleOf": 0.1,
+                "default": 0.5,
+            })
+        );
+
+        // Otherwise, it is expressed through an extension.
+        assert_eq!(
+            parameter_kind_schema(&ParameterKind::RangeU64 {
+                min: 1,
+                max: 9,
+                step: 2,
+                default: 3,
+            }),
+            json!({
+                "type": "integer",
+                "format": "uint64",
+                "minimum": 1,
+                "maximum": 9,
+                "x-ascot-step": 2,
+                "default": 3,
+            })
+        );
+
+        assert_eq!(
+            parameter_kind_schema(&ParameterKind::CharsSequence {
+                default: "hello".into(),
+                length: 5,
+            }),
+            json!({ "type": "string", "maxLength": 5, "default": "hello" })
+        );
+
+        assert_eq!(
+            parameter_kind_schema(&ParameterKind::Choice {
+                default: "low".into(),
+                choices: ["low".into(), "high".into()].into(),
+            }),
+            json!({ "type": "string", "enum": ["low", "high"], "default": "low" })
+        );
+    }
+
+    #[test]
+    fn test_parameters_schema() {
+        let schema = parameters_schema(&parameters().serialize_data());
+
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"], json!(["mode"]));
+        assert_eq!(schema["additionalProperties"], false);
+
+        let properties = &schema["properties"];
+        assert_eq!(properties["level"]["x-ascot-unit"], "Percent");
+        assert_eq!(properties["ratio"]["multipleOf"], json!(0.01f32));
+        assert_eq!(properties["brightness"]["title"], "Brightness");
+        assert_eq!(properties["brightness"]["multipleOf"], 2);
+        assert_eq!(properties["temperature"]["x-ascot-color-model"], "kelvin");
+        assert_eq!(properties["schedule"]["items"]["maximum"], 1440);
+        assert_eq!(properties["schedule"]["maxItems"], 8);
+        // All object fields are required.
+        assert_eq!(properties["position"]["required"], json!(["x", "y"]));
+    }
+
+    #[test]
+    fn test_schema_round_trip() {
+        let parameters = parameters().serialize_data();
+
+        assert_eq!(
+            parameters_from_schema(&parameters_schema(&parameters))
+                .unwrap()
+                .serialize_data(),
+            parameters
+        );
+    }
+
+    #[test]
+    fn test_import_schema() {
+        // A step relative to zero moves the minimum of a range to the first
+        // multiple of the step.
+        assert_eq!(
+            parameter_kind_from_schema(&json!({
+                "type": "integer",
+                "minimum": -7,
+                "maximum": 20,
+                "multipleOf": 5,
+            })),
+            Ok(ParameterKind::RangeI64 {
+                min: -5,
+                max: 20,
+                step: 5,
+                default: -5,
+            })
+        );
+
+        // Missing defaults are zero, when it is admitted.
+        assert_eq!(
+            parameter_kind_from_schema(&json!({ "type": "number", "maximum": 10. })),
+            Ok(ParameterKind::F64 {
+                default: 0.,
+                min: f64::MIN,
+                max: 10.,
+                step: 0.,
+            })
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(&json!({ "type": "string", "enum": ["a", "b"] })),
+            Ok(ParameterKind::Choice {
+                default: "a".into(),
+                choices: ["a".into(), "b".into()].into(),
+            })
+        );
+
+        let parameters = parameters_from_schema(&json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "maxLength": 8, "title": "Name" },
+            },
+            "required": ["name"],
+        }))
+        .unwrap()
+        .serialize_data();
+        let name = parameters.get("name").unwrap();
+        assert!(name.required);
+        assert_eq!(name.label.as_deref(), Some("Name"));
+        assert_eq!(
+            name.kind,
+            ParameterKind::CharsSequence {
+                default: "".into(),
+                length: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn test_import_schema_errors() {
+        let error = |pointer: &str, kind| SchemaError {
+            pointer: pointer.into(),
+            kind,
+        };
+
+        assert_eq!(
+            parameters_from_schema(&json!({
+                "type": "object",
+                "properties": { "a/b": { "type": "null" } },
+            }))
+            .unwrap_err(),
+            error(
+                "/properties/a~1b",
+                SchemaErrorKind::UnsupportedType("null".into())
+            )
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(&json!({ "type": "array" })).unwrap_err(),
+            error("", SchemaErrorKind::MissingKeyword("items"))
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(
+                &json!({ "type": "integer", "format": "uint8", "maximum": 300 })
+            )
+            .unwrap_err(),
+            error("", SchemaErrorKind::InvalidKeyword("maximum"))
+        );
+
+        // Bounds must be ordered and defaults must satisfy the constraints.
+        assert_eq!(
+            parameter_kind_from_schema(
+                &json!({ "type": "integer", "format": "uint8", "maximum": 10, "default": 100 })
+            )
+            .unwrap_err(),
+            error("", SchemaErrorKind::InvalidKeyword("default"))
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(
+                &json!({ "type": "integer", "minimum": 10, "maximum": 5, "multipleOf": 2 })
+            )
+            .unwrap_err(),
+            error("", SchemaErrorKind::InvalidKeyword("minimum"))
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(
+                &json!({ "type": "number", "minimum": 1.5, "maximum": -1.5 })
+            )
+            .unwrap_err(),
+            error("", SchemaErrorKind::InvalidKeyword("minimum"))
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(
+                &json!({ "type": "string", "maxLength": 3, "default": "toolong" })
+            )
+            .unwrap_err(),
+            error("", SchemaErrorKind::InvalidKeyword("default"))
+        );
+
+        assert_eq!(
+            parameter_kind_from_schema(&json!({ "type": "string", "pattern": "^a" })).unwrap_err(),
+            error("", SchemaErrorKind::UnsupportedKeyword("pattern".into()))
+        );
+
+        assert_eq!(
+            parameters_from_schema(&json!({ "type": "object", "required": ["missing"] }))
+                .unwrap_err(),
+            error("", SchemaErrorKind::InvalidKeyword("required"))
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+
+/// Physical quantity measured by a [`Unit`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum Quantity {
+    /// A ratio between two quantities of the same kind.
+    Ratio,
+    /// Temperature.
+    Temperature,
+    /// Luminous flux.
+    LuminousFlux,
+    /// Illuminance.
+    Illuminance,
+    /// Power.
+    Power,
+    /// Energy.
+    Energy,
+    /// Electric potential.
+    Voltage,
+    /// Electric current.
+    Current,
+    /// Time.
+    Time,
+    /// Frequency.
+    Frequency,
+    /// Length.
+    Length,
+    /// Pressure.
+    Pressure,
+}
+
+/// Measurement unit of a route input parameter.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum Unit {
+    /// Percentage.
+    Percent,
+    /// Degree Celsius.
+    Celsius,
+    /// Degree Fahrenheit.
+    Fahrenheit,
+    /// Kelvin.
+    Kelvin,
+    /// Lumen.
+    Lumen,
+    /// Lux.
+    Lux,
+    /// Watt.
+    Watt,
+    /// Kilowatt.
+    Kilowatt,
+    /// Joule.
+    Joule,
+    /// Watt-hour.
+    WattHour,
+    /// Kilowatt-hour.
+    KilowattHour,
+    /// Volt.
+    Volt,
+    /// Ampere.
+    Ampere,
+    /// Millisecond.
+    Millisecond,
+    /// Second.
+    Second,
+    /// Minute.
+    Minute,
+    /// Hour.
+    Hour,
+    /// Hertz.
+    Hertz,
+    /// Millimeter.
+    Millimeter,
+    /// Centimeter.
+    Centimeter,
+    /// Meter.
+    Meter,
+    /// Pascal.
+    Pascal,
+    /// Hectopascal.
+    Hectopascal,
+}
+
+impl Unit {
+    /// Returns the [`Unit`] symbol.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Percent => "%",
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+            Self::Kelvin => "K",
+            Self::Lumen => "lm",
+            Self::Lux => "lx",
+            Self::Watt => "W",
+            Self::Kilowatt => "kW",
+            Self::Joule => "J",
+            Self::WattHour => "Wh",
+            Self::KilowattHour => "kWh",
+            Self::Volt => "V",
+            Self::Ampere => "A",
+            Self::Millisecond => "ms",
+            Self::Second => "s",
+            Self::Minute => "min",
+            Self::Hour => "h",
+            Self::Hertz => "Hz",
+            Self::Millimeter => "mm",
+            Self::Centimeter => "cm",
+            Self::Meter => "m",
+            Self::Pascal => "Pa",
+            Self::Hectopascal => "hPa",
+        }
+    }
+
+    /// Returns the [`Quantity`] measured by a [`Unit`].
+    #[must_use]
+    pub const fn quantity(self) -> Quantity {
+        self.base().0
+    }
+
+    /// Converts a value expressed in a [`Unit`] into the `to` [`Unit`].
+    ///
+    /// If [`None`], the two units measure different quantities.
+    #[must_use]
+    pub fn convert(self, value: f64, to: Self) -> Option<f64> {
+        let (quantity, scale, offset) = self.base();
+        let (to_quantity, to_scale, to_offset) = to.base();
+
+        (quantity == to_quantity).then(|| (value * scale + offset - to_offset) / to_scale)
+    }
+
+    // Returns the measured quantity together with the scale and the offset
+    // necessary to express a value in the base unit of that quantity.
+    const fn base(self) -> (Quantity, f64, f64) {
+        match self {
+            Self::Percent => (Quantity::Ratio, 1., 0.),
+            Self::Kelvin => (Quantity::Temperature, 1., 0.),
+            Self::Celsius => (Quantity::Temperature, 1., 273.15),
+            Self::Fahrenheit => (Quantity::Temperature, 5. / 9., 273.15 - 32. * 5. / 9.),
+            Self::Lumen => (Quantity::LuminousFlux, 1., 0.),
+            Self::Lux => (Quantity::Illuminance, 1., 0.),
+            Self::Watt => (Quantity::Power, 1., 0.),
+            Self::Kilowatt => (Quantity::Power, 1000., 0.),
+            Self::Joule => (Quantity::Energy, 1., 0.),
+            Self::WattHour => (Quantity::Energy, 3600., 0.),
+            Self::KilowattHour => (Quantity::Energy, 3_600_000., 0.),
+            Self::Volt => (Quantity::Voltage, 1., 0.),
+            Self::Ampere => (Quantity::Current, 1., 0.),
+            Self::Millisecond => (Quantity::Time, 0.001, 0.),
+            Self::Second => (Quantity::Time, 1., 0.),
+            Self::Minute => (Quantity::Time, 60., 0.),
+            Self::Hour => (Quantity::Time, 3600., 0.),
+            Self::Hertz => (Quantity::Frequency, 1., 0.),
+            Self::Millimeter => (Quantity::Length, 0.001, 0.),
+            Self::Centimeter => (Quantity::Length, 0.01, 0.),
+            Self::Meter => (Quantity::Length, 1., 0.),
+            Self::Pascal => (Quantity::Pressure, 1., 0.),
+            Self::Hectopascal => (Quantity::Pressure, 100., 0.),
+        }
+    }
+}
+
+impl core::fmt::Display for Unit {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.symbol().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{deserialize, serialize};
+
+    use super::{Quantity, Unit};
+
+    fn assert_close(value: Option<f64>, expected: f64) {
+        assert!(
+            (value.unwrap() - expected).abs() < 1e-9,
+            "{value:?} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_convert_units() {
+        assert_close(Unit::Fahrenheit.convert(212., Unit::Celsius), 100.);
+        assert_close(Unit::Fahrenheit.convert(-40., Unit::Celsius), -40.);
+        assert_close(Unit::Celsius.convert(0., Unit::Kelvin), 273.15);
+        assert_close(Unit::Kelvin.convert(0., Unit::Fahrenheit), -459.67);
+        assert_close(Unit::KilowattHour.convert(1., Unit::Joule), 3_600_000.);
+        assert_close(Unit::Minute.convert(90., Unit::Hour), 1.5);
+        assert_close(Unit::Percent.convert(42., Unit::Percent), 42.);
+
+        assert_eq!(Unit::Watt.convert(1., Unit::WattHour), None);
+        assert_eq!(Unit::Lumen.quantity(), Quantity::LuminousFlux);
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(Unit::Celsius.to_string(), "°C");
+        assert_eq!(
+            deserialize::<Unit>(serialize(Unit::Fahrenheit)),
+            Unit::Fahrenheit
+        );
+    }
+}